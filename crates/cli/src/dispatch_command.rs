@@ -1,20 +1,33 @@
 use crate::run::DATA_INIT_HINT;
 use crate::{
-    Data, DataAdminInputCommand, DataSelector, DecryptedEmailSettings, EmailInputCommand,
-    EmailSettingsSelector, EncryptedEmailSettings, Error, HasSample, InvoiceInput, Item,
-    NamedInvoicePdf, Path, PathBuf, RelativeTime, Result, ResultExt, ValidInput, Vat, ask_for_data,
-    ask_for_email, client_path, create_invoice_pdf_with_data, curry2, data_dir, data_dir_create_if,
-    edit_data_at, edit_email_data_at, expensed_periods_path, get_email_encryption_password,
-    init_data_at, init_email_data_at, load_email_data_and_send_test_email_at, payment_info_path,
-    period_end_from_relative_time, proto_invoice_info_path, read_data_from_disk_with_base_path,
-    record_expenses_with_base_path, record_period_off_with_base_path,
-    save_pdf_location_to_tmp_file, send_email_with_settings_for_pdf, service_fees_path,
-    validate_email_data_at, vendor_path,
+    ClientId, ClientInputCommand, CreditNoteInput, Data, DataAdminInputCommand, DataSelector, Date,
+    DecryptedEmailSettings, EmailInputCommand, EmailSettingsSelector, EncryptedEmailSettings,
+    Error, ExpenseAttachInput, ExpenseEditInput, ExpenseIndexInput, ExpenseMoveInput,
+    ExpensesImportInput, ExpensesInput, ExpensesInputCommand, HasSample, InvoiceInput,
+    InvoiceNumber, InvoiceStatus, Item, LedgerEntry, LedgerInputCommand, NamedInvoicePdf, Path,
+    PathBuf, PaymentInputCommand, RelativeTime, ReminderInput, Result, ResultExt, Settlement,
+    StatusInput, ValidInput, Vat, add_client_with_base_path, ask_for_client, ask_for_data,
+    ask_for_email, attach_receipts_with_base_path, client_path,
+    create_credit_note_pdf_with_data_base_path, create_invoice_pdf_with_data,
+    create_invoice_pdf_with_data_base_path, create_reminder_pdf_with_data_base_path, curry2,
+    data_dir, data_dir_create_if, edit_data_at, edit_email_data_at, edit_expense_with_base_path,
+    expensed_periods_path, get_email_encryption_password, import_expenses_with_base_path,
+    init_data_at, init_email_data_at, invoice_statuses_with_base_path, ledger,
+    list_expenses_with_base_path, load_email_data_and_send_test_email_at,
+    move_expense_with_base_path, payment_info_path, per_diem_expenses_with_base_path,
+    period_end_from_relative_time, preview_expense_import_with_base_path, proto_invoice_info_path,
+    read_data_from_disk_with_base_path, read_expenses_csv, read_receipt_files,
+    record_expenses_with_base_path, record_payment_with_base_path,
+    record_period_off_with_base_path, remove_client_with_base_path, remove_expense_with_base_path,
+    save_pdf_location_to_tmp_file, send_email_with_settings_for_invoice_pdf,
+    send_email_with_settings_for_pdf, service_fees_path, validate_email_data_at, vendor_path,
 };
+use indexmap::IndexMap;
+use inquire::Confirm;
 use klirr_core_invoice::L10n as InvoiceL10n;
 use klirr_core_invoice::PreparedData as InvoiceDataPrepared;
 use klirr_foundation::Pdf;
-use klirr_render_typst::render as render_base;
+use klirr_render_typst::{InlineFile, render as render_base, render_with_files};
 use log::error;
 use log::info;
 use log::warn;
@@ -28,6 +41,19 @@ fn render_invoice(
     render_base(i18n, data, layout, Error::from)
 }
 
+/// Renders an invoice like [`render_invoice`], including the receipts of its
+/// expenses, read from the data directory at `data_path`.
+fn render_invoice_with_receipts(
+    data_path: &Path,
+) -> impl Fn(InvoiceL10n, InvoiceDataPrepared, klirr_core_invoice::Layout) -> Result<Pdf> + '_ {
+    move |i18n, data, layout| {
+        let files = read_receipt_files(data.information().receipts(), data_path)?
+            .into_iter()
+            .map(|file| InlineFile::new(file.receipt().to_string(), file.content().clone()));
+        render_with_files(i18n, data, layout, files, Error::from)
+    }
+}
+
 fn init_email_data(
     provide_data: impl FnOnce(EncryptedEmailSettings) -> Result<EncryptedEmailSettings>,
 ) -> Result<()> {
@@ -102,14 +128,201 @@ fn validate_data() -> Result<()> {
         .map_err(Error::from)
 }
 
-fn record_expenses(period: &str, expenses: &[Item]) -> Result<()> {
-    record_expenses_with_base_path(period, expenses, data_dir()).map_err(Error::from)
+/// Records the expenses, mileage and per diem allowances of `input` for its
+/// period, looking up the allowances in the per diem rates, each with the
+/// markup and billable flag of `input`.
+fn record_expenses(input: &ExpensesInput) -> Result<()> {
+    let period = input
+        .period()
+        .as_deref()
+        .ok_or(Error::ExpensesRequirePeriod)?;
+    let base_path = data_dir();
+    let mut expenses = input.expenses().clone();
+    expenses.extend(input.mileage().iter().cloned());
+    expenses.extend(per_diem_expenses_with_base_path(
+        input.per_diem().clone(),
+        &base_path,
+    )?);
+    let expenses = expenses
+        .into_iter()
+        .map(|expense| {
+            expense
+                .with_markup(*input.markup())
+                .with_billable(!input.not_billable())
+        })
+        .collect::<Vec<_>>();
+    record_expenses_with_base_path(period, &expenses, base_path).map_err(Error::from)
+}
+
+fn list_expenses(period: Option<&str>) -> Result<()> {
+    let expenses = list_expenses_with_base_path(period, data_dir())?;
+    if expenses.is_empty() {
+        info!(
+            "No expenses recorded, record some with `klirr data expenses -p <PERIOD> -e <EXPENSE>`"
+        );
+    }
+    for (period_end, items) in expenses {
+        info!("Expenses for period ending {}:", period_end);
+        for (index, item) in items.iter().enumerate() {
+            let markup = item
+                .markup()
+                .map(|markup| format!(" +{markup}% markup"))
+                .unwrap_or_default();
+            let not_billable = if *item.billable() {
+                ""
+            } else {
+                " (not billable)"
+            };
+            info!("  {}: {}{}{}", index + 1, item, markup, not_billable);
+        }
+    }
+    Ok(())
+}
+
+fn remove_expense(input: &ExpenseIndexInput) -> Result<()> {
+    let removed = remove_expense_with_base_path(input.period(), *input.index(), data_dir())?;
+    info!("Removed expense: {}", removed);
+    Ok(())
+}
+
+fn edit_expense(input: &ExpenseEditInput) -> Result<()> {
+    let expense_index = input.expense_index();
+    let replaced = edit_expense_with_base_path(
+        expense_index.period(),
+        *expense_index.index(),
+        input.expense().clone(),
+        data_dir(),
+    )?;
+    info!("Replaced expense: {} with: {}", replaced, input.expense());
+    Ok(())
+}
+
+fn move_expense(input: &ExpenseMoveInput) -> Result<()> {
+    let expense_index = input.expense_index();
+    let moved = move_expense_with_base_path(
+        expense_index.period(),
+        *expense_index.index(),
+        input.to(),
+        data_dir(),
+    )?;
+    info!("Moved expense: {} to period: {}", moved, input.to());
+    Ok(())
+}
+
+fn attach_receipts(input: &ExpenseAttachInput) -> Result<()> {
+    let expense_index = input.expense_index();
+    let expense = attach_receipts_with_base_path(
+        expense_index.period(),
+        *expense_index.index(),
+        input.files(),
+        data_dir(),
+    )?;
+    info!(
+        "Attached receipts to expense: {}, receipts: {}",
+        expense,
+        expense
+            .receipts()
+            .iter()
+            .map(|receipt| receipt.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(())
+}
+
+fn log_expenses_per_period(heading: &str, expenses_per_period: &IndexMap<Date, Vec<Item>>) {
+    for (period_end, items) in expenses_per_period {
+        info!("{} for period ending {}:", heading, period_end);
+        for item in items {
+            info!("  {}", item);
+        }
+    }
+}
+
+fn import_expenses(input: &ExpensesImportInput) -> Result<()> {
+    let base_path = data_dir();
+    let expenses = read_expenses_csv(input.file(), &input.format())?;
+    let preview = preview_expense_import_with_base_path(expenses.clone(), &base_path)?;
+    log_expenses_per_period("New expenses", preview.new_expenses());
+    log_expenses_per_period("Already recorded, skipped", preview.duplicates());
+    info!(
+        "#{} new expenses, #{} already recorded",
+        preview.new_count(),
+        preview.duplicate_count()
+    );
+    if *input.dry_run() || preview.new_count() == 0 {
+        info!("Nothing recorded");
+        return Ok(());
+    }
+    let confirmed = *input.yes()
+        || Confirm::new(&format!("Record #{} new expenses?", preview.new_count()))
+            .with_default(true)
+            .prompt()
+            .unwrap_or(false);
+    if !confirmed {
+        info!("Nothing recorded");
+        return Ok(());
+    }
+    import_expenses_with_base_path(expenses, base_path)?;
+    Ok(())
+}
+
+fn run_expenses_command(input: &ExpensesInput) -> Result<()> {
+    match input.command() {
+        None => record_expenses(input),
+        Some(ExpensesInputCommand::List(list_input)) => {
+            list_expenses(list_input.period().as_deref())
+        }
+        Some(ExpensesInputCommand::Remove(index_input)) => remove_expense(index_input),
+        Some(ExpensesInputCommand::Edit(edit_input)) => edit_expense(edit_input),
+        Some(ExpensesInputCommand::Move(move_input)) => move_expense(move_input),
+        Some(ExpensesInputCommand::Import(import_input)) => import_expenses(import_input),
+        Some(ExpensesInputCommand::Attach(attach_input)) => attach_receipts(attach_input),
+    }
 }
 
 fn record_period_off(period: &str) -> Result<()> {
     record_period_off_with_base_path(period, data_dir()).map_err(Error::from)
 }
 
+fn add_client(id: &ClientId) -> Result<()> {
+    let base_path = data_dir();
+    let data = read_data_from_disk_with_base_path(&base_path)?;
+    let (client, offset) = ask_for_client(&data)?;
+    add_client_with_base_path(id, client, offset, base_path).map_err(Error::from)
+}
+
+fn list_clients() -> Result<()> {
+    let data = read_data_from_disk_with_base_path(data_dir())?;
+    info!("Default client: {}", data.client().company_name());
+    if data.clients().is_empty() {
+        info!("No other clients, add one with `klirr data client add <ID>`");
+    }
+    for (id, client) in data.clients().iter() {
+        let offset = data.information().offset_for_client(Some(id))?;
+        info!(
+            "{}: {} (invoice number offset: {} at {})",
+            id,
+            client.company_name(),
+            offset.offset(),
+            offset.date()
+        );
+    }
+    Ok(())
+}
+
+fn remove_client(id: &ClientId) -> Result<()> {
+    remove_client_with_base_path(id, data_dir()).map_err(Error::from)
+}
+
+fn run_client_command(command: &ClientInputCommand) -> Result<()> {
+    match command {
+        ClientInputCommand::Add(input) => add_client(input.id()),
+        ClientInputCommand::List => list_clients(),
+        ClientInputCommand::Remove(input) => remove_client(input.id()),
+    }
+}
+
 pub fn run_data_command(command: &DataAdminInputCommand) -> Result<()> {
     match command {
         DataAdminInputCommand::Init => init_data(curry2(ask_for_data, None)),
@@ -122,9 +335,8 @@ pub fn run_data_command(command: &DataAdminInputCommand) -> Result<()> {
         DataAdminInputCommand::PeriodOff(period_off_input) => {
             record_period_off(period_off_input.period())
         }
-        DataAdminInputCommand::Expenses(expenses_input) => {
-            record_expenses(expenses_input.period(), expenses_input.expenses())
-        }
+        DataAdminInputCommand::Expenses(expenses_input) => run_expenses_command(expenses_input),
+        DataAdminInputCommand::Client(client_input) => run_client_command(client_input.command()),
     }
 }
 
@@ -165,6 +377,7 @@ pub fn render_invoice_sample_with_nonce(use_nonce: bool) -> Result<NamedInvoiceP
         data.service_fees().cadence().max_granularity(),
     ))?;
     create_invoice_pdf_with_data(
+        data_dir(),
         data,
         ValidInput::builder()
             .maybe_output_path(path)
@@ -183,11 +396,21 @@ fn run_invoice_command_with_base_path(
     let input = input.parsed(*data.service_fees().cadence())?;
     info!("🔮 Starting invoice PDF creation...");
     let email_settings = input.email().clone();
-    let named_pdf = create_invoice_pdf_with_data(data, input, render_invoice)?;
+    // Recording the invoice in the ledger is left to core, so that every
+    // generated invoice is recorded exactly once.
+    let named_pdf = create_invoice_pdf_with_data_base_path(
+        data_path,
+        input,
+        render_invoice_with_receipts(data_path),
+    )?;
     save_pdf_location_to_tmp_file(named_pdf.saved_at().clone());
     if let Some(email_settings) = email_settings {
         info!("Sending email with invoice...");
-        send_email_with_settings_for_pdf(&named_pdf, &email_settings)?;
+        let receipt_files = read_receipt_files(
+            named_pdf.prepared_data().information().receipts(),
+            data_path,
+        )?;
+        send_email_with_settings_for_invoice_pdf(&named_pdf, receipt_files, &email_settings)?;
         info!("✅ Sent email with invoice");
     }
     Ok(named_pdf)
@@ -234,14 +457,205 @@ pub fn run_invoice_command(input: InvoiceInput) -> Result<NamedInvoicePdf> {
     run_invoice_command_with_base_path(input, data_dir())
 }
 
+fn format_ledger_entry(entry: &LedgerEntry) -> String {
+    let number = entry.number().to_string();
+    let formatted_number = entry
+        .formatted_number()
+        .as_ref()
+        .filter(|formatted| **formatted != number)
+        .map(|formatted| format!(" ({formatted})"))
+        .unwrap_or_default();
+    format!(
+        "#{}{} {} {} (due {}), client: {}, total: {} {}, at: {}",
+        number,
+        formatted_number,
+        entry.kind(),
+        entry.period(),
+        entry.due_date(),
+        entry
+            .client()
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| "default".to_owned()),
+        entry.totals().grand_total(),
+        entry.currency(),
+        entry.output_path().display()
+    )
+}
+
+fn list_ledger_with_base_path(base_path: impl AsRef<Path>) -> Result<Vec<LedgerEntry>> {
+    let ledger = ledger(base_path)?;
+    if ledger.is_empty() {
+        info!("No invoices in the ledger yet, generate one with `klirr invoice`");
+    }
+    for entry in ledger.iter() {
+        info!("{}", format_ledger_entry(entry));
+    }
+    Ok(ledger.to_vec())
+}
+
+fn show_ledger_entries_with_base_path(
+    number: &InvoiceNumber,
+    base_path: impl AsRef<Path>,
+) -> Result<Vec<LedgerEntry>> {
+    let ledger = ledger(base_path)?;
+    let entries = ledger.entries_with_number(number);
+    if entries.is_empty() {
+        return Err(Error::from(klirr_core_invoice::Error::InvoiceNotInLedger {
+            number: **number,
+        }));
+    }
+    for entry in &entries {
+        let ron_str = ron::ser::to_string_pretty(entry, ron::ser::PrettyConfig::default())
+            .expect("Failed to serialize ledger entry to RON");
+        info!("{ron_str}");
+    }
+    Ok(entries.into_iter().cloned().collect())
+}
+
+pub fn run_ledger_command(command: &LedgerInputCommand) -> Result<()> {
+    match command {
+        LedgerInputCommand::List => list_ledger_with_base_path(data_dir()).map_to_void(),
+        LedgerInputCommand::Show(input) => {
+            show_ledger_entries_with_base_path(input.number(), data_dir()).map_to_void()
+        }
+    }
+}
+
+fn run_payment_command_with_base_path(
+    command: &PaymentInputCommand,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    let (invoice, settlement, amount) = match command {
+        PaymentInputCommand::Paid(input) => (input.invoice(), Settlement::Paid, *input.amount()),
+        PaymentInputCommand::PartiallyPaid(input) => (
+            input.invoice(),
+            Settlement::PartiallyPaid,
+            Some(*input.amount()),
+        ),
+        PaymentInputCommand::WrittenOff(input) => (input, Settlement::WrittenOff, None),
+    };
+    let record = record_payment_with_base_path(
+        invoice.invoice(),
+        invoice.kind().map(Into::into),
+        invoice.client().as_ref(),
+        settlement,
+        amount,
+        invoice.date_or_today(),
+        base_path,
+    )?;
+    info!(
+        "✅ Recorded invoice {} as {} on {}, amount: {}",
+        invoice.invoice(),
+        record.settlement(),
+        record.date(),
+        record.amount()
+    );
+    Ok(())
+}
+
+pub fn run_payment_command(command: &PaymentInputCommand) -> Result<()> {
+    run_payment_command_with_base_path(command, data_dir())
+}
+
+fn format_invoice_status(status: &InvoiceStatus) -> String {
+    let overdue = status
+        .days_overdue()
+        .map(|days| format!(", {days} days overdue"))
+        .unwrap_or_default();
+    format!(
+        "{}, status: {}, paid: {}, outstanding: {} {}{}",
+        format_ledger_entry(status.entry()),
+        status.status(),
+        status.paid(),
+        status.outstanding(),
+        status.entry().currency(),
+        overdue
+    )
+}
+
+fn invoice_statuses_to_list_with_base_path(
+    input: &StatusInput,
+    today: Date,
+    base_path: impl AsRef<Path>,
+) -> Result<Vec<InvoiceStatus>> {
+    let statuses = invoice_statuses_with_base_path(today, base_path)?
+        .into_iter()
+        .filter(|status| *input.all() || status.is_overdue())
+        .collect::<Vec<_>>();
+    if statuses.is_empty() {
+        if *input.all() {
+            info!("No invoices in the ledger yet, generate one with `klirr invoice`");
+        } else {
+            info!("✅ No overdue invoices");
+        }
+    }
+    for status in &statuses {
+        info!("{}", format_invoice_status(status));
+    }
+    Ok(statuses)
+}
+
+pub fn run_status_command(input: &StatusInput) -> Result<()> {
+    invoice_statuses_to_list_with_base_path(input, Date::today(), data_dir()).map_to_void()
+}
+
+fn run_credit_note_command_with_base_path(
+    input: CreditNoteInput,
+    data_path: impl AsRef<Path>,
+) -> Result<NamedInvoicePdf> {
+    let input = input.parsed()?;
+    info!("🔮 Starting credit note PDF creation...");
+    let named_pdf = create_credit_note_pdf_with_data_base_path(data_path, input, render_invoice)?;
+    save_pdf_location_to_tmp_file(named_pdf.saved_at().clone());
+    Ok(named_pdf)
+}
+
+pub fn run_credit_note_command(input: CreditNoteInput) -> Result<NamedInvoicePdf> {
+    run_credit_note_command_with_base_path(input, data_dir())
+}
+
+fn run_reminder_command_with_base_path(
+    input: ReminderInput,
+    data_path: impl AsRef<Path>,
+) -> Result<NamedInvoicePdf> {
+    let input = input.parsed()?;
+    info!("🔮 Starting payment reminder PDF creation...");
+    let email_settings = input.email().clone();
+    let named_pdf = create_reminder_pdf_with_data_base_path(data_path, input, render_invoice)?;
+    save_pdf_location_to_tmp_file(named_pdf.saved_at().clone());
+    if let Some(email_settings) = email_settings {
+        info!("Sending email with payment reminder...");
+        send_email_with_settings_for_pdf(&named_pdf, &email_settings)?;
+        info!("✅ Sent email with payment reminder");
+    }
+    Ok(named_pdf)
+}
+
+pub fn run_reminder_command(input: ReminderInput) -> Result<NamedInvoicePdf> {
+    run_reminder_command_with_base_path(input, data_dir())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input::InvoiceInput;
+    use crate::CliArgs;
+    use crate::input::{CreditNoteInput, InvoiceInput, ReminderInput};
     use clap::Parser;
     use klirr_core_invoice::save_data_with_base_path;
+    use klirr_core_invoice::{DueDays, PaymentStatus};
+    use klirr_foundation::BINARY_NAME;
     use test_log::test;
 
+    #[test]
+    fn test_record_expenses_fails_without_period() {
+        let input = ExpensesInput::builder().build();
+        assert!(matches!(
+            record_expenses(&input),
+            Err(Error::ExpensesRequirePeriod)
+        ));
+    }
+
     #[test]
     fn test_run_invoice_command() {
         let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
@@ -254,5 +668,164 @@ mod tests {
         ]);
         let result = run_invoice_command_with_base_path(input, tempdir.path());
         assert!(result.is_ok(), "Expected run to succeed, got: {:?}", result);
+        let entries = list_ledger_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].output_path(), &tempfile);
+        let number = entries[0].number().clone();
+        assert_eq!(
+            show_ledger_entries_with_base_path(&number, tempdir.path()).unwrap(),
+            entries
+        );
+    }
+
+    #[test]
+    fn test_show_ledger_entries_fails_for_unknown_number() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let result = show_ledger_entries_with_base_path(&InvoiceNumber::from(1), tempdir.path());
+        assert!(matches!(
+            result,
+            Err(Error::Core(klirr_core_invoice::Error::InvoiceNotInLedger {
+                number: 1
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_payment_and_status() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = InvoiceInput::parse_from([
+            "invoice",
+            "--out",
+            &format!("{}", tempdir.path().join("out.pdf").display()),
+        ]);
+        run_invoice_command_with_base_path(input, tempdir.path()).unwrap();
+        let entry = list_ledger_with_base_path(tempdir.path()).unwrap()[0].clone();
+        let after_due_date = entry.due_date().advance_days(&DueDays::new(1).unwrap());
+        let overdue = StatusInput::builder().build();
+        let all = StatusInput::builder().all(true).build();
+
+        let statuses =
+            invoice_statuses_to_list_with_base_path(&overdue, after_due_date, tempdir.path())
+                .unwrap();
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(*statuses[0].days_overdue(), Some(1));
+
+        let number = entry.number().to_string();
+        let partially_paid = CliArgs::parse_from([
+            BINARY_NAME,
+            "payment",
+            "partially-paid",
+            "--invoice",
+            &number,
+            "--amount",
+            "100",
+        ])
+        .command
+        .unwrap_payment();
+        run_payment_command_with_base_path(partially_paid.command(), tempdir.path()).unwrap();
+        let statuses =
+            invoice_statuses_to_list_with_base_path(&all, after_due_date, tempdir.path()).unwrap();
+        assert_eq!(*statuses[0].status(), PaymentStatus::PartiallyPaid);
+        assert!(statuses[0].is_overdue());
+
+        let paid = CliArgs::parse_from([BINARY_NAME, "payment", "paid", "--invoice", &number])
+            .command
+            .unwrap_payment();
+        run_payment_command_with_base_path(paid.command(), tempdir.path()).unwrap();
+        assert!(
+            invoice_statuses_to_list_with_base_path(&overdue, after_due_date, tempdir.path())
+                .unwrap()
+                .is_empty()
+        );
+        let statuses =
+            invoice_statuses_to_list_with_base_path(&all, after_due_date, tempdir.path()).unwrap();
+        assert_eq!(*statuses[0].status(), PaymentStatus::Paid);
+        assert_eq!(statuses[0].paid(), entry.totals().grand_total());
+    }
+
+    #[test]
+    fn test_payment_of_unknown_invoice_fails() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let paid = CliArgs::parse_from([BINARY_NAME, "payment", "written-off", "--invoice", "1"])
+            .command
+            .unwrap_payment();
+        let result = run_payment_command_with_base_path(paid.command(), tempdir.path());
+        assert!(matches!(
+            result,
+            Err(Error::Core(klirr_core_invoice::Error::InvoiceNotInLedger {
+                number: 1
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_run_credit_note_command() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let tempfile = tempdir.path().join("credit_note.pdf");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let offset = Data::sample().information().offset().offset().to_string();
+        let input = CreditNoteInput::parse_from([
+            "credit-note",
+            "--invoice",
+            &offset,
+            "--out",
+            &format!("{}", tempfile.as_path().display()),
+        ]);
+        let result = run_credit_note_command_with_base_path(input, tempdir.path());
+        assert!(result.is_ok(), "Expected run to succeed, got: {:?}", result);
+        assert_eq!(
+            read_data_from_disk_with_base_path(tempdir.path())
+                .unwrap()
+                .credit_notes()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_run_reminder_command() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = InvoiceInput::parse_from([
+            "invoice",
+            "--out",
+            &format!("{}", tempdir.path().join("out.pdf").display()),
+        ]);
+        run_invoice_command_with_base_path(input, tempdir.path()).unwrap();
+        let entry = list_ledger_with_base_path(tempdir.path()).unwrap()[0].clone();
+        let number = entry.number().to_string();
+        let reminder = |date: Date| {
+            ReminderInput::parse_from([
+                "reminder",
+                "--invoice",
+                &number,
+                "--date",
+                &date.to_string(),
+                "--out",
+                &format!("{}", tempdir.path().join("reminder.pdf").display()),
+            ])
+        };
+
+        let result =
+            run_reminder_command_with_base_path(reminder(*entry.due_date()), tempdir.path());
+        assert!(matches!(
+            result,
+            Err(Error::Core(
+                klirr_core_invoice::Error::InvoiceNotOverdue { .. }
+            ))
+        ));
+
+        let after_due_date = entry.due_date().advance_days(&DueDays::new(10).unwrap());
+        let named_pdf =
+            run_reminder_command_with_base_path(reminder(after_due_date), tempdir.path()).unwrap();
+        assert_eq!(
+            *named_pdf.prepared_data().information().reminded_due_date(),
+            Some(*entry.due_date())
+        );
+        assert_eq!(
+            list_ledger_with_base_path(tempdir.path()).unwrap(),
+            vec![entry]
+        );
     }
 }
@@ -22,6 +22,10 @@ pub enum CliError {
     /// The user supplied an output path that does not exist.
     #[error("Specified output path does not exist: {path}")]
     SpecifiedOutputPathDoesNotExist { path: String },
+
+    /// Expenses were to be recorded without a period to record them for.
+    #[error("A period is required to record expenses, e.g. `klirr data expenses --period 2025-05`")]
+    ExpensesRequirePeriod,
 }
 
 /// Errors that can occur when collecting email data from terminal prompts.
@@ -0,0 +1,40 @@
+use clap::Args;
+use clap::Subcommand;
+use derive_more::Unwrap;
+
+use crate::ClientId;
+use bon::Builder;
+use getset::Getters;
+
+/// Manage the client registry, see [`ClientInputCommand`].
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct ClientInput {
+    /// Client registry command, see [`ClientInputCommand`].
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: ClientInputCommand,
+}
+
+/// Client registry subcommands, add, list or remove clients.
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum ClientInputCommand {
+    /// Adds a client to the registry, asking for its information and invoice
+    /// number offset. Invoice it with `klirr invoice --client <ID>`.
+    Add(ClientIdInput),
+
+    /// Lists the default client and all clients in the registry.
+    List,
+
+    /// Removes a client and its invoice number offset from the registry.
+    Remove(ClientIdInput),
+}
+
+/// Identifies a client in the client registry.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct ClientIdInput {
+    /// The id of the client, e.g. `acme`, used as file name in the data
+    /// directory, so only lowercase letters, digits, `-` and `_` are allowed.
+    #[arg(value_name = "ID")]
+    #[getset(get = "pub")]
+    id: ClientId,
+}
@@ -1,4 +1,4 @@
-use crate::{EditDataInput, ExpensesInput, PeriodOffInput};
+use crate::{ClientInput, EditDataInput, ExpensesInput, PeriodOffInput};
 use bon::Builder;
 use clap::Args;
 use clap::Subcommand;
@@ -37,5 +37,8 @@ pub enum DataAdminInputCommand {
     PeriodOff(PeriodOffInput),
     /// Records expenses for the specified period, used to create expenses invoices
     /// and affects invoice number calculation.
-    Expenses(ExpensesInput),
+    Expenses(Box<ExpensesInput>),
+    /// Manages the client registry, used to invoice more than one client
+    /// from the same data directory.
+    Client(ClientInput),
 }
@@ -1,8 +1,10 @@
+mod client_input;
 #[allow(clippy::module_inception)]
 mod data_admin_input;
 mod edit_data;
 mod period_off_input;
 
+pub use client_input::{ClientIdInput, ClientInput, ClientInputCommand};
 pub use data_admin_input::{DataAdminInput, DataAdminInputCommand};
 pub use edit_data::{EditDataInput, EditDataInputSelector};
 pub use period_off_input::PeriodOffInput;
@@ -1,22 +1,32 @@
 use clap::Args;
+use clap::Subcommand;
+use derive_more::Unwrap;
 
-use crate::Item;
+use crate::{Currency, Decimal, ExpenseCsvFormat, ExpenseSign, Item, PathBuf, PerDiemTrip};
 use bon::Builder;
 use getset::Getters;
 
 /// Record expenses for the specified period, which will be used to create expenses invoices
-/// and affects invoice number calculation.
+/// and affects invoice number calculation, or manage the recorded expenses with a
+/// subcommand, see [`ExpensesInputCommand`].
 #[derive(Debug, Args, Getters, PartialEq, Builder)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct ExpensesInput {
+    /// Manages the recorded expenses instead of recording new ones.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: Option<ExpensesInputCommand>,
+
     /// The period for which the expenses are recorded.
     #[arg(
         long,
         short = 'p',
+        required = true,
         default_value = None,
         help = "The period for which you wanna record expenses, e.g. `2025-05`. or `2025-06-first-half`. Note that we might expense for period of May even thought we had an expense in beginning of June, so this is not a strict period, but rather a period in which we want to record the expenses."
     )]
     #[getset(get = "pub")]
-    period: String,
+    period: Option<String>,
 
     /// The expenses to record for the period, which are specified as a list of items.
     /// Please note that the transaction date might be different from the month specified,
@@ -24,6 +34,259 @@ pub struct ExpensesInput {
     /// month, e.g. you can record expenses for May even if the transaction date is in June.
     /// Format for each item is: `name,amount,currency,quantity,date`, e.g. `Coffee,2.5,EUR,3.0,2025-05-31`.
     #[arg(long, short = 'e', help = "The expenses to record for the period.")]
+    #[builder(default)]
     #[getset(get = "pub")]
     expenses: Vec<Item>,
+
+    /// Mileage to record for the period, billed as distance times the rate per
+    /// distance unit. Format for each is: `name,distance,unit,rate,currency,date`,
+    /// where unit is `km` or `mi`, e.g. `Client visit,120,km,0.25,EUR,2025-05-12`.
+    #[arg(long, value_parser = Item::mileage_from_str)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    mileage: Vec<Item>,
+
+    /// Per diem allowances to record for the period, at the rate of the
+    /// destination in `per_diem_rates.ron`. Format for each is:
+    /// `name,destination,days,date`, optionally followed by the number of provided
+    /// breakfasts, lunches and dinners, e.g. `Berlin workshop,DE,3,2025-05-14,2,1,0`.
+    #[arg(long)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    per_diem: Vec<PerDiemTrip>,
+
+    /// A markup in percent added to the unit price of each recorded expense
+    /// when invoiced, e.g. `10` for a 10% handling fee, none if omitted.
+    #[arg(long, value_name = "PERCENT", value_parser = parse_markup)]
+    #[getset(get = "pub")]
+    markup: Option<Decimal>,
+
+    /// Records the expenses for bookkeeping only, they are kept but left out
+    /// of invoices.
+    #[arg(long)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    not_billable: bool,
+}
+
+/// Parses a non-negative markup in percent, e.g. `10` or `12.5%`.
+fn parse_markup(s: &str) -> Result<Decimal, String> {
+    let markup = s
+        .trim()
+        .trim_end_matches('%')
+        .parse::<Decimal>()
+        .map_err(|e| format!("Invalid markup '{s}': {e}"))?;
+    if markup.is_sign_negative() {
+        return Err(format!("Markup must not be negative, got: '{s}'"));
+    }
+    Ok(markup)
+}
+
+/// Subcommands managing the recorded expenses, each expense is identified by
+/// its period and its 1-based index as listed by `list`.
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum ExpensesInputCommand {
+    /// Lists the recorded expenses of all periods, or of one period, with
+    /// the index of each expense.
+    List(ExpensesListInput),
+
+    /// Removes a recorded expense.
+    Remove(ExpenseIndexInput),
+
+    /// Replaces a recorded expense with another expense.
+    Edit(ExpenseEditInput),
+
+    /// Moves a recorded expense to another period.
+    Move(ExpenseMoveInput),
+
+    /// Imports expenses from a CSV file, e.g. a bank export, recording each
+    /// expense in the period of its transaction date.
+    Import(ExpensesImportInput),
+
+    /// Attaches receipt files, PDFs or images, to a recorded expense, copying
+    /// them into the data directory.
+    Attach(ExpenseAttachInput),
+}
+
+/// Optionally limits the listed expenses to one period.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct ExpensesListInput {
+    /// The period to list the expenses of, e.g. `2025-05`, all periods if
+    /// not specified.
+    #[arg(long, short = 'p')]
+    #[getset(get = "pub")]
+    period: Option<String>,
+}
+
+/// Identifies a recorded expense by period and index.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct ExpenseIndexInput {
+    /// The period the expense is recorded for, e.g. `2025-05`.
+    #[arg(long, short = 'p')]
+    #[getset(get = "pub")]
+    period: String,
+
+    /// The 1-based index of the expense in the period, as listed by
+    /// `klirr data expenses list`.
+    #[arg(long, short = 'i')]
+    #[getset(get = "pub")]
+    index: usize,
+}
+
+/// Identifies a recorded expense and the expense to replace it with.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct ExpenseEditInput {
+    /// The expense to replace.
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    expense_index: ExpenseIndexInput,
+
+    /// The expense replacing it, in the same format as when recording
+    /// expenses: `name,amount,currency,quantity,date`, e.g.
+    /// `Coffee,2.5,EUR,3.0,2025-05-31`.
+    #[arg(long, short = 'e')]
+    #[getset(get = "pub")]
+    expense: Item,
+}
+
+/// Identifies a recorded expense and the period to move it to.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct ExpenseMoveInput {
+    /// The expense to move.
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    expense_index: ExpenseIndexInput,
+
+    /// The period to move the expense to, e.g. `2025-06`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    to: String,
+}
+
+/// Identifies a recorded expense and the receipt files to attach to it.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct ExpenseAttachInput {
+    /// The expense to attach the receipts to.
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    expense_index: ExpenseIndexInput,
+
+    /// The receipt files, PDF, PNG, JPEG, GIF or SVG files.
+    #[arg(value_name = "FILE", required = true)]
+    #[getset(get = "pub")]
+    files: Vec<PathBuf>,
+}
+
+/// Parses a single ASCII character used as CSV delimiter, e.g. `;`.
+fn parse_delimiter(s: &str) -> Result<u8, String> {
+    match s.as_bytes() {
+        [byte] if byte.is_ascii() => Ok(*byte),
+        _ => Err(format!("Expected a single ASCII character, got: '{s}'")),
+    }
+}
+
+/// Reads expenses from a CSV file with a header row, see [`ExpenseCsvFormat`].
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct ExpensesImportInput {
+    /// The CSV file to import, with a header row, e.g.
+    /// `name,unit_price,currency,quantity,transaction_date`.
+    #[arg(value_name = "FILE")]
+    #[getset(get = "pub")]
+    file: PathBuf,
+
+    /// Only shows what would be imported, without recording anything.
+    #[arg(long)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    dry_run: bool,
+
+    /// Records the expenses without asking for confirmation.
+    #[arg(long, short = 'y', conflicts_with = "dry_run")]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    yes: bool,
+
+    /// The field delimiter, e.g. `;` for many bank exports, defaults to `,`.
+    #[arg(long, value_parser = parse_delimiter)]
+    #[getset(get = "pub")]
+    delimiter: Option<u8>,
+
+    /// Header of the column with the name of the expense, defaults to `name`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    name_column: Option<String>,
+
+    /// Header of the column with the cost per item, defaults to `unit_price`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    unit_price_column: Option<String>,
+
+    /// Header of the column with the currency, defaults to `currency`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    currency_column: Option<String>,
+
+    /// Header of the optional column with the quantity, defaults to `quantity`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    quantity_column: Option<String>,
+
+    /// Header of the column with the date of the expense, defaults to
+    /// `transaction_date`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    date_column: Option<String>,
+
+    /// Header of the optional column with the VAT rate, defaults to `vat`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    vat_column: Option<String>,
+
+    /// The currency of every expense, for files without a currency column.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    currency: Option<Currency>,
+
+    /// Reads expenses from negative amounts, as outgoing payments are listed
+    /// in many bank exports, skipping rows with positive amounts.
+    #[arg(long)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    negative_amounts: bool,
+
+    /// The format of the dates, e.g. `%d.%m.%Y` for `31.05.2025`, defaults to
+    /// `%Y-%m-%d`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    date_format: Option<String>,
+
+    /// The decimal separator of amounts, e.g. `,` for `1.234,50`, defaults to
+    /// `.`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    decimal_separator: Option<char>,
+}
+
+impl ExpensesImportInput {
+    /// The format to read the file in, the default format with any column
+    /// headers overridden.
+    pub fn format(&self) -> ExpenseCsvFormat {
+        ExpenseCsvFormat::builder()
+            .maybe_delimiter(self.delimiter)
+            .maybe_name_column(self.name_column.clone())
+            .maybe_unit_price_column(self.unit_price_column.clone())
+            .maybe_currency_column(self.currency_column.clone())
+            .maybe_quantity_column(self.quantity_column.clone())
+            .maybe_transaction_date_column(self.date_column.clone())
+            .maybe_vat_column(self.vat_column.clone())
+            .maybe_currency(self.currency)
+            .expense_sign(if self.negative_amounts {
+                ExpenseSign::Negative
+            } else {
+                ExpenseSign::Positive
+            })
+            .maybe_date_format(self.date_format.clone())
+            .maybe_decimal_separator(self.decimal_separator)
+            .build()
+    }
 }
@@ -1,4 +1,7 @@
 #[allow(clippy::module_inception)]
 mod expenses_input;
 
-pub use expenses_input::ExpensesInput;
+pub use expenses_input::{
+    ExpenseAttachInput, ExpenseEditInput, ExpenseIndexInput, ExpenseMoveInput, ExpensesImportInput,
+    ExpensesInput, ExpensesInputCommand, ExpensesListInput,
+};
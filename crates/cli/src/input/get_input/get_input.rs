@@ -1,9 +1,11 @@
 use crate::{
-    Cadence, DataAdminInput, EmailInput, Error, InvoicedItems, Language, PathBuf, Result,
-    TargetItems, TargetPeriod, TimeOff, ValidInput, period_end_from_relative_time,
-    validate_email_data,
+    Cadence, ClientId, DataAdminInput, Date, EmailInput, Error, InvoiceNumber, InvoicedItems,
+    Language, LedgerInput, PathBuf, PaymentInput, ReceiptPlacement, Result, StatusInput,
+    TargetItems, TargetPeriod, TimeOff, ValidCreditNoteInput, ValidInput, ValidReminderInput,
+    period_end_from_relative_time, read_timesheet, validate_email_data,
 };
 
+use super::InvoiceKindInput;
 use klirr_core_invoice::Layout as InvoiceLayout;
 use klirr_foundation::BINARY_NAME;
 
@@ -37,8 +39,25 @@ pub enum Command {
     /// CLI arguments for generating an invoice PDF, see [`InvoiceInput`].
     Invoice(InvoiceInput),
 
+    /// CLI arguments for generating a credit note PDF, see [`CreditNoteInput`].
+    CreditNote(CreditNoteInput),
+
+    /// CLI arguments for generating a payment reminder PDF, see
+    /// [`ReminderInput`].
+    Reminder(ReminderInput),
+
     /// CLI arguments for admin tasks related to data.
     Data(DataAdminInput),
+
+    /// Browse the ledger of issued invoices, see [`LedgerInput`].
+    Ledger(LedgerInput),
+
+    /// Record payments of issued invoices, see [`PaymentInput`].
+    Payment(PaymentInput),
+
+    /// List overdue invoices, or the payment status of all issued invoices,
+    /// see [`StatusInput`].
+    Status(StatusInput),
 }
 
 /// The CLI arguments for generating an invoice PDF.
@@ -64,12 +83,19 @@ pub struct InvoiceInput {
     #[getset(get = "pub")]
     layout: InvoiceLayout,
 
-    /// The items to be invoiced, either expenses our consulting services
+    /// The items to be invoiced, either expenses our consulting services, or
+    /// both combined
     /// with an optional number of days off.
     #[command(subcommand)]
     #[getset(get = "pub")]
     items: Option<TargetItems>,
 
+    /// The client to invoice, by its id in the client registry, see
+    /// `klirr data client list`. Invoices the default client if omitted.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<ClientId>,
+
     /// An optional override of where to save the output PDF file.
     #[arg(long, short = 'o')]
     out: Option<PathBuf>,
@@ -94,23 +120,84 @@ pub struct InvoiceInput {
     #[builder(default = false)]
     #[getset(get = "pub")]
     refresh_holidays: bool,
+
+    /// A CSV file of the hours worked during the period, with a
+    /// `date,hours,description` header and an optional description, billed
+    /// instead of the working days for services billed per day or per hour.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    timesheet: Option<PathBuf>,
+
+    /// Itemize each row of the timesheet on an appendix page of the invoice.
+    #[arg(long, requires = "timesheet")]
+    #[builder(default = false)]
+    #[getset(get = "pub")]
+    itemize_timesheet: bool,
+
+    /// How the receipts of the invoiced expenses are included in the invoice,
+    /// image receipts as pages of their own by default.
+    #[arg(long, value_enum, default_value_t)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    receipts: ReceiptPlacementInput,
 }
 
-impl InvoiceInput {
-    /// Maps `Option<TargetItems>` to `InvoicedItems`.
-    fn _invoiced_items(&self) -> Result<InvoicedItems> {
-        match self.items.clone().unwrap_or_default() {
-            TargetItems::ServicesOff(time_off) => {
-                let time_off = TimeOff::try_from(time_off)?;
-                Ok(InvoicedItems::Service {
-                    time_off: Some(time_off),
-                })
-            }
-            TargetItems::Services => Ok(InvoicedItems::Service { time_off: None }),
-            TargetItems::Expenses => Ok(InvoicedItems::Expenses),
+/// How the receipts of expenses are included in an invoice.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum ReceiptPlacementInput {
+    /// Image receipts are appended as a page each, PDF receipts are embedded.
+    #[default]
+    Pages,
+    /// All receipts are embedded as files of the invoice.
+    Embedded,
+}
+
+impl From<ReceiptPlacementInput> for ReceiptPlacement {
+    fn from(input: ReceiptPlacementInput) -> Self {
+        match input {
+            ReceiptPlacementInput::Pages => Self::Pages,
+            ReceiptPlacementInput::Embedded => Self::Embedded,
+        }
+    }
+}
+
+/// Maps `Option<TargetItems>` to `InvoicedItems`.
+fn invoiced_items(items: Option<TargetItems>) -> Result<InvoicedItems> {
+    match items.unwrap_or_default() {
+        TargetItems::ServicesOff(time_off) => {
+            let time_off = TimeOff::try_from(time_off)?;
+            Ok(InvoicedItems::Service {
+                time_off: Some(time_off),
+            })
+        }
+        TargetItems::Services => Ok(InvoicedItems::Service { time_off: None }),
+        TargetItems::Expenses => Ok(InvoicedItems::Expenses),
+        TargetItems::Combined => Ok(InvoicedItems::Combined { time_off: None }),
+        TargetItems::CombinedOff(time_off) => {
+            let time_off = TimeOff::try_from(time_off)?;
+            Ok(InvoicedItems::Combined {
+                time_off: Some(time_off),
+            })
         }
     }
+}
 
+/// Fails if the parent directory of `out` does not exist.
+fn validate_output_path(out: Option<&PathBuf>) -> Result<()> {
+    if let Some(path) = out {
+        let parent = path
+            .parent()
+            .expect("Invalid path specified, no parent found, don't specify an empty path, a root or a prefix.");
+        if !parent.exists() {
+            Err(Error::SpecifiedOutputPathDoesNotExist {
+                path: path.display().to_string(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
+impl InvoiceInput {
     /// Returns a `ValidInput` from the parsed command line arguments.
     /// This function validates the input, e.g. checks if the output path exists,
     /// resolves `current`/`last` using invoice cadence, and returns a
@@ -120,22 +207,18 @@ impl InvoiceInput {
     /// Returns an error if the input is invalid, e.g. if the output path does not
     /// exist or if the items are not specified correctly.
     pub fn parsed(self, cadence: Cadence) -> Result<ValidInput> {
-        if let Some(path) = &self.out {
-            let parent = path
-                .parent()
-                .expect("Invalid path specified, no parent found, don't specify an empty path, a root or a prefix.");
-            if !parent.exists() {
-                Err(Error::SpecifiedOutputPathDoesNotExist {
-                    path: path.display().to_string(),
-                })?;
-            }
-        }
+        validate_output_path(self.out.as_ref())?;
         let email_config = if self.email {
             validate_email_data().map(Some)
         } else {
             Ok(None)
         }?;
-        let items = self._invoiced_items()?;
+        let items = invoiced_items(self.items.clone())?;
+        let timesheet = self
+            .timesheet
+            .as_ref()
+            .map(|path| read_timesheet(path, self.itemize_timesheet))
+            .transpose()?;
         let relative_time = self.period.relative_time_for_cadence(cadence);
         let date = period_end_from_relative_time(relative_time)?;
         let valid = ValidInput::builder()
@@ -145,6 +228,9 @@ impl InvoiceInput {
             .language(*self.language())
             .worked_holidays(self.worked_holidays)
             .refresh_holidays(self.refresh_holidays)
+            .maybe_timesheet(timesheet)
+            .receipts(self.receipts.into())
+            .maybe_client(self.client)
             .maybe_maybe_output_path(self.out)
             .maybe_email(email_config)
             .build();
@@ -152,15 +238,178 @@ impl InvoiceInput {
     }
 }
 
+/// The CLI arguments for generating a credit note PDF, reversing all or some
+/// lines of an earlier invoice.
+#[derive(Debug, Clone, Builder, Getters, Parser)]
+#[command(name = "credit-note")]
+#[command(about = "Generate a credit note PDF for an earlier invoice", long_about = None)]
+pub struct CreditNoteInput {
+    /// The number of the invoice to credit.
+    #[arg(long, short = 'i')]
+    #[getset(get = "pub")]
+    invoice: InvoiceNumber,
+
+    /// The 1-based lines of the invoice to credit, comma separated, e.g.
+    /// `--lines 1,3`. Credits all lines if omitted.
+    #[arg(long, value_delimiter = ',')]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    lines: Vec<usize>,
+
+    /// The language for which the credit note is generated.
+    #[arg(long, short = 'l', default_value_t)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    language: Language,
+
+    /// The layout of the credit note to use
+    #[arg(long, short = 't', default_value_t)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    layout: InvoiceLayout,
+
+    /// The items of the credited invoice, must match how the invoice was
+    /// generated, including any time off.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    items: Option<TargetItems>,
+
+    /// The client of the credited invoice, by its id in the client registry.
+    /// The default client if omitted.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<ClientId>,
+
+    /// The timesheet CSV file the credited invoice was billed from, must be
+    /// passed for invoices generated with `--timesheet`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    timesheet: Option<PathBuf>,
+
+    /// An optional override of where to save the output PDF file.
+    #[arg(long, short = 'o')]
+    out: Option<PathBuf>,
+}
+
+impl CreditNoteInput {
+    /// Returns a `ValidCreditNoteInput` issued today from the parsed command
+    /// line arguments.
+    ///
+    /// # Errors
+    /// Returns an error if the output path does not exist, if the items are
+    /// not specified correctly or if the timesheet cannot be read.
+    pub fn parsed(self) -> Result<ValidCreditNoteInput> {
+        validate_output_path(self.out.as_ref())?;
+        let items = invoiced_items(self.items)?;
+        let timesheet = self
+            .timesheet
+            .as_ref()
+            .map(|path| read_timesheet(path, false))
+            .transpose()?;
+        Ok(ValidCreditNoteInput::builder()
+            .invoice(self.invoice)
+            .lines(self.lines)
+            .items(items)
+            .maybe_client(self.client)
+            .maybe_timesheet(timesheet)
+            .language(self.language)
+            .layout(self.layout)
+            .date(Date::today())
+            .maybe_maybe_output_path(self.out)
+            .build())
+    }
+}
+
+/// The CLI arguments for generating a payment reminder PDF of an overdue
+/// invoice in the ledger, with late-payment interest and a reminder fee.
+#[derive(Debug, Clone, Builder, Getters, Parser)]
+#[command(name = "reminder")]
+#[command(about = "Generate a payment reminder PDF for an overdue invoice", long_about = None)]
+pub struct ReminderInput {
+    /// The number of the overdue invoice.
+    #[arg(long, short = 'i')]
+    #[getset(get = "pub")]
+    invoice: InvoiceNumber,
+
+    /// The kind of the invoice, only needed if a services and an expenses
+    /// invoice share the number.
+    #[arg(long, short = 'k')]
+    #[getset(get = "pub")]
+    kind: Option<InvoiceKindInput>,
+
+    /// The client of the overdue invoice, by its id in the client registry.
+    /// The default client if omitted.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<ClientId>,
+
+    /// The date of the reminder, up to which interest is accrued, e.g.
+    /// `2025-07-15`, today if omitted.
+    #[arg(long, short = 'd')]
+    #[getset(get = "pub")]
+    date: Option<Date>,
+
+    /// The language for which the reminder is generated.
+    #[arg(long, short = 'l', default_value_t)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    language: Language,
+
+    /// The layout of the reminder to use
+    #[arg(long, short = 't', default_value_t)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    layout: InvoiceLayout,
+
+    /// An optional override of where to save the output PDF file.
+    #[arg(long, short = 'o')]
+    out: Option<PathBuf>,
+
+    /// Whether to send the reminder via email after generating it - if
+    /// the email settings are configured.
+    #[arg(long, short = 'e')]
+    #[builder(default = false)]
+    email: bool,
+}
+
+impl ReminderInput {
+    /// Returns a `ValidReminderInput` from the parsed command line arguments.
+    ///
+    /// # Errors
+    /// Returns an error if the output path does not exist or if the email
+    /// settings are invalid when emailing the reminder.
+    pub fn parsed(self) -> Result<ValidReminderInput> {
+        validate_output_path(self.out.as_ref())?;
+        let email_config = if self.email {
+            validate_email_data().map(Some)
+        } else {
+            Ok(None)
+        }?;
+        Ok(ValidReminderInput::builder()
+            .invoice(self.invoice)
+            .maybe_kind(self.kind.map(Into::into))
+            .maybe_client(self.client)
+            .language(self.language)
+            .layout(self.layout)
+            .date(self.date.unwrap_or_else(Date::today))
+            .maybe_maybe_output_path(self.out)
+            .maybe_email(email_config)
+            .build())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::input::{
-        DataAdminInputCommand, EditDataInputSelector, EditEmailInputSelector, ExpensesInput,
-        TimeOffInput, TimeUnitInput,
+        ClientIdInput, ClientInput, ClientInputCommand, DataAdminInputCommand,
+        EditDataInputSelector, EditEmailInputSelector, ExpenseAttachInput, ExpenseEditInput,
+        ExpenseIndexInput, ExpenseMoveInput, ExpensesInput, ExpensesInputCommand,
+        ExpensesListInput, TimeOffInput, TimeUnitInput,
     };
     use klirr_core_invoice::{
-        DataSelector, Decimal, EmailSettingsSelector, FromStr, Item, Quantity,
+        Currency, DataSelector, Decimal, EmailSettingsSelector, ExpenseCsvFormat, ExpenseSign,
+        FromStr, Item, PerDiemTrip, Quantity,
     };
 
     mod data_admin_input {
@@ -178,6 +427,42 @@ mod tests {
             assert!(matches!(input.command, Command::Data(_)));
         }
 
+        #[test]
+        fn test_data_admin_client_add() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "client", "add", "acme"]);
+            let data_input = input.command.unwrap_data();
+            let DataAdminInputCommand::Client(client_input) = data_input.command() else {
+                panic!("expected client command");
+            };
+            assert_eq!(
+                *client_input.command(),
+                ClientInputCommand::Add(
+                    ClientIdInput::builder()
+                        .id(ClientId::from_str("acme").unwrap())
+                        .build()
+                )
+            );
+        }
+
+        #[test]
+        fn test_data_admin_client_list() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "client", "list"]);
+            assert_eq!(
+                *input.command.unwrap_data().command(),
+                DataAdminInputCommand::Client(
+                    ClientInput::builder()
+                        .command(ClientInputCommand::List)
+                        .build()
+                )
+            );
+        }
+
+        #[test]
+        fn test_data_admin_client_remove_requires_id() {
+            let result = CliArgs::try_parse_from([BINARY_NAME, "data", "client", "remove"]);
+            assert!(result.is_err());
+        }
+
         #[test]
         fn test_data_admin_expense() {
             let item_1_str = "Coffee,2.5,EUR,3.0,2025-05-31";
@@ -197,12 +482,285 @@ mod tests {
             ]);
             assert_eq!(
                 *input.command.unwrap_data().command(),
-                DataAdminInputCommand::Expenses(
+                DataAdminInputCommand::Expenses(Box::new(
                     ExpensesInput::builder()
                         .period("2025-05".to_owned())
                         .expenses(vec![item_1, item_2])
                         .build()
-                )
+                ))
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_mileage_and_per_diem() {
+            let mileage_str = "Client visit,120,km,0.25,EUR,2025-05-12";
+            let per_diem_str = "Berlin workshop,DE,3,2025-05-14,2,1,0";
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "--period",
+                "2025-05",
+                "--mileage",
+                mileage_str,
+                "--per-diem",
+                per_diem_str,
+            ]);
+            assert_eq!(
+                *input.command.unwrap_data().command(),
+                DataAdminInputCommand::Expenses(Box::new(
+                    ExpensesInput::builder()
+                        .period("2025-05".to_owned())
+                        .mileage(vec![Item::mileage_from_str(mileage_str).unwrap()])
+                        .per_diem(vec![PerDiemTrip::from_str(per_diem_str).unwrap()])
+                        .build()
+                ))
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_rejects_invalid_mileage_unit() {
+            let result = CliArgs::try_parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "--period",
+                "2025-05",
+                "--mileage",
+                "Client visit,120,yards,0.25,EUR,2025-05-12",
+            ]);
+            assert!(result.is_err());
+        }
+
+        fn assert_expenses_command(args: &[&str], expected: ExpensesInputCommand) {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "expenses"].iter().chain(args));
+            let data_input = input.command.unwrap_data();
+            let DataAdminInputCommand::Expenses(expenses_input) = data_input.command() else {
+                panic!("Expected expenses command");
+            };
+            assert_eq!(expenses_input.command().as_ref(), Some(&expected));
+        }
+
+        #[test]
+        fn test_data_admin_expenses_requires_period_without_subcommand() {
+            let result = CliArgs::try_parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "-e",
+                "Coffee,2.5,EUR,3.0,2025-05-31",
+            ]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_data_admin_expenses_list() {
+            assert_expenses_command(
+                &["list"],
+                ExpensesInputCommand::List(ExpensesListInput::builder().build()),
+            );
+            assert_expenses_command(
+                &["list", "-p", "2025-05"],
+                ExpensesInputCommand::List(
+                    ExpensesListInput::builder()
+                        .period("2025-05".to_owned())
+                        .build(),
+                ),
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_remove() {
+            assert_expenses_command(
+                &["remove", "-p", "2025-05", "-i", "2"],
+                ExpensesInputCommand::Remove(
+                    ExpenseIndexInput::builder()
+                        .period("2025-05".to_owned())
+                        .index(2)
+                        .build(),
+                ),
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_edit() {
+            let item_str = "Coffee,2.5,EUR,3.0,2025-05-31";
+            assert_expenses_command(
+                &["edit", "-p", "2025-05", "-i", "1", "-e", item_str],
+                ExpensesInputCommand::Edit(
+                    ExpenseEditInput::builder()
+                        .expense_index(
+                            ExpenseIndexInput::builder()
+                                .period("2025-05".to_owned())
+                                .index(1)
+                                .build(),
+                        )
+                        .expense(Item::from_str(item_str).unwrap())
+                        .build(),
+                ),
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_import() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "import",
+                "bank.csv",
+                "--dry-run",
+                "--delimiter",
+                ";",
+                "--name-column",
+                "Text",
+                "--unit-price-column",
+                "Amount",
+                "--date-column",
+                "Date",
+                "--currency",
+                "SEK",
+                "--negative-amounts",
+                "--date-format",
+                "%d.%m.%Y",
+                "--decimal-separator",
+                ",",
+            ]);
+            let data_input = input.command.unwrap_data();
+            let DataAdminInputCommand::Expenses(expenses_input) = data_input.command() else {
+                panic!("Expected expenses command");
+            };
+            let Some(ExpensesInputCommand::Import(import_input)) = expenses_input.command() else {
+                panic!("Expected import command");
+            };
+            assert_eq!(*import_input.file(), PathBuf::from("bank.csv"));
+            assert!(*import_input.dry_run());
+            assert_eq!(
+                import_input.format(),
+                ExpenseCsvFormat::builder()
+                    .delimiter(b';')
+                    .name_column("Text")
+                    .unit_price_column("Amount")
+                    .transaction_date_column("Date")
+                    .currency(Currency::SEK)
+                    .expense_sign(ExpenseSign::Negative)
+                    .date_format("%d.%m.%Y")
+                    .decimal_separator(',')
+                    .build()
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_import_rejects_invalid_delimiter() {
+            let result = CliArgs::try_parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "import",
+                "bank.csv",
+                "--delimiter",
+                ";;",
+            ]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_data_admin_expenses_markup_and_not_billable() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "-p",
+                "2025-05",
+                "-e",
+                "Hotel,90,EUR,2,2025-05-31",
+                "--markup",
+                "12.5%",
+                "--not-billable",
+            ]);
+            assert_eq!(
+                *input.command.unwrap_data().command(),
+                DataAdminInputCommand::Expenses(Box::new(
+                    ExpensesInput::builder()
+                        .period("2025-05".to_owned())
+                        .expenses(vec![Item::from_str("Hotel,90,EUR,2,2025-05-31").unwrap()])
+                        .markup(Decimal::from(rust_decimal::dec!(12.5)))
+                        .not_billable(true)
+                        .build()
+                ))
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_rejects_negative_markup() {
+            let result = CliArgs::try_parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "-p",
+                "2025-05",
+                "--markup",
+                "-5",
+            ]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_data_admin_expenses_attach() {
+            assert_expenses_command(
+                &[
+                    "attach",
+                    "-p",
+                    "2025-05",
+                    "-i",
+                    "2",
+                    "hotel.pdf",
+                    "taxi.jpg",
+                ],
+                ExpensesInputCommand::Attach(
+                    ExpenseAttachInput::builder()
+                        .expense_index(
+                            ExpenseIndexInput::builder()
+                                .period("2025-05".to_owned())
+                                .index(2)
+                                .build(),
+                        )
+                        .files(vec![PathBuf::from("hotel.pdf"), PathBuf::from("taxi.jpg")])
+                        .build(),
+                ),
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_attach_requires_file() {
+            let result = CliArgs::try_parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "attach",
+                "-p",
+                "2025-05",
+                "-i",
+                "2",
+            ]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_data_admin_expenses_move() {
+            assert_expenses_command(
+                &["move", "-p", "2025-05", "-i", "1", "--to", "2025-06"],
+                ExpensesInputCommand::Move(
+                    ExpenseMoveInput::builder()
+                        .expense_index(
+                            ExpenseIndexInput::builder()
+                                .period("2025-05".to_owned())
+                                .index(1)
+                                .build(),
+                        )
+                        .to("2025-06".to_owned())
+                        .build(),
+                ),
             );
         }
     }
@@ -272,12 +830,64 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn test_input_parsing_items_specified_combined() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "combined"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().items,
+                    Some(TargetItems::Combined)
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_items_specified_combined_off() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "combined-off",
+                    "--quantity",
+                    "2",
+                    "--unit",
+                    "days",
+                ]);
+                assert_eq!(
+                    input.command.unwrap_invoice().items,
+                    Some(TargetItems::CombinedOff(
+                        TimeOffInput::builder()
+                            .quantity(2.0)
+                            .unit(TimeUnitInput::Days)
+                            .build()
+                    ))
+                );
+            }
+
             #[test]
             fn test_input_parsing_items_default() {
                 let input = CliArgs::parse_from([BINARY_NAME, "invoice"]);
                 assert_eq!(input.command.unwrap_invoice().items, None);
             }
 
+            #[test]
+            fn test_input_parsing_client_specified() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "--client", "acme"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().client,
+                    Some(ClientId::from_str("acme").unwrap())
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_client_invalid() {
+                let result = CliArgs::try_parse_from([BINARY_NAME, "invoice", "--client", "Acme"]);
+                assert!(result.is_err());
+            }
+
+            #[test]
+            fn test_input_parsing_client_default() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice"]);
+                assert_eq!(input.command.unwrap_invoice().client, None);
+            }
+
             #[test]
             fn test_input_parsing_out_specified() {
                 let input =
@@ -323,6 +933,41 @@ mod tests {
                 let input = CliArgs::parse_from([BINARY_NAME, "invoice"]);
                 assert!(!input.command.unwrap_invoice().refresh_holidays);
             }
+
+            #[test]
+            fn test_input_parsing_timesheet() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "--timesheet",
+                    "hours.csv",
+                    "--itemize-timesheet",
+                ]);
+                let invoice = input.command.unwrap_invoice();
+                assert_eq!(invoice.timesheet, Some(PathBuf::from("hours.csv")));
+                assert!(invoice.itemize_timesheet);
+            }
+
+            #[test]
+            fn test_input_parsing_receipts() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().receipts,
+                    ReceiptPlacementInput::Pages
+                );
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "--receipts", "embedded"]);
+                assert_eq!(
+                    ReceiptPlacement::from(input.command.unwrap_invoice().receipts),
+                    ReceiptPlacement::Embedded
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_itemize_timesheet_requires_timesheet() {
+                let result =
+                    CliArgs::try_parse_from([BINARY_NAME, "invoice", "--itemize-timesheet"]);
+                assert!(result.is_err());
+            }
         }
 
         mod tests_parsed_input {
@@ -357,6 +1002,13 @@ mod tests {
                 assert_eq!(*input.items(), InvoicedItems::Expenses);
             }
 
+            #[test]
+            fn test_input_parsing_items_combined() {
+                let input = InvoiceInput::builder().items(TargetItems::Combined).build();
+                let input = input.parsed(Cadence::Monthly).unwrap();
+                assert_eq!(*input.items(), InvoicedItems::Combined { time_off: None });
+            }
+
             #[test]
             fn test_input_parsing_out() {
                 let input = InvoiceInput::builder()
@@ -369,6 +1021,15 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn test_input_parsing_client_threads_to_valid_input() {
+                let input = InvoiceInput::builder()
+                    .client(ClientId::from_str("acme").unwrap())
+                    .build();
+                let input = input.parsed(Cadence::Monthly).unwrap();
+                assert_eq!(*input.client(), Some(ClientId::from_str("acme").unwrap()));
+            }
+
             #[test]
             fn test_input_parsing_worked_holidays_threads_to_valid_input() {
                 let input = InvoiceInput::builder().worked_holidays(true).build();
@@ -383,6 +1044,29 @@ mod tests {
                 assert!(!*input.worked_holidays());
             }
 
+            #[test]
+            fn test_input_parsing_timesheet_threads_to_valid_input() {
+                let tempdir = tempfile::tempdir().unwrap();
+                let path = tempdir.path().join("hours.csv");
+                std::fs::write(&path, "date,hours,description\n2025-05-12,7.5,Review\n").unwrap();
+                let input = InvoiceInput::builder()
+                    .timesheet(path)
+                    .itemize_timesheet(true)
+                    .build();
+                let input = input.parsed(Cadence::Monthly).unwrap();
+                let timesheet = input.timesheet().as_ref().unwrap();
+                assert_eq!(timesheet.entries().len(), 1);
+                assert!(timesheet.itemized());
+            }
+
+            #[test]
+            fn test_input_parsing_timesheet_fails_when_file_is_missing() {
+                let input = InvoiceInput::builder()
+                    .timesheet(PathBuf::from("/tmp/klirr-missing-timesheet.csv"))
+                    .build();
+                assert!(input.parsed(Cadence::Monthly).is_err());
+            }
+
             #[test]
             fn test_input_parsing_refresh_holidays_threads_to_valid_input() {
                 let input = InvoiceInput::builder().refresh_holidays(true).build();
@@ -399,6 +1083,199 @@ mod tests {
         }
     }
 
+    mod ledger_input {
+        use super::*;
+        use crate::input::{LedgerInputCommand, LedgerShowInput};
+        use test_log::test;
+
+        #[test]
+        fn test_ledger_list() {
+            let input = CliArgs::parse_from([BINARY_NAME, "ledger", "list"]);
+            assert_eq!(
+                *input.command.unwrap_ledger().command(),
+                LedgerInputCommand::List
+            );
+        }
+
+        #[test]
+        fn test_ledger_show() {
+            let input = CliArgs::parse_from([BINARY_NAME, "ledger", "show", "42"]);
+            assert_eq!(
+                *input.command.unwrap_ledger().command(),
+                LedgerInputCommand::Show(
+                    LedgerShowInput::builder()
+                        .number(InvoiceNumber::from(42))
+                        .build()
+                )
+            );
+        }
+
+        #[test]
+        fn test_ledger_show_requires_number() {
+            let result = CliArgs::try_parse_from([BINARY_NAME, "ledger", "show"]);
+            assert!(result.is_err());
+        }
+    }
+
+    mod payment_input {
+        use super::*;
+        use crate::StatusInput;
+        use crate::input::{
+            InvoiceKindInput, InvoiceOfPaymentInput, PaymentInputCommand, PaymentOfInvoiceInput,
+        };
+        use test_log::test;
+
+        #[test]
+        fn test_payment_paid_defaults() {
+            let input = CliArgs::parse_from([BINARY_NAME, "payment", "paid", "--invoice", "42"]);
+            assert_eq!(
+                *input.command.unwrap_payment().command(),
+                PaymentInputCommand::Paid(
+                    PaymentOfInvoiceInput::builder()
+                        .invoice(
+                            InvoiceOfPaymentInput::builder()
+                                .invoice(InvoiceNumber::from(42))
+                                .build()
+                        )
+                        .build()
+                )
+            );
+        }
+
+        #[test]
+        fn test_payment_partially_paid() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "payment",
+                "partially-paid",
+                "-i",
+                "42",
+                "--kind",
+                "expenses",
+                "--date",
+                "2025-06-15",
+                "--amount",
+                "500",
+            ]);
+            let payment = input.command.unwrap_payment();
+            let PaymentInputCommand::PartiallyPaid(input) = payment.command() else {
+                panic!("Expected partially paid, got: {:?}", payment.command());
+            };
+            assert_eq!(*input.amount(), Decimal::from(500));
+            assert_eq!(*input.invoice().kind(), Some(InvoiceKindInput::Expenses));
+            assert_eq!(
+                input.invoice().date_or_today(),
+                Date::from_str("2025-06-15").unwrap()
+            );
+        }
+
+        #[test]
+        fn test_payment_partially_paid_requires_amount() {
+            let result =
+                CliArgs::try_parse_from([BINARY_NAME, "payment", "partially-paid", "-i", "42"]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_payment_written_off() {
+            let input = CliArgs::parse_from([BINARY_NAME, "payment", "written-off", "-i", "42"]);
+            let payment = input.command.unwrap_payment();
+            let PaymentInputCommand::WrittenOff(input) = payment.command() else {
+                panic!("Expected written off, got: {:?}", payment.command());
+            };
+            assert_eq!(*input.invoice(), InvoiceNumber::from(42));
+        }
+
+        #[test]
+        fn test_status_defaults_to_overdue() {
+            let input = CliArgs::parse_from([BINARY_NAME, "status"]);
+            assert_eq!(
+                input.command.unwrap_status(),
+                StatusInput::builder().build()
+            );
+        }
+
+        #[test]
+        fn test_status_all() {
+            let input = CliArgs::parse_from([BINARY_NAME, "status", "--all"]);
+            assert!(*input.command.unwrap_status().all());
+        }
+    }
+
+    mod credit_note_input {
+        use super::*;
+        use test_log::test;
+
+        #[test]
+        fn test_credit_note_parsing_invoice_and_lines() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "credit-note",
+                "--invoice",
+                "42",
+                "--lines",
+                "1,3",
+            ]);
+            let input = input.command.unwrap_credit_note();
+            assert_eq!(*input.invoice(), InvoiceNumber::from(42));
+            assert_eq!(*input.lines(), vec![1, 3]);
+            assert_eq!(*input.items(), None);
+        }
+
+        #[test]
+        fn test_credit_note_requires_invoice() {
+            let result = CliArgs::try_parse_from([BINARY_NAME, "credit-note"]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_credit_note_parsing_items_and_client() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "credit-note",
+                "-i",
+                "7",
+                "--client",
+                "acme",
+                "expenses",
+            ]);
+            let input = input.command.unwrap_credit_note();
+            assert_eq!(*input.items(), Some(TargetItems::Expenses));
+            assert_eq!(*input.client(), Some(ClientId::from_str("acme").unwrap()));
+        }
+
+        #[test]
+        fn test_credit_note_parsed_is_issued_today_for_all_lines() {
+            let input = CreditNoteInput::builder()
+                .invoice(InvoiceNumber::from(42))
+                .build()
+                .parsed()
+                .unwrap();
+            assert_eq!(*input.invoice(), InvoiceNumber::from(42));
+            assert!(input.lines().is_empty());
+            assert_eq!(*input.items(), InvoicedItems::Service { time_off: None });
+            assert_eq!(*input.date(), Date::today());
+            assert!(input.timesheet().is_none());
+        }
+
+        #[test]
+        fn test_credit_note_parsed_reads_timesheet() {
+            let tempdir = tempfile::tempdir().unwrap();
+            let path = tempdir.path().join("hours.csv");
+            std::fs::write(&path, "date,hours,description\n2025-05-12,7.5,Review\n").unwrap();
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "credit-note",
+                "-i",
+                "42",
+                "--timesheet",
+                path.to_str().unwrap(),
+            ]);
+            let input = input.command.unwrap_credit_note().parsed().unwrap();
+            assert_eq!(input.timesheet().as_ref().unwrap().entries().len(), 1);
+        }
+    }
+
     #[test]
     fn test_data_selector_from_edit_data_input_selector() {
         let selector = EditDataInputSelector::Vendor;
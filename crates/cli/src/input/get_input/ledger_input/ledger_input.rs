@@ -0,0 +1,36 @@
+use clap::Args;
+use clap::Subcommand;
+use derive_more::Unwrap;
+
+use crate::InvoiceNumber;
+use bon::Builder;
+use getset::Getters;
+
+/// Browse the ledger of issued invoices, see [`LedgerInputCommand`].
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct LedgerInput {
+    /// Ledger command, see [`LedgerInputCommand`].
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: LedgerInputCommand,
+}
+
+/// Ledger subcommands, list all issued invoices or show one of them.
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum LedgerInputCommand {
+    /// Lists all issued invoices, oldest first.
+    List,
+
+    /// Shows all details of the issued invoices with a number.
+    Show(LedgerShowInput),
+}
+
+/// Identifies issued invoices in the ledger by number.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct LedgerShowInput {
+    /// The invoice number, e.g. `42`. Services and expenses invoices of
+    /// different periods may share a number, all of them are shown.
+    #[arg(value_name = "NUMBER")]
+    #[getset(get = "pub")]
+    number: InvoiceNumber,
+}
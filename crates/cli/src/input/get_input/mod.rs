@@ -3,10 +3,23 @@ mod email_input;
 mod expenses_input;
 #[allow(clippy::module_inception)]
 mod get_input;
+mod ledger_input;
+mod payment_input;
 
 pub use data_admin_input::{
-    DataAdminInput, DataAdminInputCommand, EditDataInput, EditDataInputSelector, PeriodOffInput,
+    ClientIdInput, ClientInput, ClientInputCommand, DataAdminInput, DataAdminInputCommand,
+    EditDataInput, EditDataInputSelector, PeriodOffInput,
 };
 pub use email_input::{EditEmailInput, EditEmailInputSelector, EmailInput, EmailInputCommand};
-pub use expenses_input::ExpensesInput;
-pub use get_input::{CliArgs, Command, InvoiceInput};
+pub use expenses_input::{
+    ExpenseAttachInput, ExpenseEditInput, ExpenseIndexInput, ExpenseMoveInput, ExpensesImportInput,
+    ExpensesInput, ExpensesInputCommand, ExpensesListInput,
+};
+pub use get_input::{
+    CliArgs, Command, CreditNoteInput, InvoiceInput, ReceiptPlacementInput, ReminderInput,
+};
+pub use ledger_input::{LedgerInput, LedgerInputCommand, LedgerShowInput};
+pub use payment_input::{
+    InvoiceKindInput, InvoiceOfPaymentInput, PartialPaymentOfInvoiceInput, PaymentInput,
+    PaymentInputCommand, PaymentOfInvoiceInput, StatusInput,
+};
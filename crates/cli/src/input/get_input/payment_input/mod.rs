@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+mod payment_input;
+
+pub use payment_input::{
+    InvoiceKindInput, InvoiceOfPaymentInput, PartialPaymentOfInvoiceInput, PaymentInput,
+    PaymentInputCommand, PaymentOfInvoiceInput, StatusInput,
+};
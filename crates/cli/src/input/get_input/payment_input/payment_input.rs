@@ -0,0 +1,121 @@
+use clap::Args;
+use clap::Subcommand;
+use derive_more::Unwrap;
+
+use crate::{ClientId, Date, Decimal, InvoiceKind, InvoiceNumber};
+use bon::Builder;
+use getset::Getters;
+
+/// Record payments of issued invoices, see [`PaymentInputCommand`].
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct PaymentInput {
+    /// Payment command, see [`PaymentInputCommand`].
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: PaymentInputCommand,
+}
+
+/// Payment subcommands, mark an issued invoice as paid, partially paid or
+/// written off.
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum PaymentInputCommand {
+    /// Marks an invoice as paid in full, by default paying the outstanding
+    /// amount.
+    Paid(PaymentOfInvoiceInput),
+
+    /// Records a partial payment of an invoice.
+    PartiallyPaid(PartialPaymentOfInvoiceInput),
+
+    /// Writes off the outstanding amount of an invoice, which then is no
+    /// longer overdue.
+    WrittenOff(InvoiceOfPaymentInput),
+}
+
+/// The kind of invoice, used to tell invoices sharing a number apart.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum InvoiceKindInput {
+    Services,
+    Expenses,
+    Combined,
+}
+
+impl From<InvoiceKindInput> for InvoiceKind {
+    fn from(input: InvoiceKindInput) -> Self {
+        match input {
+            InvoiceKindInput::Services => Self::Services,
+            InvoiceKindInput::Expenses => Self::Expenses,
+            InvoiceKindInput::Combined => Self::Combined,
+        }
+    }
+}
+
+/// Identifies an issued invoice in the ledger and when it was settled.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct InvoiceOfPaymentInput {
+    /// The invoice number, e.g. `42`.
+    #[arg(long, short = 'i')]
+    #[getset(get = "pub")]
+    invoice: InvoiceNumber,
+
+    /// The kind of the invoice, only needed if a services and an expenses
+    /// invoice share the number.
+    #[arg(long, short = 'k')]
+    #[getset(get = "pub")]
+    kind: Option<InvoiceKindInput>,
+
+    /// The client of the invoice, by its id in the client registry. The
+    /// default client if omitted.
+    #[arg(long, short = 'c')]
+    #[getset(get = "pub")]
+    client: Option<ClientId>,
+
+    /// The date of the payment, e.g. `2025-05-31`, today if omitted.
+    #[arg(long, short = 'd')]
+    #[getset(get = "pub")]
+    date: Option<Date>,
+}
+
+impl InvoiceOfPaymentInput {
+    /// The date of the payment, today if none was specified.
+    pub fn date_or_today(&self) -> Date {
+        self.date.unwrap_or_else(Date::today)
+    }
+}
+
+/// A payment in full of an issued invoice.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct PaymentOfInvoiceInput {
+    /// The paid invoice.
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    invoice: InvoiceOfPaymentInput,
+
+    /// The paid amount, the outstanding amount if omitted.
+    #[arg(long, short = 'a')]
+    #[getset(get = "pub")]
+    amount: Option<Decimal>,
+}
+
+/// A partial payment of an issued invoice.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct PartialPaymentOfInvoiceInput {
+    /// The partially paid invoice.
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    invoice: InvoiceOfPaymentInput,
+
+    /// The paid amount, e.g. `500`.
+    #[arg(long, short = 'a')]
+    #[getset(get = "pub")]
+    amount: Decimal,
+}
+
+/// Lists the payment status of issued invoices.
+#[derive(Debug, Args, Getters, PartialEq, Builder)]
+pub struct StatusInput {
+    /// List all issued invoices with their status, not only the overdue.
+    #[arg(long, short = 'a')]
+    #[builder(default = false)]
+    #[getset(get = "pub")]
+    all: bool,
+}
@@ -13,6 +13,11 @@ pub enum TargetItems {
     /// Expenses mode, specify expenses in `input/data/expenses.json` for the
     /// target month.
     Expenses,
+    /// Combined mode, services and the expenses of the target period on a
+    /// single invoice, with no time off
+    Combined,
+    /// Combined mode with time off specification
+    CombinedOff(TimeOffInput),
 }
 
 impl HasSample for TargetItems {
@@ -7,17 +7,17 @@ use derive_more::FromStr;
 #[command(name = "invoice")]
 #[command(about = "Generate an invoice PDF", long_about = None)]
 pub enum TargetPeriod {
-    /// Current period, e.g. current month or current fortnight
+    /// Current period, e.g. current month, fortnight, week or quarter
     Current,
     #[default]
-    /// Last period, e.g. last month or last fortnight
+    /// Last period, e.g. last month, fortnight, week or quarter
     Last,
 }
 
 impl TargetPeriod {
     pub fn relative_time_for_cadence(&self, cadence: Cadence) -> RelativeTime {
         let unit = cadence.max_granularity();
-        debug_assert!(matches!(unit, Granularity::Month | Granularity::Fortnight));
+        debug_assert!(!matches!(unit, Granularity::Day | Granularity::Hour));
         match self {
             Self::Current => RelativeTime::current(unit),
             Self::Last => RelativeTime::last(unit),
@@ -67,4 +67,11 @@ mod tests {
         assert_eq!(*relative.unit(), Granularity::Fortnight);
         assert_eq!(*relative.amount(), 0);
     }
+
+    #[test]
+    fn relative_time_for_cadence_iso_biweekly_last() {
+        let relative = Sut::Last.relative_time_for_cadence(Cadence::IsoBiWeekly);
+        assert_eq!(*relative.unit(), Granularity::TwoWeeks);
+        assert_eq!(*relative.amount(), -1);
+    }
 }
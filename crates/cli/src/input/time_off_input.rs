@@ -1,6 +1,6 @@
 use clap::Args;
 
-use crate::{Decimal, HasSample, Quantity, Result, TimeOff, TimeUnitInput};
+use crate::{DaysOff, Decimal, HasSample, Quantity, Result, TimeOff, TimeUnitInput};
 use bon::Builder;
 use getset::Getters;
 
@@ -8,21 +8,45 @@ use getset::Getters;
 #[derive(Args, Debug, Clone, PartialEq, Builder, Getters)]
 pub struct TimeOffInput {
     /// Number of hours or days off
-    #[arg(short, long)]
+    #[arg(short, long, required_unless_present = "off", conflicts_with = "off")]
     #[getset(get = "pub")]
-    quantity: f64,
+    quantity: Option<f64>,
 
     /// Unit of time (hours or days)
     #[arg(short, long, value_enum, default_value = "days")]
+    #[builder(default = TimeUnitInput::Days)]
     #[getset(get = "pub")]
     unit: TimeUnitInput,
+
+    /// Dates off instead of a quantity, a date, e.g. `2025-05-20`, a range of
+    /// dates, e.g. `2025-05-12..2025-05-16`, or either followed by `:half` for
+    /// half days, e.g. `2025-05-20:half`. Can be repeated.
+    ///
+    /// Only dates off on working days, which are not bank holidays, are
+    /// deducted.
+    #[arg(long)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    off: Vec<DaysOff>,
+
+    /// List the dates off on the invoice.
+    #[arg(long, requires = "off")]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    list_off: bool,
 }
 
 impl TryFrom<TimeOffInput> for TimeOff {
     type Error = klirr_core_invoice::Error;
 
     fn try_from(input: TimeOffInput) -> Result<Self, Self::Error> {
-        let decimal = Decimal::try_from(input.quantity)?;
+        if !input.off.is_empty() {
+            return Ok(TimeOff::Dates {
+                days_off: input.off.into_iter().collect(),
+                listed: input.list_off,
+            });
+        }
+        let decimal = Decimal::try_from(input.quantity.unwrap_or_default())?;
         let quantity = Quantity::from(decimal);
         match input.unit {
             TimeUnitInput::Hours => Ok(TimeOff::Hours(quantity)),
@@ -63,4 +87,21 @@ mod tests {
     fn inequality() {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
+
+    #[test]
+    fn dates_off_are_merged() {
+        let sut = Sut::builder()
+            .off(vec![DaysOff::sample(), DaysOff::sample_other()])
+            .list_off(true)
+            .build();
+        assert_eq!(
+            TimeOff::try_from(sut).unwrap(),
+            TimeOff::Dates {
+                days_off: [DaysOff::sample(), DaysOff::sample_other()]
+                    .into_iter()
+                    .collect(),
+                listed: true,
+            }
+        );
+    }
 }
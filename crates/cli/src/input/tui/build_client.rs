@@ -0,0 +1,16 @@
+use crate::{
+    CompanyInformation, Data, Result, TimestampedInvoiceNumber, build_company,
+    build_invoice_number_offset, config_render,
+};
+
+/// Asks for the information and invoice number offset of a client to add to
+/// the client registry, using `data` for defaults.
+pub fn ask_for_client(data: &Data) -> Result<(CompanyInformation, TimestampedInvoiceNumber)> {
+    config_render();
+
+    let client = build_company("Your client", &CompanyInformation::sample_client())?;
+    let offset =
+        build_invoice_number_offset(data.information().offset(), *data.service_fees().cadence())?;
+
+    Ok((client, offset))
+}
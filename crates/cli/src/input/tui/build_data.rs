@@ -37,6 +37,7 @@ pub fn ask_for_data(default: Data, data_selector: Option<DataSelector>) -> Resul
 
     let data = Data::builder()
         .client(client)
+        .clients(default.clients().clone())
         .vendor(vendor)
         .payment_info(payment_info)
         .service_fees(service_fees)
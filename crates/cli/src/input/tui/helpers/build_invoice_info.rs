@@ -6,32 +6,50 @@ use crate::{
     format_help_skippable,
 };
 
+fn build_invoice_number_offset_inner(
+    default: &TimestampedInvoiceNumber,
+    cadence: Cadence,
+) -> InquireResult<TimestampedInvoiceNumber> {
+    let invoice_number_offset = CustomType::<InvoiceNumber>::new(
+        "What is the last invoice number you issued? We call this the 'offset'",
+    )
+    .with_help_message(&format_help_skippable(
+        "Used with the date of that invoice to calculate future invoice numbers.".to_owned(),
+    ))
+    .with_default(default.offset().clone())
+    .prompt_skippable()?
+    .unwrap_or_default();
+
+    let invoice_number_offset_period = build_period(
+        "When was that invoice issued? (Used to calculate future invoice numbers)".to_owned(),
+        Some(*default.date()),
+        cadence,
+    )?
+    .unwrap_or(*default.date());
+
+    Ok(TimestampedInvoiceNumber::builder()
+        .offset(invoice_number_offset)
+        .date(invoice_number_offset_period)
+        .build())
+}
+
+/// Asks for the last issued invoice number and its period, used as offset to
+/// calculate future invoice numbers.
+pub fn build_invoice_number_offset(
+    default: &TimestampedInvoiceNumber,
+    cadence: Cadence,
+) -> Result<TimestampedInvoiceNumber> {
+    build_invoice_number_offset_inner(default, cadence)
+        .map_err(InvoiceDataFromTuiError::invalid_invoice_info)
+        .map_err(crate::Error::from)
+}
+
 pub fn build_invoice_info(
     default: &ProtoInvoiceInfo,
     cadence: Cadence,
 ) -> Result<ProtoInvoiceInfo> {
     fn inner(default: &ProtoInvoiceInfo, cadence: Cadence) -> InquireResult<ProtoInvoiceInfo> {
-        let invoice_number_offset = CustomType::<InvoiceNumber>::new(
-            "What is the last invoice number you issued? We call this the 'offset'",
-        )
-        .with_help_message(&format_help_skippable(
-            "Used with the date of that invoice to calculate future invoice numbers.".to_owned(),
-        ))
-        .with_default(default.offset().offset().clone())
-        .prompt_skippable()?
-        .unwrap_or_default();
-
-        let invoice_number_offset_period = build_period(
-            "When was that invoice issued? (Used to calculate future invoice numbers)".to_owned(),
-            Some(*default.offset().date()),
-            cadence,
-        )?
-        .unwrap_or(*default.offset().date());
-
-        let offset = TimestampedInvoiceNumber::builder()
-            .offset(invoice_number_offset)
-            .date(invoice_number_offset_period)
-            .build();
+        let offset = build_invoice_number_offset_inner(default.offset(), cadence)?;
 
         let purchase_order = CustomType::<PurchaseOrder>::new("Purchase order number (optional)")
             .with_optional_default(default.purchase_order())
@@ -61,6 +79,7 @@ pub fn build_invoice_info(
             .maybe_footer_text(footer_text)
             .maybe_emphasize_color_hex(emphasize_color_hex)
             .record_of_periods_off(default.record_of_periods_off().clone())
+            .client_offsets(default.client_offsets().clone())
             .build();
 
         Ok(info)
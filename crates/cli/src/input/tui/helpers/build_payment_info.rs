@@ -1,6 +1,9 @@
 use inquire::{CustomType, Text, error::InquireResult};
 
-use crate::{Currency, InvoiceDataFromTuiError, PaymentInformation, PaymentTerms, Result, Vat};
+use crate::{
+    Currency, Decimal, InvoiceDataFromTuiError, LatePaymentCharges, PaymentInformation,
+    PaymentTerms, Result, Vat,
+};
 
 pub fn build_payment_info(default: &PaymentInformation) -> Result<PaymentInformation> {
     fn inner(default: &PaymentInformation) -> InquireResult<PaymentInformation> {
@@ -22,7 +25,9 @@ pub fn build_payment_info(default: &PaymentInformation) -> Result<PaymentInforma
             .prompt()?;
 
         let payment_terms = CustomType::<PaymentTerms>::new("Payment terms?")
-            .with_help_message("The payment terms for this invoice, e.g. 'Net 30'")
+            .with_help_message(
+                "The payment terms for this invoice, e.g. 'Net 30', 'Net 30 EOM', 'Due on receipt', 'Day 15 next month' or '2/10 Net 30'",
+            )
             .with_default(PaymentTerms::net30())
             .prompt()?;
 
@@ -35,6 +40,35 @@ pub fn build_payment_info(default: &PaymentInformation) -> Result<PaymentInforma
             .with_default(*default.vat())
             .prompt()?;
 
+        let reporting_currency = {
+            let prompt = CustomType::<Currency>::new("Reporting currency?").with_help_message(
+                "The currency you report VAT in, if other than the invoice currency, e.g. 'SEK'. \
+                 Totals are then also shown converted at the rate of the invoice date. \
+                 Press ESC for none.",
+            );
+            match default.reporting_currency() {
+                Some(currency) => prompt.with_default(*currency),
+                None => prompt,
+            }
+            .prompt_skippable()?
+        };
+
+        let annual_interest_rate = CustomType::<Decimal>::new("Late-payment interest?")
+            .with_help_message(
+                "Annual interest rate in percent charged on overdue invoices in payment \
+                 reminders, e.g. '10.5' for 10.5% per year. Enter '0' to charge no interest.",
+            )
+            .with_default(*default.late_payment().annual_interest_rate())
+            .prompt()?;
+
+        let reminder_fee = CustomType::<Decimal>::new("Reminder fee?")
+            .with_help_message(
+                "Fixed fee charged per payment reminder, in the currency of the invoice, \
+                 e.g. '60'. Enter '0' to charge no fee.",
+            )
+            .with_default(*default.late_payment().reminder_fee())
+            .prompt()?;
+
         let payment_info = default
             .clone()
             .with_bank_name(bank_name)
@@ -42,7 +76,14 @@ pub fn build_payment_info(default: &PaymentInformation) -> Result<PaymentInforma
             .with_bic(bic)
             .with_currency(currency)
             .with_terms(payment_terms)
-            .with_vat(vat);
+            .with_vat(vat)
+            .with_reporting_currency(reporting_currency)
+            .with_late_payment(
+                LatePaymentCharges::builder()
+                    .annual_interest_rate(annual_interest_rate)
+                    .reminder_fee(reminder_fee)
+                    .build(),
+            );
 
         Ok(payment_info)
     }
@@ -1,8 +1,8 @@
-use inquire::{CustomType, error::InquireResult};
+use inquire::{CustomType, InquireError, error::InquireResult};
 
 use crate::{
     Cadence, Date, Day, Month, MonthHalf, WithOptionalDefault, WithPossibleValues,
-    build_year_month_inner,
+    build_year_month_inner, normalize_period_end_date_for_cadence,
 };
 
 fn first_half_end_day(month: Month) -> Day {
@@ -56,5 +56,35 @@ pub fn build_period(
                 Date::builder().year(year).month(month).day(day).build(),
             ))
         }
+        Cadence::Quarterly => normalize(
+            Date::builder()
+                .year(year)
+                .month(month)
+                .day(month.last_day(year))
+                .build(),
+            cadence,
+        ),
+        Cadence::Weekly | Cadence::IsoBiWeekly => {
+            let day = CustomType::<Day>::new("Any day in the period?")
+                .with_help_message("The period containing this day is used")
+                .with_optional_default(&default.and_then(|d| {
+                    if d.month() == &month {
+                        Some(*d.day())
+                    } else {
+                        None
+                    }
+                }))
+                .prompt()?;
+            normalize(
+                Date::builder().year(year).month(month).day(day).build(),
+                cadence,
+            )
+        }
     }
 }
+
+fn normalize(date: Date, cadence: Cadence) -> InquireResult<Option<Date>> {
+    normalize_period_end_date_for_cadence(date, cadence)
+        .map(Some)
+        .map_err(|e| InquireError::Custom(Box::new(e)))
+}
@@ -1,4 +1,4 @@
-use inquire::{Confirm, CustomType, Text, error::InquireResult};
+use inquire::{Confirm, CustomType, InquireError, Text, error::InquireResult};
 
 use crate::{
     Cadence, Granularity, InvoiceDataFromTuiError, Rate, Result, ServiceFees, UnitPrice,
@@ -17,7 +17,7 @@ pub fn build_service_fees(default: &ServiceFees) -> Result<ServiceFees> {
             .with_default(*default.cadence())
             .prompt()?;
 
-        let granularity = CustomType::<Granularity>::new("Do you invoice per month, day or hour? Next question will be the rate which is per time unit you provide here")
+        let granularity = CustomType::<Granularity>::new("Do you invoice per quarter, month, week, day or hour? Next question will be the rate which is per time unit you provide here")
           .with_help_possible_values()
             .with_default(default.rate().granularity())
             .prompt()?;
@@ -42,13 +42,16 @@ pub fn build_service_fees(default: &ServiceFees) -> Result<ServiceFees> {
             .with_default(*default.off_on_bank_holidays())
             .prompt()?;
 
-        Ok(ServiceFees::builder()
+        ServiceFees::builder()
             .name(name)
             .cadence(cadence)
             .rate(rate)
             .off_on_bank_holidays(off_on_bank_holidays)
+            .additional_services(default.additional_services().clone())
+            .rate_history(default.rate_history().clone())
+            .maybe_retainer(default.retainer().clone())
             .build()
-            .unwrap())
+            .map_err(|e| InquireError::Custom(Box::new(e)))
     }
     inner(default)
         .map_err(InvoiceDataFromTuiError::invalid_service_fees)
@@ -20,7 +20,7 @@ pub use build_email_address::{
     ask_for_many_email_addresses,
 };
 pub use build_email_template::ask_for_template;
-pub use build_invoice_info::build_invoice_info;
+pub use build_invoice_info::{build_invoice_info, build_invoice_number_offset};
 pub use build_password::{
     ask_for_email_encryption_password_with_confirmation, ask_for_password,
     get_email_encryption_password,
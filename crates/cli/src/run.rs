@@ -1,8 +1,9 @@
 use crate::migration_guides::requires_manual_data_migration;
 use crate::{
     CliArgs, CliResult, Command, Error, curry1, data_dir, email_settings_path,
-    render_invoice_sample, render_invoice_sample_with_nonce, run_data_command, run_email_command,
-    run_invoice_command,
+    render_invoice_sample, render_invoice_sample_with_nonce, run_credit_note_command,
+    run_data_command, run_email_command, run_invoice_command, run_ledger_command,
+    run_payment_command, run_reminder_command, run_status_command,
 };
 use log::{error, warn};
 use std::path::Path;
@@ -83,6 +84,31 @@ pub fn run(input: CliArgs) -> CliResult<()> {
                 .inspect_err(|e| log_data_setup_hint_or_error("Error creating PDF", e))?;
             open_file_at(outcome.saved_at());
         }
+        Command::CreditNote(credit_note_input) => {
+            let outcome = run_credit_note_command(credit_note_input)
+                .inspect_err(|e| log_data_setup_hint_or_error("Error creating PDF", e))?;
+            open_file_at(outcome.saved_at());
+        }
+        Command::Reminder(reminder_input) => {
+            let outcome = run_reminder_command(reminder_input)
+                .inspect_err(|e| log_data_setup_hint_or_error("Error creating PDF", e))?;
+            open_file_at(outcome.saved_at());
+        }
+        Command::Ledger(ledger_input) => {
+            run_ledger_command(ledger_input.command()).inspect_err(|e| {
+                log_data_setup_hint_or_error("Error running ledger command", e);
+            })?;
+        }
+        Command::Payment(payment_input) => {
+            run_payment_command(payment_input.command()).inspect_err(|e| {
+                log_data_setup_hint_or_error("Error running payment command", e);
+            })?;
+        }
+        Command::Status(status_input) => {
+            run_status_command(&status_input).inspect_err(|e| {
+                log_data_setup_hint_or_error("Error running status command", e);
+            })?;
+        }
         Command::Data(data_admin_input) => {
             run_data_command(data_admin_input.command()).inspect_err(|e| {
                 log_data_setup_hint_or_error("Error running data admin command", e);
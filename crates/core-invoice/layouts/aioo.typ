@@ -27,6 +27,10 @@
 
 #let render(data, l10n) = {
   let is_expenses = data.line_items.is_expenses
+  // Credit notes reference the invoice they credit.
+  let is_credit_note = "credited_invoice" in data.information
+  // Payment reminders restate the due date of the overdue invoice.
+  let is_reminder = "reminded_due_date" in data.information
 
   // ** Invoice Data Variables **
   let emphasize_color = rgb(data.information.emphasize_color_hex)
@@ -98,6 +102,23 @@
   ])
   set text(font: "CMU Serif", size: 11pt)
 
+  // The payment terms are localized by kind, with the placeholders of the
+  // localized text filled in from the terms of this invoice.
+  let terms = data.information.terms
+  let discount = if "early_payment_discount" in terms { terms.early_payment_discount } else { none }
+  let fill_terms(text) = {
+    let text = if "days" in terms { text.replace("{days}", str(terms.days)) } else { text }
+    let text = if "day" in terms { text.replace("{day}", str(terms.day)) } else { text }
+    if discount != none {
+      text
+        .replace("{percent}", str(discount.percent))
+        .replace("{discount_days}", str(discount.days))
+        .replace("{deadline}", discount.deadline)
+    } else {
+      text
+    }
+  }
+
   grid(
     columns: (58%, 42%),
     // Two columns of equal width
@@ -124,9 +145,26 @@
     ]),
     block(fill: none, inset: 0pt, stroke: none, width: 100%, [
       // align the following block to the right margin
-      #ovalbox(100%, [#Large(strong[#l10n.invoice_info.invoice_identifier]) #text(fill: emphasize_color)[#strong(str(
-          data.information.number,
+      #if is_credit_note {
+        align(right, LARGE(strong(l10n.invoice_info.credit_note)))
+      } else if is_reminder {
+        align(right, LARGE(strong(l10n.invoice_info.reminder)))
+      }
+      #ovalbox(100%, [#Large(strong(
+          if is_credit_note { l10n.invoice_info.credit_note_identifier } else { l10n.invoice_info.invoice_identifier },
+        )) #text(fill: emphasize_color)[#strong(str(
+          data.information.formatted_number,
         ))]])
+      #if is_credit_note {
+        ovalbox(100%, [#strong[#l10n.invoice_info.credited_invoice] #text(fill: emphasize_color)[#strong(str(
+            data.information.credited_invoice,
+          ))]])
+      }
+      #if is_reminder {
+        ovalbox(100%, [#strong[#l10n.invoice_info.reminded_due_date] #text(fill: emphasize_color)[#strong(
+            data.information.reminded_due_date,
+          )]])
+      }
       // Conditionally display purchase order if it exists
       #if "purchase_order" in data.information and data.information.purchase_order != none {
         ovalbox(100%, [#strong[#l10n.invoice_info.purchase_order] #text(fill: emphasize_color)[#strong(
@@ -147,7 +185,7 @@
         ]
       }
       #strong[#l10n.invoice_info.vendor_contact] #data.vendor.contact_person \
-      #strong[#l10n.invoice_info.terms] #data.payment_info.terms
+      #strong[#l10n.invoice_info.terms] #fill_terms(l10n.payment_terms.at(terms.kind))
     ]),
   )
 
@@ -155,14 +193,14 @@
 
   // ** Invoice Items Table **
   double-line()
-  // Calculate subtotal, VAT amount, and grand total.
+  // The subtotal, VAT per rate, and grand total are computed in Rust and
+  // passed as `data.totals`, this file never sums amounts itself.
   // When VAT is 0% the VAT and subtotal rows are suppressed and the grand
   // total equals the subtotal.
-  let subtotal = 0.0
-  for it in data.line_items.items { subtotal = subtotal + it.total_cost }
-  let vat_percent = if "vat" in data.payment_info { data.payment_info.vat } else { 0 }
-  let vat_amount = subtotal * vat_percent / 100
-  let grand_total = subtotal + vat_amount
+  // Combined invoices list their expenses in a section of their own below the
+  // services, the subtotal covers both sections.
+  let expenses = if "expenses" in data.line_items { data.line_items.expenses } else { () }
+  let totals = data.totals
   v(-10pt)
   table(
     columns: (auto, auto, 1fr, auto, auto),
@@ -181,31 +219,79 @@
         row.name,
         format_item_date(l10n, is_expenses, row.transaction_date),
         format_amount(row.unit_price, row.currency),
-        str(row.quantity),
+        format_quantity(l10n, row),
         format_amount(row.total_cost, row.currency),
         table.hline(stroke: (thickness: 0.2pt, dash: "dashed")),
       )
     },
   )
+  if expenses.len() > 0 {
+    v(5pt)
+    strong(l10n.line_items.expenses)
+    v(-10pt)
+    table(
+      columns: (auto, auto, 1fr, auto, auto),
+      align: (left, left, center, center, right),
+      stroke: none,
+      table.hline(stroke: 0.2pt),
+      ..for row in expenses {
+        (
+          row.name,
+          format_item_date(l10n, true, row.transaction_date),
+          format_amount(row.unit_price, row.currency),
+          format_quantity(l10n, row),
+          format_amount(row.total_cost, row.currency),
+          table.hline(stroke: (thickness: 0.2pt, dash: "dashed")),
+        )
+      },
+    )
+  }
   // Subtotal + VAT rows shown only when VAT > 0%.
   // The subtotal row is suppressed when there is a single line item, because
   // its "Total cost" already equals the subtotal — restating it just adds
-  // visual noise. Multi-line invoices (typically expenses) keep the subtotal
-  // since the eye can't sum the column at a glance.
-  if vat_percent > 0 {
-    if data.line_items.items.len() > 1 {
+  // visual noise. Multi-line invoices (expenses, or several services) keep
+  // the subtotal since the eye can't sum the column at a glance.
+  if totals.vat != 0 {
+    if data.line_items.items.len() + expenses.len() > 1 {
       align(right)[
         #set text(weight: "bold")
         #l10n.line_items.subtotal
-        #format_amount(subtotal, data.payment_info.currency)
+        #format_amount(totals.subtotal, data.payment_info.currency)
       ]
       v(-5pt)
     }
-    align(right)[
-      #set text(weight: "bold")
-      #l10n.line_items.vat #str(vat_percent)%
-      #format_amount(vat_amount, data.payment_info.currency)
-    ]
+    // Line items with different VAT rates get a summary of the VAT per rate.
+    if totals.vat_per_rate.len() > 1 {
+      align(right)[
+        #table(
+          columns: (auto, auto, auto),
+          align: right,
+          stroke: none,
+          [#strong(l10n.line_items.vat_rate)],
+          [#strong(l10n.line_items.vat_base)],
+          [#strong(l10n.line_items.vat)],
+          ..for vat in totals.vat_per_rate {
+            (
+              str(vat.rate) + "%",
+              format_amount(vat.base, data.payment_info.currency),
+              format_amount(vat.amount, data.payment_info.currency),
+            )
+          },
+        )
+      ]
+      v(-5pt)
+      align(right)[
+        #set text(weight: "bold")
+        #l10n.line_items.vat
+        #format_amount(totals.vat, data.payment_info.currency)
+      ]
+    } else {
+      align(right)[
+        #set text(weight: "bold")
+        #l10n.line_items.vat #str(totals.vat_per_rate.at(0).rate)%
+        #format_amount(totals.vat, data.payment_info.currency)
+      ]
+    }
     v(-5pt)
   }
   // Grand Total Row
@@ -213,11 +299,63 @@
     #set text(weight: "bold")
     #l10n.line_items.grand_total
     #set text(fill: emphasize_color)
-    #format_amount(grand_total, data.payment_info.currency)
+    #format_amount(totals.grand_total, data.payment_info.currency)
   ]
+  // Discount terms state the discount of the grand total, computed in Rust,
+  // and the date it must be paid by to be deducted.
+  if discount != none and "early_payment_discount" in totals {
+    align(right)[
+      #fill_terms(l10n.payment_terms.early_payment_discount.replace(
+        "{amount}",
+        format_amount(totals.early_payment_discount, data.payment_info.currency),
+      ))
+    ]
+  }
   v(-5pt)
   double-line()
 
+  // Dates off are only listed if requested when generating the invoice.
+  if "days_off" in data.information {
+    footnotesize[
+      #strong(l10n.invoice_info.days_off)
+      #data.information.days_off.map(day => if day.half {
+        day.date + " (" + l10n.invoice_info.half_day + ")"
+      } else {
+        day.date
+      }).join(", ")
+    ]
+  }
+
+  // The totals in the currency VAT is reported in, if other than that of the
+  // invoice, converted in Rust at the rate of the invoice date.
+  if "reporting_totals" in data {
+    let reporting = data.reporting_totals
+    let rate_line = (
+      l10n.line_items.reporting_totals
+        .replace("{invoice_currency}", data.payment_info.currency)
+        .replace("{currency}", reporting.currency)
+        .replace("{rate}", str(reporting.rate))
+        .replace("{date}", reporting.rate_date)
+        .replace("{source}", reporting.source)
+    )
+    footnotesize[
+      #rate_line\
+      #l10n.line_items.subtotal #format_amount(reporting.subtotal, reporting.currency),
+      #l10n.line_items.vat #format_amount(reporting.vat, reporting.currency),
+      #l10n.line_items.grand_total #format_amount(reporting.grand_total, reporting.currency)
+    ]
+  }
+
+  // Reverse charged invoices carry no VAT, the client accounts for it, which
+  // must be stated on the invoice together with the VAT numbers of both.
+  if "reverse_charge" in data.client and data.client.reverse_charge {
+    footnotesize[
+      #l10n.line_items.reverse_charge\
+      #l10n.line_items.seller_vat_number #data.vendor.vat_number,
+      #l10n.line_items.buyer_vat_number #data.client.vat_number
+    ]
+  }
+
   v(30pt)
 
   // Conditionally display the purchase order if it exists
@@ -228,4 +366,56 @@
         )]])
     ])
   }
+
+  // The timesheet is only itemized, on an appendix page of its own, if
+  // requested when generating the invoice.
+  if "timesheet" in data.information {
+    set page(margin: (top: 2cm, bottom: 2cm, left: 1.5cm, right: 1.5cm), footer: none)
+    Large(strong(l10n.invoice_info.timesheet))
+    double-line()
+    v(-10pt)
+    table(
+      columns: (auto, auto, 1fr),
+      align: (left, right, left),
+      stroke: none,
+      table.header(
+        [#strong(l10n.invoice_info.timesheet_date)],
+        [#strong(l10n.invoice_info.timesheet_hours)],
+        [#strong(l10n.line_items.description)],
+      ),
+      table.hline(stroke: 0.2pt),
+      ..for entry in data.information.timesheet {
+        (
+          entry.date,
+          str(entry.hours),
+          if "description" in entry { entry.description } else { "" },
+          table.hline(stroke: (thickness: 0.2pt, dash: "dashed")),
+        )
+      },
+    )
+  }
+
+  // The receipts of the expenses, image receipts on an appendix page each,
+  // other receipts, e.g. PDFs, embedded as files of the invoice.
+  for receipt in data.information.at("receipts", default: ()) {
+    if receipt.page {
+      set page(margin: (top: 2cm, bottom: 2cm, left: 1.5cm, right: 1.5cm), footer: none)
+      pagebreak(weak: true)
+      Large(strong(receipt.expense))
+      double-line()
+      block(width: 100%, height: 1fr, image(
+        "/" + receipt.receipt,
+        width: 100%,
+        height: 100%,
+        fit: "contain",
+      ))
+    } else {
+      pdf.embed(
+        "/" + receipt.receipt,
+        relationship: "supplement",
+        mime-type: receipt.mime_type,
+        description: receipt.expense,
+      )
+    }
+  }
 }
@@ -1,11 +1,12 @@
 use crate::{
-    BankHolidays, Cadence, Date, Error, Granularity, InvoiceNumber, Quantity, RecordOfPeriodsOff,
-    RelativeTime, Result, TimestampedInvoiceNumber,
+    BankHolidays, Cadence, Date, DaysOff, Error, Granularity, InvoiceNumber, Quantity,
+    RecordOfPeriodsOff, RelativeTime, Result, TimestampedInvoiceNumber, WorkSchedule,
 };
 use klirr_foundation::{
     CalendarError, calculate_period_number, normalize_period_end_date_for_cadence as normalize,
-    parse_period_label_for_cadence as parse_period_label,
-    period_end_from_relative_time as from_relative, quantity_in_period as quantity_in_period_inner,
+    parse_period_label_for_cadence as parse_period_label, period_bounds as bounds,
+    period_end_for_period_number, period_end_from_relative_time as from_relative,
+    quantity_in_period as quantity_in_period_inner,
 };
 
 fn map_calendar_error(error: CalendarError) -> Error {
@@ -31,6 +32,19 @@ fn map_calendar_error(error: CalendarError) -> Error {
             Error::CannotExpenseForFortnightWhenCadenceIsMonthly
         }
         CalendarError::InvalidDate { underlying } => Error::InvalidDate { underlying },
+        CalendarError::NumberPrecedesOffset { number, offset } => {
+            Error::InvoiceNumberPrecedesOffset { number, offset }
+        }
+        CalendarError::GranularityDoesNotFitCadence {
+            granularity,
+            cadence,
+        } => Error::GranularityDoesNotFitCadence {
+            granularity,
+            cadence,
+        },
+        CalendarError::PeriodLabelDoesNotMatchCadence { label, cadence } => {
+            Error::PeriodLabelDoesNotMatchCadence { label, cadence }
+        }
     }
 }
 
@@ -131,7 +145,67 @@ pub fn calculate_invoice_number(
     .map_err(map_calendar_error)
 }
 
-/// Calculates billable quantity for a period-end date and cadence.
+/// Finds the period-end date of the invoice with `number`, the inverse of
+/// [`calculate_invoice_number`].
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core_invoice;
+/// use klirr_core_invoice::*;
+///
+/// let offset = TimestampedInvoiceNumber::builder()
+///     .offset(100)
+///     .date("2024-01-31".parse::<Date>().unwrap())
+///     .build();
+///
+/// let period_end = period_end_for_invoice_number(
+///     &offset,
+///     &InvoiceNumber::from(103),
+///     Cadence::Monthly,
+///     false,
+///     &RecordOfPeriodsOff::default(),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(period_end.to_string(), "2024-04-30");
+/// ```
+pub fn period_end_for_invoice_number(
+    offset: &TimestampedInvoiceNumber,
+    number: &InvoiceNumber,
+    cadence: Cadence,
+    is_expenses: bool,
+    record_of_periods_off: &RecordOfPeriodsOff,
+) -> Result<Date> {
+    period_end_for_period_number(
+        **offset.offset(),
+        offset.date(),
+        **number,
+        cadence,
+        is_expenses,
+        record_of_periods_off,
+    )
+    .map_err(map_calendar_error)
+}
+
+/// Returns the first and last date, both inclusive, of the period of
+/// `cadence` that `period_end` falls in.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core_invoice;
+/// use klirr_core_invoice::*;
+///
+/// let (start, end) = period_bounds("2025-05-31".parse().unwrap(), Cadence::Monthly).unwrap();
+/// assert_eq!(start.to_string(), "2025-05-01");
+/// assert_eq!(end.to_string(), "2025-05-31");
+/// ```
+pub fn period_bounds(period_end: Date, cadence: Cadence) -> Result<(Date, Date)> {
+    bounds(period_end, cadence).map_err(map_calendar_error)
+}
+
+/// Calculates billable quantity for a period-end date and cadence, following
+/// `work_schedule` and deducting `days_off` for daily and hourly
+/// granularities.
 ///
 /// # Examples
 /// ```
@@ -146,6 +220,8 @@ pub fn calculate_invoice_number(
 ///     Cadence::Monthly,
 ///     &RecordOfPeriodsOff::default(),
 ///     &BankHolidays::default(),
+///     &WorkSchedule::default(),
+///     &DaysOff::default(),
 /// )
 /// .unwrap();
 ///
@@ -157,6 +233,8 @@ pub fn quantity_in_period(
     cadence: Cadence,
     record_of_periods_off: &RecordOfPeriodsOff,
     bank_holidays: &BankHolidays,
+    work_schedule: &WorkSchedule,
+    days_off: &DaysOff,
 ) -> Result<Quantity> {
     quantity_in_period_inner(
         target_date,
@@ -164,6 +242,8 @@ pub fn quantity_in_period(
         cadence,
         record_of_periods_off,
         bank_holidays,
+        work_schedule,
+        days_off,
     )
     .map_err(map_calendar_error)
 }
@@ -268,4 +348,19 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn map_calendar_error_maps_number_precedes_offset() {
+        let mapped = map_calendar_error(CalendarError::NumberPrecedesOffset {
+            number: 5,
+            offset: 10,
+        });
+        assert_eq!(
+            mapped,
+            Error::InvoiceNumberPrecedesOffset {
+                number: 5,
+                offset: 10
+            }
+        );
+    }
 }
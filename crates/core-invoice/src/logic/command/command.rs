@@ -1,9 +1,14 @@
 use crate::{
-    Data, DecryptedEmailSettings, EncryptedEmailSettings, Error, ExpensedPeriods, HasSample, Item,
-    NamedPdf, Path, ProtoInvoiceInfo, Result, deserialize_contents_of_ron, expensed_periods_path,
-    parse_period_label_for_cadence, proto_invoice_info_path, read_data_from_disk_with_base_path,
-    read_email_data_from_disk_with_base_path, save_data_with_base_path,
-    save_email_settings_with_base_path, save_to_disk, send_email_with_settings_for_pdf,
+    ClientId, CompanyInformation, Data, Date, Decimal, DecryptedEmailSettings,
+    EncryptedEmailSettings, Error, ExpenseImport, ExpensedPeriods, HasSample, InvoiceKind,
+    InvoiceNumber, InvoiceStatus, Item, LedgerEntry, NamedPdf, Path, PathBuf, PaymentRecord,
+    PerDiemTrip, ProtoInvoiceInfo, Result, Settlement, TimestampedInvoiceNumber,
+    deserialize_contents_of_ron, expensed_periods, expensed_periods_path, ledger, ledger_path,
+    parse_period_label_for_cadence, payments, payments_path, per_diem_rates, proto_invoice_info,
+    proto_invoice_info_path, read_data_from_disk_with_base_path,
+    read_email_data_from_disk_with_base_path, registered_client_path, save_data_with_base_path,
+    save_email_settings_with_base_path, save_receipt_file_with_base_path,
+    save_registered_client_with_base_path, save_to_disk, send_email_with_settings_for_pdf,
     service_fees,
 };
 use klirr_foundation::BINARY_NAME;
@@ -193,10 +198,22 @@ fn mutate<D: Serialize + DeserializeOwned + Clone>(
     path: impl AsRef<Path>,
     mutate: impl FnOnce(&mut D),
 ) -> Result<()> {
+    try_mutate(path, |data| {
+        mutate(data);
+        Ok(())
+    })
+}
+
+/// Like [`mutate`] but for fallible mutations, nothing is saved if `mutate`
+/// fails.
+fn try_mutate<D: Serialize + DeserializeOwned + Clone, T>(
+    path: impl AsRef<Path>,
+    mutate: impl FnOnce(&mut D) -> Result<T>,
+) -> Result<T> {
     let mut data = deserialize_contents_of_ron(&path)?;
-    mutate(&mut data);
+    let output = mutate(&mut data)?;
     save_to_disk(&data, &path)?;
-    Ok(())
+    Ok(output)
 }
 
 /// Adds `expenses` to the specified `period` in the data file at `data_path`.
@@ -226,6 +243,224 @@ pub fn record_expenses_with_base_path(
     })
 }
 
+/// Creates the per diem expense of each of `trips`, with the allowance of its
+/// destination in `per_diem_rates.ron` in the data directory at `data_path`.
+///
+/// # Throws
+/// Throws an error if a destination has no rate, or a trip provides more
+/// meals than it has days.
+pub fn per_diem_expenses_with_base_path(
+    trips: Vec<PerDiemTrip>,
+    data_path: impl AsRef<Path>,
+) -> Result<Vec<Item>> {
+    if trips.is_empty() {
+        return Ok(Vec::new());
+    }
+    let rates = per_diem_rates(data_path)?;
+    trips
+        .into_iter()
+        .map(|trip| trip.into_item(&rates))
+        .collect()
+}
+
+/// Places each of `expenses` in the period of its transaction date, for the
+/// service fees cadence, and splits off those already recorded in the data
+/// file at `data_path`, without recording anything, see [`ExpenseImport`].
+pub fn preview_expense_import_with_base_path(
+    expenses: Vec<Item>,
+    data_path: impl AsRef<Path>,
+) -> Result<ExpenseImport> {
+    let data_path = data_path.as_ref();
+    let service_fees = service_fees(data_path)?;
+    ExpenseImport::new(
+        expenses,
+        *service_fees.cadence(),
+        &expensed_periods(data_path)?,
+    )
+}
+
+/// Records each of `expenses` in the period of its transaction date in the
+/// data file at `data_path`, skipping those already recorded, and returns
+/// what was recorded and skipped, see
+/// [`preview_expense_import_with_base_path`].
+pub fn import_expenses_with_base_path(
+    expenses: Vec<Item>,
+    data_path: impl AsRef<Path>,
+) -> Result<ExpenseImport> {
+    let data_path = data_path.as_ref();
+    info!("Importing #{} expenses", expenses.len());
+    let service_fees = service_fees(data_path)?;
+    try_mutate(
+        expensed_periods_path(data_path),
+        |data: &mut ExpensedPeriods| {
+            let import = ExpenseImport::new(expenses, *service_fees.cadence(), data)?;
+            for (period_end_date, items) in import.new_expenses() {
+                data.insert_expenses(period_end_date, items.clone());
+            }
+            Ok(import)
+        },
+    )
+    .inspect(|import| {
+        info!(
+            "✅ Imported #{} expenses, skipped #{} already recorded",
+            import.new_count(),
+            import.duplicate_count()
+        );
+    })
+}
+
+/// Returns the recorded expenses of every period, or only of `period` if
+/// specified, keyed by period-end date.
+///
+/// # Throws
+/// Throws an error if the period type is incompatible with the service fees cadence.
+pub fn list_expenses_with_base_path(
+    period: Option<&str>,
+    data_path: impl AsRef<Path>,
+) -> Result<Vec<(Date, Vec<Item>)>> {
+    let data_path = data_path.as_ref();
+    let period_end_date = period
+        .map(|period| parse_period_label_for_cadence(period, *service_fees(data_path)?.cadence()))
+        .transpose()?;
+    let expensed_periods = expensed_periods(data_path)?;
+    expensed_periods
+        .period_end_dates()
+        .filter(|date| period_end_date.is_none_or(|period_end_date| **date == period_end_date))
+        .map(|date| Ok((*date, expensed_periods.get(date)?)))
+        .collect()
+}
+
+/// Removes the expense at the 1-based `index` of the specified `period` in
+/// the data file at `data_path`, see [`ExpensedPeriods::remove_expense`].
+///
+/// # Throws
+/// Throws an error if the period type is incompatible with the service fees
+/// cadence, or if the period has no expense at `index`.
+pub fn remove_expense_with_base_path(
+    period: impl AsRef<str>,
+    index: usize,
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    let data_path = data_path.as_ref();
+    let period = period.as_ref();
+    info!("Removing expense #{} of: {:?}", index, period);
+    let service_fees = service_fees(data_path)?;
+    let period_end_date = parse_period_label_for_cadence(period, *service_fees.cadence())?;
+    try_mutate(
+        expensed_periods_path(data_path),
+        |data: &mut ExpensedPeriods| data.remove_expense(&period_end_date, index),
+    )
+    .inspect(|removed| {
+        info!("✅ Expense removed successfully: {}", removed);
+    })
+}
+
+/// Replaces the expense at the 1-based `index` of the specified `period` in
+/// the data file at `data_path` with `expense`, returning the replaced
+/// expense, see [`ExpensedPeriods::replace_expense`].
+///
+/// # Throws
+/// Throws an error if the period type is incompatible with the service fees
+/// cadence, or if the period has no expense at `index`.
+pub fn edit_expense_with_base_path(
+    period: impl AsRef<str>,
+    index: usize,
+    expense: Item,
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    let data_path = data_path.as_ref();
+    let period = period.as_ref();
+    info!("Editing expense #{} of: {:?}", index, period);
+    let service_fees = service_fees(data_path)?;
+    let period_end_date = parse_period_label_for_cadence(period, *service_fees.cadence())?;
+    try_mutate(
+        expensed_periods_path(data_path),
+        |data: &mut ExpensedPeriods| data.replace_expense(&period_end_date, index, expense),
+    )
+    .inspect(|replaced| {
+        info!("✅ Expense edited successfully, replaced: {}", replaced);
+    })
+}
+
+/// Moves the expense at the 1-based `index` of the specified `period` to
+/// the period `to_period` in the data file at `data_path`, see
+/// [`ExpensedPeriods::move_expense`].
+///
+/// # Throws
+/// Throws an error if either period type is incompatible with the service
+/// fees cadence, or if the period has no expense at `index`.
+pub fn move_expense_with_base_path(
+    period: impl AsRef<str>,
+    index: usize,
+    to_period: impl AsRef<str>,
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    let data_path = data_path.as_ref();
+    let period = period.as_ref();
+    let to_period = to_period.as_ref();
+    info!(
+        "Moving expense #{} of: {:?} to: {:?}",
+        index, period, to_period
+    );
+    let cadence = *service_fees(data_path)?.cadence();
+    let period_end_date = parse_period_label_for_cadence(period, cadence)?;
+    let to_period_end_date = parse_period_label_for_cadence(to_period, cadence)?;
+    try_mutate(
+        expensed_periods_path(data_path),
+        |data: &mut ExpensedPeriods| {
+            data.move_expense(&period_end_date, index, &to_period_end_date)
+        },
+    )
+    .inspect(|moved| {
+        info!("✅ Expense moved successfully: {}", moved);
+    })
+}
+
+/// Attaches the receipt `files` to the expense at the 1-based `index` of the
+/// specified `period` in the data file at `data_path`, copying them into the
+/// receipts directory of the period, see [`save_receipt_file_with_base_path`].
+///
+/// # Throws
+/// Throws an error if the period type is incompatible with the service fees
+/// cadence, if the period has no expense at `index`, or if a file is not a
+/// readable PDF or image.
+pub fn attach_receipts_with_base_path(
+    period: impl AsRef<str>,
+    index: usize,
+    files: &[PathBuf],
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    let data_path = data_path.as_ref();
+    let period = period.as_ref();
+    info!(
+        "Attaching {} receipt(s) to expense #{} of: {:?}",
+        files.len(),
+        index,
+        period
+    );
+    let service_fees = service_fees(data_path)?;
+    let period_end_date = parse_period_label_for_cadence(period, *service_fees.cadence())?;
+    try_mutate(
+        expensed_periods_path(data_path),
+        |data: &mut ExpensedPeriods| {
+            // Checks that the expense exists before copying any file.
+            data.attach_receipts(&period_end_date, index, [])?;
+            let receipts = files
+                .iter()
+                .map(|file| save_receipt_file_with_base_path(file, &period_end_date, data_path))
+                .collect::<Result<Vec<_>>>()?;
+            data.attach_receipts(&period_end_date, index, receipts)
+        },
+    )
+    .inspect(|expense| {
+        info!(
+            "✅ Receipts attached successfully to: {}, receipts: {:?}",
+            expense,
+            expense.receipts()
+        );
+    })
+}
+
 pub fn record_period_off_with_base_path(
     period: impl AsRef<str>,
     base_path: impl AsRef<Path>,
@@ -244,14 +479,174 @@ pub fn record_period_off_with_base_path(
     })
 }
 
+/// Records `entry` in the ledger of issued invoices, `ledger.ron`, creating
+/// the ledger if needed, see [`crate::Ledger::record`].
+pub fn record_invoice_in_ledger_with_base_path(
+    entry: LedgerEntry,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    let base_path = base_path.as_ref();
+    info!("Recording invoice {} in ledger", entry.number());
+    let mut ledger = ledger(base_path)?;
+    ledger.record(entry);
+    save_to_disk(&ledger, ledger_path(base_path))
+}
+
+/// Records a payment, or write-off, dated `date` of the issued invoice with
+/// `number` of `client`, see [`crate::Ledger::find`]. A payment in full
+/// without `amount` pays the outstanding amount, write-offs have no amount.
+///
+/// # Throws
+/// Throws an error if the invoice is not in the ledger, if a partial
+/// payment has no `amount` or if `amount` is not positive.
+pub fn record_payment_with_base_path(
+    number: &InvoiceNumber,
+    kind: Option<InvoiceKind>,
+    client: Option<&ClientId>,
+    settlement: Settlement,
+    amount: Option<Decimal>,
+    date: Date,
+    base_path: impl AsRef<Path>,
+) -> Result<PaymentRecord> {
+    if let Some(amount) = amount.filter(|amount| *amount <= Decimal::ZERO) {
+        return Err(Error::NonPositivePaymentAmount {
+            amount: amount.to_string(),
+        });
+    }
+    let base_path = base_path.as_ref();
+    let ledger = ledger(base_path)?;
+    let entry = ledger.find(number, kind, client)?;
+    let mut payments = payments(base_path)?;
+    let amount = match settlement {
+        Settlement::Paid => amount.unwrap_or_else(|| {
+            *InvoiceStatus::new(entry.clone(), payments.records_for(entry), date).outstanding()
+        }),
+        Settlement::PartiallyPaid => amount.ok_or(Error::MissingPaymentAmount)?,
+        Settlement::WrittenOff => Decimal::ZERO,
+    };
+    let record = PaymentRecord::builder()
+        .settlement(settlement)
+        .date(date)
+        .amount(amount)
+        .build();
+    info!(
+        "Recording invoice {} {} as {}",
+        entry.kind(),
+        number,
+        settlement
+    );
+    payments.record(entry, record.clone());
+    save_to_disk(&payments, payments_path(base_path))?;
+    Ok(record)
+}
+
+/// Returns the payment status at `today` of every invoice in the ledger,
+/// oldest first, credit notes are not paid and left out.
+pub fn invoice_statuses_with_base_path(
+    today: Date,
+    base_path: impl AsRef<Path>,
+) -> Result<Vec<InvoiceStatus>> {
+    let base_path = base_path.as_ref();
+    let ledger = ledger(base_path)?;
+    let payments = payments(base_path)?;
+    Ok(ledger
+        .iter()
+        .filter(|entry| !entry.is_credit_note())
+        .map(|entry| InvoiceStatus::new(entry.clone(), payments.records_for(entry), today))
+        .collect())
+}
+
+/// Returns the payment status at `today` of the issued invoice with `number`
+/// of `client`, see [`crate::Ledger::find`].
+///
+/// # Throws
+/// Throws an error if the invoice is not in the ledger.
+pub fn invoice_status_with_base_path(
+    number: &InvoiceNumber,
+    kind: Option<InvoiceKind>,
+    client: Option<&ClientId>,
+    today: Date,
+    base_path: impl AsRef<Path>,
+) -> Result<InvoiceStatus> {
+    let base_path = base_path.as_ref();
+    let ledger = ledger(base_path)?;
+    let entry = ledger.find(number, kind, client)?;
+    let payments = payments(base_path)?;
+    Ok(InvoiceStatus::new(
+        entry.clone(),
+        payments.records_for(entry),
+        today,
+    ))
+}
+
+/// Adds `client` to the client registry as `id`, with its own invoice number
+/// `offset`.
+///
+/// # Throws
+/// Throws an error if a client with `id` is already registered, or if the
+/// period of `offset` is in the record of periods off.
+pub fn add_client_with_base_path(
+    id: &ClientId,
+    client: CompanyInformation,
+    offset: TimestampedInvoiceNumber,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    let base_path = base_path.as_ref();
+    info!("Adding client: {}", id);
+    if registered_client_path(base_path, id).exists() {
+        return Err(Error::ClientAlreadyExists {
+            client: id.to_string(),
+        });
+    }
+    let mut information = proto_invoice_info(base_path)?;
+    information.insert_client_offset(id.clone(), offset);
+    information.validate()?;
+    // The client file is written first and removed again if the offset
+    // cannot be saved, so that a failure never leaves an offset without a
+    // client.
+    save_registered_client_with_base_path(id, &client, base_path)?;
+    save_to_disk(&information, proto_invoice_info_path(base_path))
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(registered_client_path(base_path, id));
+        })
+        .inspect(|_| {
+            info!("✅ Client added successfully");
+        })
+}
+
+/// Removes the client `id` and its invoice number offset from the client
+/// registry.
+///
+/// # Throws
+/// Throws an error if no client with `id` is registered.
+pub fn remove_client_with_base_path(id: &ClientId, base_path: impl AsRef<Path>) -> Result<()> {
+    let base_path = base_path.as_ref();
+    info!("Removing client: {}", id);
+    let path = registered_client_path(base_path, id);
+    if !path.exists() {
+        return Err(Error::UnknownClient {
+            client: id.to_string(),
+        });
+    }
+    mutate(
+        proto_invoice_info_path(base_path),
+        |data: &mut ProtoInvoiceInfo| data.remove_client_offset(id),
+    )?;
+    std::fs::remove_file(&path)
+        .map_err(Error::failed_to_write_data_to_disk)
+        .inspect(|_| {
+            info!("✅ Client removed successfully");
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        Attachment, Cadence, CompanyInformation, DataSelector, Date, Email, EmailAccount,
-        EmailCredentials, EmailSettingsSelector, ExpensedPeriods, HasSample, NamedPdf, PathBuf,
-        ProtoInvoiceInfo, Rate, Select, ServiceFees, UnitPrice, email_settings_path,
-        expensed_periods, proto_invoice_info, service_fees_path,
+        Attachment, Cadence, DataSelector, Date, Email, EmailAccount, EmailCredentials,
+        EmailSettingsSelector, ExpensedPeriods, HasSample, NamedPdf, PathBuf, PerDiemRates,
+        ProtoInvoiceInfo, Rate, Receipt, Select, ServiceFees, UnitPrice, email_settings_path,
+        expensed_periods, per_diem_rates_path, proto_invoice_info, receipts_dir, service_fees_path,
     };
     use std::str::FromStr;
     use test_log::test;
@@ -301,7 +696,7 @@ mod tests {
     #[test]
     fn test_init_data_directory_at() {
         let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
-        let result = init_data_at::<Error>(tempdir.path(), |data| Ok::<Data, Error>(data));
+        let result = init_data_at::<Error>(tempdir.path(), Ok::<Data, Error>);
         assert!(
             result.is_ok(),
             "Expected data directory initialization to succeed, got: {:?}",
@@ -326,6 +721,70 @@ mod tests {
         assert!(data.record_of_periods_off().contains(&period_end_date));
     }
 
+    #[test]
+    fn test_add_and_remove_client_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let id = ClientId::sample();
+        let offset = TimestampedInvoiceNumber::sample_other();
+
+        add_client_with_base_path(
+            &id,
+            CompanyInformation::sample_vendor(),
+            offset.clone(),
+            tempdir.path(),
+        )
+        .unwrap();
+        let data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(
+            data.clients().get(&id),
+            Some(&CompanyInformation::sample_vendor())
+        );
+        assert_eq!(data.information().client_offsets().get(&id), Some(&offset));
+
+        remove_client_with_base_path(&id, tempdir.path()).unwrap();
+        let data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert!(data.clients().is_empty());
+        assert!(data.information().client_offsets().is_empty());
+    }
+
+    #[test]
+    fn test_add_client_with_base_path_fails_when_already_exists() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let add = || {
+            add_client_with_base_path(
+                &ClientId::sample(),
+                CompanyInformation::sample_client(),
+                TimestampedInvoiceNumber::sample(),
+                tempdir.path(),
+            )
+        };
+        add().unwrap();
+        assert!(matches!(add(), Err(Error::ClientAlreadyExists { .. })));
+    }
+
+    #[test]
+    fn test_remove_client_with_base_path_fails_for_unknown_client() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let result = remove_client_with_base_path(&ClientId::sample(), tempdir.path());
+        assert!(matches!(result, Err(Error::UnknownClient { .. })));
+    }
+
+    #[test]
+    fn test_per_diem_expenses_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let trips = || vec![PerDiemTrip::from_str("Berlin, DE, 3, 2025-05-14, 1, 0, 0").unwrap()];
+        let result = per_diem_expenses_with_base_path(trips(), tempdir.path());
+        assert!(matches!(result, Err(Error::PerDiemRateNotFound { .. })));
+
+        save_to_disk(&PerDiemRates::sample(), per_diem_rates_path(tempdir.path())).unwrap();
+        let expenses = per_diem_expenses_with_base_path(trips(), tempdir.path()).unwrap();
+        assert_eq!(expenses.len(), 1);
+        assert_eq!(**expenses[0].unit_price(), rust_decimal::dec!(28));
+    }
+
     #[test]
     fn test_record_expenses_with_base_path() {
         let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
@@ -388,6 +847,149 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn save_monthly_expenses(base_path: &Path) {
+        let services_fees: ServiceFees = ServiceFees::builder()
+            .cadence(Cadence::Monthly)
+            .rate(Rate::daily(UnitPrice::ONE))
+            .name("Sample Service Fees".to_owned())
+            .build()
+            .unwrap();
+        save_to_disk(&services_fees, service_fees_path(base_path)).unwrap();
+        save_to_disk(
+            &ExpensedPeriods::default(),
+            expensed_periods_path(base_path),
+        )
+        .unwrap();
+        let expenses = vec![
+            Item::sample_expense_breakfast(),
+            Item::sample_expense_coffee(),
+        ];
+        record_expenses_with_base_path("2025-05", &expenses, base_path).unwrap();
+    }
+
+    #[test]
+    fn test_list_expenses_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_monthly_expenses(tempdir.path());
+        let may = Date::from_str("2025-05-31").unwrap();
+        let expenses = vec![
+            Item::sample_expense_breakfast(),
+            Item::sample_expense_coffee(),
+        ];
+        assert_eq!(
+            list_expenses_with_base_path(None, tempdir.path()).unwrap(),
+            vec![(may, expenses.clone())]
+        );
+        assert_eq!(
+            list_expenses_with_base_path(Some("2025-05"), tempdir.path()).unwrap(),
+            vec![(may, expenses)]
+        );
+        assert!(
+            list_expenses_with_base_path(Some("2025-06"), tempdir.path())
+                .unwrap()
+                .is_empty()
+        );
+        assert!(list_expenses_with_base_path(Some("2025-05-first-half"), tempdir.path()).is_err());
+    }
+
+    #[test]
+    fn test_import_expenses_with_base_path_skips_recorded() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_monthly_expenses(tempdir.path());
+        let june = Date::from_str("2025-06-30").unwrap();
+        let lunch = Item::from_str("Lunch,11,GBP,2,2025-06-02").unwrap();
+        let expenses = vec![Item::sample_expense_coffee(), lunch.clone()];
+
+        let preview =
+            preview_expense_import_with_base_path(expenses.clone(), tempdir.path()).unwrap();
+        assert_eq!(preview.new_count(), 1);
+        assert_eq!(preview.duplicate_count(), 1);
+        assert!(
+            list_expenses_with_base_path(Some("2025-06"), tempdir.path())
+                .unwrap()
+                .is_empty()
+        );
+
+        let imported = import_expenses_with_base_path(expenses.clone(), tempdir.path()).unwrap();
+        assert_eq!(imported, preview);
+        assert_eq!(
+            list_expenses_with_base_path(Some("2025-06"), tempdir.path()).unwrap(),
+            vec![(june, vec![lunch])]
+        );
+
+        let reimported = import_expenses_with_base_path(expenses, tempdir.path()).unwrap();
+        assert_eq!(reimported.new_count(), 0);
+        assert_eq!(reimported.duplicate_count(), 2);
+    }
+
+    #[test]
+    fn test_remove_edit_and_move_expense_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_monthly_expenses(tempdir.path());
+        let may = Date::from_str("2025-05-31").unwrap();
+        let june = Date::from_str("2025-06-30").unwrap();
+        let lunch = Item::from_str("Lunch,11,GBP,2,2025-05-31").unwrap();
+
+        let replaced =
+            edit_expense_with_base_path("2025-05", 2, lunch.clone(), tempdir.path()).unwrap();
+        assert_eq!(replaced, Item::sample_expense_coffee());
+        let moved = move_expense_with_base_path("2025-05", 1, "2025-06", tempdir.path()).unwrap();
+        assert_eq!(moved, Item::sample_expense_breakfast());
+        let expensed = expensed_periods(tempdir.path()).unwrap();
+        assert_eq!(expensed.get(&may).unwrap(), vec![lunch.clone()]);
+        assert_eq!(
+            expensed.get(&june).unwrap(),
+            vec![Item::sample_expense_breakfast()]
+        );
+
+        let removed = remove_expense_with_base_path("2025-05", 1, tempdir.path()).unwrap();
+        assert_eq!(removed, lunch);
+        assert!(!expensed_periods(tempdir.path()).unwrap().contains(&may));
+    }
+
+    #[test]
+    fn test_remove_expense_with_base_path_fail_without_changes() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_monthly_expenses(tempdir.path());
+        let before = expensed_periods(tempdir.path()).unwrap();
+        assert!(remove_expense_with_base_path("2025-05", 3, tempdir.path()).is_err());
+        assert!(remove_expense_with_base_path("2025-05-first-half", 1, tempdir.path()).is_err());
+        assert!(
+            move_expense_with_base_path("2025-05", 1, "2025-06-first-half", tempdir.path())
+                .is_err()
+        );
+        assert_eq!(expensed_periods(tempdir.path()).unwrap(), before);
+    }
+
+    #[test]
+    fn test_attach_receipts_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_monthly_expenses(tempdir.path());
+        let receipt_file = tempdir.path().join("coffee.png");
+        std::fs::write(&receipt_file, b"png").unwrap();
+        let files = vec![receipt_file];
+
+        assert!(attach_receipts_with_base_path("2025-05", 3, &files, tempdir.path()).is_err());
+        assert!(!receipts_dir(tempdir.path()).exists());
+
+        let expense = attach_receipts_with_base_path("2025-05", 2, &files, tempdir.path()).unwrap();
+        assert_eq!(expense.name(), Item::sample_expense_coffee().name());
+        assert_eq!(
+            *expense.receipts(),
+            vec![Receipt::new("receipts/2025-05-31/coffee.png").unwrap()]
+        );
+        assert!(
+            receipts_dir(tempdir.path())
+                .join("2025-05-31/coffee.png")
+                .exists()
+        );
+        let may = Date::from_str("2025-05-31").unwrap();
+        assert_eq!(
+            expensed_periods(tempdir.path()).unwrap().get(&may).unwrap()[1],
+            expense
+        );
+    }
+
     #[test]
     fn test_data_selector_includes() {
         let all_selector = DataSelector::All;
@@ -630,7 +1232,7 @@ mod tests {
         let attachments = email.attachments();
         assert_eq!(attachments.len(), 1);
         assert!(
-            matches!(attachments[0], Attachment::Pdf(ref attached_pdf) if attached_pdf == &pdf)
+            matches!(attachments[0], Attachment::Pdf(ref attached_pdf) if **attached_pdf == pdf)
         );
 
         // Verify that subject and body are set (template materialization)
@@ -673,7 +1275,7 @@ mod tests {
         let attachments = email.attachments();
         assert_eq!(attachments.len(), 1);
         assert!(
-            matches!(attachments[0], Attachment::Pdf(ref attached_pdf) if attached_pdf == &pdf)
+            matches!(attachments[0], Attachment::Pdf(ref attached_pdf) if **attached_pdf == pdf)
         );
 
         // Verify subject and body are set
@@ -748,4 +1350,56 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_invoice_statuses_leave_out_credit_notes() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let invoice = LedgerEntry::sample();
+        record_invoice_in_ledger_with_base_path(invoice.clone(), tempdir.path()).unwrap();
+        record_invoice_in_ledger_with_base_path(
+            LedgerEntry::credit_note(&NamedPdf::sample(), None),
+            tempdir.path(),
+        )
+        .unwrap();
+
+        let statuses =
+            invoice_statuses_with_base_path(*invoice.due_date(), tempdir.path()).unwrap();
+
+        assert_eq!(ledger(tempdir.path()).unwrap().len(), 2);
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].entry(), &invoice);
+    }
+
+    #[test]
+    fn test_record_payment_fails_for_non_positive_amount() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let invoice = LedgerEntry::sample();
+        record_invoice_in_ledger_with_base_path(invoice.clone(), tempdir.path()).unwrap();
+        for (settlement, amount) in [
+            (Settlement::PartiallyPaid, Decimal::ZERO),
+            (Settlement::Paid, Decimal::from(rust_decimal::dec!(-100))),
+        ] {
+            let result = record_payment_with_base_path(
+                invoice.number(),
+                None,
+                None,
+                settlement,
+                Some(amount),
+                *invoice.due_date(),
+                tempdir.path(),
+            );
+            assert_eq!(
+                result,
+                Err(Error::NonPositivePaymentAmount {
+                    amount: amount.to_string()
+                })
+            );
+        }
+        assert!(
+            payments(tempdir.path())
+                .unwrap()
+                .records_for(&invoice)
+                .is_empty()
+        );
+    }
 }
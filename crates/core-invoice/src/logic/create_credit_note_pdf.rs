@@ -0,0 +1,429 @@
+use crate::{
+    Data, Error, ExchangeRatesFetcher, FetchExchangeRates, InvoiceKind, InvoiceNumber,
+    IssuedCreditNote, L10n, Layout, LedgerEntry, NamedPdf, OutputPath, Path, PreparedData, Result,
+    ValidCreditNoteInput, ValidInput, get_localization, period_end_for_invoice_number,
+    prepare_invoice_input_data, read_data_from_disk_with_base_path,
+    record_invoice_in_ledger_with_base_path, save_credit_notes_with_base_path,
+};
+use klirr_foundation::{Pdf, create_pdf_document};
+use log::{info, warn};
+
+/// Returns the number and the credit note to issue for `input`, reusing the
+/// number and issue date of an earlier credit note for the same invoice and
+/// lines, see [`crate::CreditNotes::issue`].
+pub fn issue_credit_note(
+    data: &Data,
+    input: &ValidCreditNoteInput,
+) -> (InvoiceNumber, IssuedCreditNote) {
+    data.credit_notes().issue(
+        IssuedCreditNote::builder()
+            .invoice(input.invoice().clone())
+            .items(input.items().clone())
+            .maybe_client(input.client().clone())
+            .lines(input.lines().clone())
+            .date(*input.date())
+            .build(),
+    )
+}
+
+/// Rebuilds the invoice credited by `input` from `data` and turns it into a
+/// credit note with negated amounts for the credited lines.
+///
+/// # Errors
+/// Returns an error if the invoice number precedes the invoice number offset
+/// of the client, if the invoice cannot be rebuilt, if the rebuilt invoice
+/// differs from the one recorded in the ledger or if any credited line does
+/// not exist on the invoice.
+pub fn prepare_credit_note_input_data(
+    data: Data,
+    input: ValidCreditNoteInput,
+    fetcher: impl FetchExchangeRates,
+) -> Result<PreparedData> {
+    info!("Preparing credit note input data for PDF generation...");
+    let client_id = input.client().as_ref();
+    let period_end = period_end_for_invoice_number(
+        data.information().offset_for_client(client_id)?,
+        input.invoice(),
+        *data.service_fees().cadence(),
+        input.items().is_expenses(),
+        data.information().record_of_periods_off(),
+    )?;
+    let (number, issued) = issue_credit_note(&data, &input);
+    let format = data.information().number_series().credit_notes().clone();
+    let formatted_number = format.format(
+        data.credit_notes()
+            .sequential_number(&number, issued.date(), format.resets_yearly()),
+        *issued.date().year(),
+    );
+    let output_path = input
+        .maybe_output_path()
+        .clone()
+        .map(OutputPath::AbsolutePath)
+        .unwrap_or_else(|| {
+            OutputPath::Name(format!(
+                "{}_{}{}_credit_note_{}.pdf",
+                issued.date(),
+                data.vendor().company_name().replace(' ', "_"),
+                client_id.map(|id| format!("_{id}")).unwrap_or_default(),
+                formatted_number
+            ))
+        });
+    let invoice_input = ValidInput::builder()
+        .date(period_end)
+        .items(input.items().clone())
+        .maybe_client(input.client().clone())
+        .maybe_timesheet(input.timesheet().clone())
+        .language(*input.language())
+        .layout(*input.layout())
+        .build();
+    let issued_totals = match data.ledger().find(
+        input.invoice(),
+        Some(InvoiceKind::from(input.items())),
+        client_id,
+    ) {
+        Ok(entry) => Some(entry.totals().clone()),
+        Err(Error::InvoiceNotInLedger { .. }) => {
+            warn!(
+                "Invoice {} is not in the ledger, its amounts cannot be checked against the issued invoice",
+                input.invoice()
+            );
+            None
+        }
+        Err(error) => return Err(error),
+    };
+    let invoice = prepare_invoice_input_data(data, invoice_input, fetcher)?;
+    if let Some(issued_totals) = issued_totals.filter(|totals| totals != invoice.totals()) {
+        return Err(Error::CreditedInvoiceChanged {
+            number: **input.invoice(),
+            issued: issued_totals.grand_total().to_string(),
+            rebuilt: invoice.totals().grand_total().to_string(),
+        });
+    }
+    let credit_note = invoice.credit_note(
+        number,
+        formatted_number,
+        *issued.date(),
+        issued.lines(),
+        output_path,
+    )?;
+    info!("✅ Prepared credit note input data for PDF generation.");
+    Ok(credit_note)
+}
+
+/// Compile the Typst source into a credit note PDF and save it, by reading
+/// data from disk at the provided path. The issued credit note is recorded in
+/// `credit_notes.ron` and in the ledger, `ledger.ron`, once the PDF is saved.
+pub fn create_credit_note_pdf_with_data_base_path<E>(
+    data_base_path: impl AsRef<Path>,
+    input: ValidCreditNoteInput,
+    render: impl Fn(L10n, PreparedData, Layout) -> Result<Pdf, E>,
+) -> Result<NamedPdf, E>
+where
+    E: From<Error>,
+{
+    let data_base_path = data_base_path.as_ref();
+    let data = read_data_from_disk_with_base_path(data_base_path).map_err(E::from)?;
+    let (number, issued) = issue_credit_note(&data, &input);
+    let mut credit_notes = data.credit_notes().clone();
+    let client = input.client().clone();
+    let named_pdf = create_credit_note_pdf_with_data(data_base_path, data, input, render)?;
+    credit_notes.insert(number, issued);
+    save_credit_notes_with_base_path(&credit_notes, data_base_path).map_err(E::from)?;
+    record_invoice_in_ledger_with_base_path(
+        LedgerEntry::credit_note(&named_pdf, client),
+        data_base_path,
+    )
+    .map_err(E::from)?;
+    Ok(named_pdf)
+}
+
+/// Compile the Typst source into a credit note PDF and save it, using the
+/// provided `Data` and `ValidCreditNoteInput`. Exchange rates are cached in,
+/// and a rates file is resolved against, the data directory at
+/// `data_base_path`.
+pub fn create_credit_note_pdf_with_data<E>(
+    data_base_path: impl AsRef<Path>,
+    data: Data,
+    input: ValidCreditNoteInput,
+    render: impl Fn(L10n, PreparedData, Layout) -> Result<Pdf, E>,
+) -> Result<NamedPdf, E>
+where
+    E: From<Error>,
+{
+    let l10n: L10n = get_localization(input.language()).map_err(E::from)?;
+    let layout = *input.layout();
+    create_pdf_document(
+        input,
+        || Ok::<Data, E>(data),
+        |data, input| {
+            let fetcher = ExchangeRatesFetcher::for_provider(
+                data.payment_info().exchange_rates_provider(),
+                data_base_path,
+            );
+            prepare_credit_note_input_data(data, input, fetcher).map_err(E::from)
+        },
+        |prepared_data| prepared_data.absolute_path_and_name().map_err(E::from),
+        |prepared_data| render(l10n, prepared_data, layout),
+        |error| E::from(Error::failed_to_create_output_directory(error)),
+        |error| E::from(Error::save_pdf(error)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Cadence, Date, HasSample, InvoiceKind, InvoiceNumberFormat, InvoiceNumberSeries,
+        InvoicedItems, NumberReset, Quantity, Rate, ServiceFees, Timesheet, credit_notes, ledger,
+        read_data_from_disk_with_base_path, save_data_with_base_path,
+    };
+    use rust_decimal::dec;
+    use test_log::test;
+
+    /// The invoice number of the services invoice for May 2025 in `Data::sample`.
+    fn invoice_number_of_may_services() -> InvoiceNumber {
+        let input = ValidInput::builder()
+            .date("2025-05-31".parse::<Date>().unwrap())
+            .build();
+        let bank_holidays = Default::default();
+        Data::sample()
+            .to_partial(input, &bank_holidays)
+            .unwrap()
+            .information()
+            .number()
+            .clone()
+    }
+
+    fn input(lines: Vec<usize>) -> ValidCreditNoteInput {
+        ValidCreditNoteInput::builder()
+            .invoice(invoice_number_of_may_services())
+            .items(InvoicedItems::Service { time_off: None })
+            .lines(lines)
+            .date(Date::sample())
+            .build()
+    }
+
+    #[test]
+    fn prepare_credit_note_negates_invoice() {
+        let invoice = prepare_invoice_input_data(
+            Data::sample(),
+            ValidInput::builder()
+                .date("2025-05-31".parse::<Date>().unwrap())
+                .build(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        let sut = prepare_credit_note_input_data(
+            Data::sample(),
+            input(vec![]),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        assert_eq!(*sut.information().number(), InvoiceNumber::from(1));
+        assert_eq!(
+            sut.information().credited_invoice(),
+            &Some(invoice_number_of_may_services().to_string())
+        );
+        assert_eq!(sut.information().formatted_number(), "1");
+        assert_eq!(*sut.information().invoice_date(), Date::sample());
+        assert_eq!(
+            **sut.line_items().items()[0].total_cost(),
+            -**invoice.line_items().items()[0].total_cost()
+        );
+    }
+
+    #[test]
+    fn prepare_credit_note_credits_hours_of_timesheet() {
+        let invoice = prepare_invoice_input_data(
+            Data::sample(),
+            ValidInput::builder()
+                .date("2025-05-31".parse::<Date>().unwrap())
+                .timesheet(Timesheet::sample())
+                .build(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        let input = ValidCreditNoteInput::builder()
+            .invoice(invoice_number_of_may_services())
+            .items(InvoicedItems::Service { time_off: None })
+            .timesheet(Timesheet::sample())
+            .date(Date::sample())
+            .build();
+        let sut =
+            prepare_credit_note_input_data(Data::sample(), input, ExchangeRatesFetcher::default())
+                .unwrap();
+        let credited = &sut.line_items().items()[0];
+        // The 15.5 hours of the timesheet are billed as 1.9375 days of 8 hours.
+        assert_eq!(*credited.quantity(), Quantity::from(dec!(-1.9375)));
+        assert_eq!(
+            **credited.total_cost(),
+            -**invoice.line_items().items()[0].total_cost()
+        );
+    }
+
+    #[test]
+    fn prepare_credit_note_fails_for_line_out_of_range() {
+        let result = prepare_credit_note_input_data(
+            Data::sample(),
+            input(vec![2]),
+            ExchangeRatesFetcher::default(),
+        );
+        assert_eq!(
+            result,
+            Err(Error::InvalidCreditNoteLine {
+                line: 2,
+                line_count: 1
+            })
+        );
+    }
+
+    #[test]
+    fn prepare_credit_note_fails_for_invoice_before_offset() {
+        let input = ValidCreditNoteInput::builder()
+            .invoice(1)
+            .date(Date::sample())
+            .build();
+        let result =
+            prepare_credit_note_input_data(Data::sample(), input, ExchangeRatesFetcher::default());
+        assert!(matches!(
+            result,
+            Err(Error::InvoiceNumberPrecedesOffset { number: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn prepare_credit_note_uses_number_formats() {
+        let series = InvoiceNumberSeries::builder()
+            .services(InvoiceNumberFormat::builder().prefix("ACME-").build())
+            .credit_notes(
+                InvoiceNumberFormat::builder()
+                    .prefix("CN-")
+                    .year(true)
+                    .padding(3)
+                    .reset(NumberReset::Yearly)
+                    .build(),
+            )
+            .build();
+        let data = Data::sample().with_information(
+            Data::sample()
+                .information()
+                .clone()
+                .with_number_series(series),
+        );
+        let sut =
+            prepare_credit_note_input_data(data, input(vec![]), ExchangeRatesFetcher::default())
+                .unwrap();
+        assert_eq!(sut.information().formatted_number(), "CN-2025-001");
+        assert_eq!(
+            sut.information().credited_invoice(),
+            &Some(format!("ACME-{}", invoice_number_of_may_services()))
+        );
+        assert!(
+            sut.absolute_path_and_name()
+                .unwrap()
+                .name()
+                .ends_with("_credit_note_CN-2025-001.pdf")
+        );
+    }
+
+    #[test]
+    fn create_credit_note_refuses_invoice_changed_since_issued() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let render = |_, _, _| Ok::<Pdf, Error>(Pdf::from(Vec::from(b"%PDF-1.4")));
+        let invoice = crate::create_invoice_pdf_with_data_base_path(
+            tempdir.path(),
+            ValidInput::builder()
+                .date("2025-05-31".parse::<Date>().unwrap())
+                .maybe_output_path(tempdir.path().join("invoice.pdf"))
+                .build(),
+            render,
+        )
+        .unwrap();
+        let number = invoice.prepared_data().information().number().clone();
+        let input = ValidCreditNoteInput::builder()
+            .invoice(number.clone())
+            .items(InvoicedItems::Service { time_off: None })
+            .date(Date::sample())
+            .maybe_output_path(tempdir.path().join("credit_note.pdf"))
+            .build();
+        assert!(
+            prepare_credit_note_input_data(
+                read_data_from_disk_with_base_path(tempdir.path()).unwrap(),
+                input.clone(),
+                ExchangeRatesFetcher::default()
+            )
+            .is_ok()
+        );
+
+        let raised_rate = ServiceFees::builder()
+            .name("Discreet Investigative Services".to_owned())
+            .rate(Rate::daily(dec!(850.0)))
+            .cadence(Cadence::Monthly)
+            .build()
+            .unwrap();
+        crate::save_to_disk(&raised_rate, crate::service_fees_path(tempdir.path())).unwrap();
+
+        let result = create_credit_note_pdf_with_data_base_path(tempdir.path(), input, render);
+        assert!(matches!(
+            result,
+            Err(Error::CreditedInvoiceChanged { number: credited, .. }) if credited == *number
+        ));
+        assert!(credit_notes(tempdir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn create_credit_note_records_it_once() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let render = |_, _, _| Ok::<Pdf, Error>(Pdf::from(Vec::from(b"%PDF-1.4")));
+        let out = tempdir.path().join("credit_note.pdf");
+        let input = |date: Date| {
+            ValidCreditNoteInput::builder()
+                .invoice(invoice_number_of_may_services())
+                .items(InvoicedItems::Service { time_off: None })
+                .date(date)
+                .maybe_output_path(out.clone())
+                .build()
+        };
+
+        let first = create_credit_note_pdf_with_data_base_path(
+            tempdir.path(),
+            input(Date::sample()),
+            render,
+        )
+        .unwrap();
+        let second = create_credit_note_pdf_with_data_base_path(
+            tempdir.path(),
+            input(Date::sample_other()),
+            render,
+        )
+        .unwrap();
+
+        assert_eq!(first.prepared_data(), second.prepared_data());
+        let recorded = credit_notes(tempdir.path()).unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(
+            read_data_from_disk_with_base_path(tempdir.path())
+                .unwrap()
+                .credit_notes(),
+            &recorded
+        );
+        let ledger = ledger(tempdir.path()).unwrap();
+        assert_eq!(ledger.len(), 1, "recreating replaces the entry");
+        let entry = &ledger[0];
+        assert_eq!(*entry.kind(), InvoiceKind::CreditNote);
+        assert_eq!(entry.number(), first.prepared_data().information().number());
+        assert!(**entry.totals().grand_total() < rust_decimal::Decimal::ZERO);
+        assert_eq!(
+            ledger.find(entry.number(), None, None),
+            Err(Error::InvoiceNotInLedger {
+                number: **entry.number()
+            })
+        );
+        assert_eq!(
+            ledger.find(entry.number(), Some(InvoiceKind::CreditNote), None),
+            Ok(entry)
+        );
+    }
+}
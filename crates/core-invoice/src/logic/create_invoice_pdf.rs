@@ -1,11 +1,13 @@
 use crate::{
-    Data, Error, ExchangeRatesFetcher, L10n, Layout, NamedPdf, Path, PreparedData, Result,
-    ValidInput, get_localization, prepare_invoice_input_data, read_data_from_disk_with_base_path,
+    Data, Error, ExchangeRatesFetcher, L10n, Layout, LedgerEntry, NamedPdf, Path, PreparedData,
+    Result, ValidInput, get_localization, prepare_invoice_input_data,
+    read_data_from_disk_with_base_path, record_invoice_in_ledger_with_base_path,
 };
 use klirr_foundation::{Pdf, create_pdf_document};
 
 /// Compile the Typst source into a PDF and save it at the specified path, by
 /// reading data from disk at the provided path and using the provided `ValidInput`.
+/// The invoice is recorded in the ledger, `ledger.ron`, once the PDF is saved.
 pub fn create_invoice_pdf_with_data_base_path<E>(
     data_base_path: impl AsRef<Path>,
     input: ValidInput,
@@ -14,6 +16,7 @@ pub fn create_invoice_pdf_with_data_base_path<E>(
 where
     E: From<Error>,
 {
+    let data_base_path = data_base_path.as_ref();
     let data = read_data_from_disk_with_base_path(data_base_path).map_err(E::from)?;
     log::debug!(
         "input.date: {:?}, data.offset.date: {:?}, cadence: {:?}",
@@ -21,12 +24,22 @@ where
         data.information().offset().date(),
         data.service_fees().cadence()
     );
-    create_invoice_pdf_with_data(data, input, render)
+    let items = input.items().clone();
+    let client = input.client().clone();
+    let named_pdf = create_invoice_pdf_with_data(data_base_path, data, input, render)?;
+    record_invoice_in_ledger_with_base_path(
+        LedgerEntry::new(&named_pdf, &items, client),
+        data_base_path,
+    )
+    .map_err(E::from)?;
+    Ok(named_pdf)
 }
 
 /// Compile the Typst source into a PDF and save it at the specified path, using
-/// the provided `Data` and `ValidInput`.
+/// the provided `Data` and `ValidInput`. Exchange rates are cached in, and a
+/// rates file is resolved against, the data directory at `data_base_path`.
 pub fn create_invoice_pdf_with_data<E>(
+    data_base_path: impl AsRef<Path>,
     data: Data,
     input: ValidInput,
     render: impl Fn(L10n, PreparedData, Layout) -> Result<Pdf, E>,
@@ -40,8 +53,11 @@ where
         input,
         || Ok::<Data, E>(data),
         |data, input| {
-            prepare_invoice_input_data(data, input, ExchangeRatesFetcher::default())
-                .map_err(E::from)
+            let fetcher = ExchangeRatesFetcher::for_provider(
+                data.payment_info().exchange_rates_provider(),
+                data_base_path,
+            );
+            prepare_invoice_input_data(data, input, fetcher).map_err(E::from)
         },
         |prepared_data| prepared_data.absolute_path_and_name().map_err(E::from),
         |prepared_data| render(l10n, prepared_data, layout),
@@ -68,16 +84,49 @@ mod tests {
             .date(crate::Date::sample())
             .build();
         let dummy_pdf_data = Vec::from(b"%PDF-1.4\n1 0 obj\n<< /Type /Catalog >>\nendobj\n");
-        let named_pdf = create_invoice_pdf_with_data::<Error>(Data::sample(), input, |_, _, _| {
-            // Simulate PDF rendering
-            Ok::<Pdf, Error>(Pdf::from(dummy_pdf_data.clone()))
-        })
+        let tempdir = tempfile::tempdir().unwrap();
+        let named_pdf = create_invoice_pdf_with_data::<Error>(
+            tempdir.path(),
+            Data::sample(),
+            input,
+            |_, _, _| {
+                // Simulate PDF rendering
+                Ok::<Pdf, Error>(Pdf::from(dummy_pdf_data.clone()))
+            },
+        )
         .unwrap();
         assert_eq!(named_pdf.saved_at(), &out);
         let result = std::fs::read(named_pdf.saved_at()).unwrap();
         assert_eq!(result, dummy_pdf_data);
     }
 
+    #[test]
+    fn create_pdf_with_data_base_path_records_invoice_in_ledger() {
+        let tempdir = tempfile::tempdir().unwrap();
+        crate::save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = ValidInput::builder()
+            .maybe_output_path(tempdir.path().join("invoice.pdf"))
+            .date(crate::Date::sample())
+            .build();
+        let render = |_, _, _| Ok::<Pdf, Error>(Pdf::sample());
+
+        let named_pdf =
+            create_invoice_pdf_with_data_base_path(tempdir.path(), input.clone(), render).unwrap();
+        create_invoice_pdf_with_data_base_path(tempdir.path(), input, render).unwrap();
+
+        let ledger = crate::ledger(tempdir.path()).unwrap();
+        assert_eq!(ledger.len(), 1, "regenerating replaces the entry");
+        let entry = &ledger[0];
+        assert_eq!(
+            entry.number(),
+            named_pdf.prepared_data().information().number()
+        );
+        assert_eq!(*entry.kind(), crate::InvoiceKind::Services);
+        assert_eq!(*entry.client(), None);
+        assert_eq!(entry.output_path(), named_pdf.saved_at());
+        assert_eq!(*entry.pdf_sha256(), Pdf::sample().sha256_hex());
+    }
+
     #[test]
     fn test_save_pdf() {
         let tmp_file = NamedTempFile::new().unwrap();
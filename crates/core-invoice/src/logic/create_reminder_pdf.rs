@@ -0,0 +1,296 @@
+use crate::{
+    Data, Date, Decimal, Error, InvoiceInfoFull, InvoiceStatus, InvoiceTerms, Item, L10n,
+    L10nLineItems, Layout, LineItemsFlat, NamedPdf, OutputPath, Path, PreparedData, Quantity,
+    Result, UnitPrice, ValidReminderInput, Vat, get_localization, invoice_status_with_base_path,
+    read_data_from_disk_with_base_path,
+};
+use klirr_foundation::{Pdf, create_pdf_document};
+use log::info;
+
+/// Builds the payment reminder of the overdue invoice of `status` at the date
+/// of `input`. The reminder restates the outstanding amount of the invoice
+/// and adds the late-payment interest accrued since its due date and the
+/// reminder fee, see [`crate::LatePaymentCharges`]. The names of the lines
+/// are localized with `l10n`.
+///
+/// The reminder keeps the number of the invoice, is due on receipt and
+/// carries no VAT, since the outstanding amount already includes it.
+///
+/// # Errors
+/// Returns [`Error::InvoiceNotOverdue`] if the invoice is not overdue and an
+/// error if its client is not registered.
+pub fn prepare_reminder_input_data(
+    data: Data,
+    input: ValidReminderInput,
+    status: &InvoiceStatus,
+    l10n: &L10nLineItems,
+) -> Result<PreparedData> {
+    info!("Preparing reminder input data for PDF generation...");
+    let entry = status.entry();
+    let days_overdue = status.days_overdue().ok_or(Error::InvoiceNotOverdue {
+        number: **entry.number(),
+        due_date: *entry.due_date(),
+    })?;
+    let date = *input.date();
+    let currency = *entry.currency();
+    let charges = *data.payment_info().late_payment();
+    let formatted_number = entry
+        .formatted_number()
+        .clone()
+        .unwrap_or_else(|| entry.number().to_string());
+    let line = |name: String, amount: Decimal, date: Date| {
+        Item::builder()
+            .name(name)
+            .unit_price(UnitPrice::from(*amount))
+            .currency(currency)
+            .quantity(Quantity::ONE)
+            .transaction_date(date)
+            .build()
+            .with_total_cost()
+    };
+    let interest = charges.interest(*status.outstanding(), days_overdue);
+    let items = [
+        Some(line(
+            l10n.reminded_invoice()
+                .replace("{number}", &formatted_number),
+            *status.outstanding(),
+            *entry.due_date(),
+        )),
+        (!interest.is_zero()).then(|| {
+            line(
+                l10n.late_payment_interest()
+                    .replace("{rate}", &charges.annual_interest_rate().to_string())
+                    .replace("{days}", &days_overdue.to_string()),
+                interest,
+                date,
+            )
+        }),
+        (!charges.reminder_fee().is_zero())
+            .then(|| line(l10n.reminder_fee().clone(), *charges.reminder_fee(), date)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    let client = data.client_for(entry.client().as_ref())?.clone();
+    let output_path = input
+        .maybe_output_path()
+        .clone()
+        .map(OutputPath::AbsolutePath)
+        .unwrap_or_else(|| {
+            OutputPath::Name(format!(
+                "{}_{}{}_reminder_{}.pdf",
+                date,
+                data.vendor().company_name().replace(' ', "_"),
+                entry
+                    .client()
+                    .as_ref()
+                    .map(|id| format!("_{id}"))
+                    .unwrap_or_default(),
+                formatted_number
+            ))
+        });
+    let information = InvoiceInfoFull::builder()
+        .number(entry.number().clone())
+        .formatted_number(formatted_number)
+        .invoice_date(date)
+        .due_date(date)
+        .terms(InvoiceTerms::due_on_receipt())
+        .emphasize_color_hex(
+            data.information()
+                .emphasize_color_hex()
+                .clone()
+                .unwrap_or_default(),
+        )
+        .maybe_footer_text(data.information().footer_text().clone())
+        .reminded_due_date(*entry.due_date())
+        .build();
+    let reminder = PreparedData::builder()
+        .information(information)
+        .vendor(data.vendor().clone())
+        .client(client)
+        .line_items(
+            LineItemsFlat::builder()
+                .is_expenses(true)
+                .items(items)
+                .build(),
+        )
+        .payment_info(
+            data.payment_info()
+                .clone()
+                .with_currency(currency)
+                .with_vat(Vat::ZERO),
+        )
+        .output_path(output_path)
+        .build();
+    info!("✅ Prepared reminder input data for PDF generation.");
+    Ok(reminder)
+}
+
+/// Compile the Typst source into a payment reminder PDF and save it, by
+/// reading data, the ledger and the payments from disk at the provided path.
+/// The reminder is not recorded in the ledger, it is no invoice of its own.
+pub fn create_reminder_pdf_with_data_base_path<E>(
+    data_base_path: impl AsRef<Path>,
+    input: ValidReminderInput,
+    render: impl Fn(L10n, PreparedData, Layout) -> Result<Pdf, E>,
+) -> Result<NamedPdf, E>
+where
+    E: From<Error>,
+{
+    let data_base_path = data_base_path.as_ref();
+    let data = read_data_from_disk_with_base_path(data_base_path).map_err(E::from)?;
+    let status = invoice_status_with_base_path(
+        input.invoice(),
+        *input.kind(),
+        input.client().as_ref(),
+        *input.date(),
+        data_base_path,
+    )
+    .map_err(E::from)?;
+    create_reminder_pdf_with_data(data, &status, input, render)
+}
+
+/// Compile the Typst source into a payment reminder PDF of the invoice of
+/// `status` and save it, using the provided `Data` and `ValidReminderInput`.
+pub fn create_reminder_pdf_with_data<E>(
+    data: Data,
+    status: &InvoiceStatus,
+    input: ValidReminderInput,
+    render: impl Fn(L10n, PreparedData, Layout) -> Result<Pdf, E>,
+) -> Result<NamedPdf, E>
+where
+    E: From<Error>,
+{
+    let l10n: L10n = get_localization(input.language()).map_err(E::from)?;
+    let line_items_l10n = l10n.content().line_items().clone();
+    let layout = *input.layout();
+    create_pdf_document(
+        input,
+        || Ok::<Data, E>(data),
+        |data, input| {
+            prepare_reminder_input_data(data, input, status, &line_items_l10n).map_err(E::from)
+        },
+        |prepared_data| prepared_data.absolute_path_and_name().map_err(E::from),
+        |prepared_data| render(l10n, prepared_data, layout),
+        |error| E::from(Error::failed_to_create_output_directory(error)),
+        |error| E::from(Error::save_pdf(error)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        DueDays, HasSample, LatePaymentCharges, LedgerEntry, ledger,
+        record_invoice_in_ledger_with_base_path, save_data_with_base_path,
+    };
+    use rust_decimal::dec;
+    use test_log::test;
+
+    fn days_after_due(days: u16) -> Date {
+        LedgerEntry::sample()
+            .due_date()
+            .advance_days(&DueDays::try_from(days).unwrap())
+    }
+
+    fn data_with_charges() -> Data {
+        let data = Data::sample();
+        let payment_info = data
+            .payment_info()
+            .clone()
+            .with_late_payment(LatePaymentCharges::sample());
+        data.with_payment_info(payment_info)
+    }
+
+    fn input(date: Date) -> ValidReminderInput {
+        ValidReminderInput::builder()
+            .invoice(LedgerEntry::sample().number().clone())
+            .date(date)
+            .build()
+    }
+
+    fn prepare(data: Data, date: Date) -> Result<PreparedData> {
+        let status = InvoiceStatus::new(LedgerEntry::sample(), &[], date);
+        prepare_reminder_input_data(
+            data,
+            input(date),
+            &status,
+            L10n::english().content().line_items(),
+        )
+    }
+
+    #[test]
+    fn prepare_reminder_adds_interest_and_fee() {
+        let date = days_after_due(30);
+        let sut = prepare(data_with_charges(), date).unwrap();
+        let entry = LedgerEntry::sample();
+        let outstanding = **entry.totals().grand_total();
+        let interest = *LatePaymentCharges::sample().interest(Decimal::from(outstanding), 30);
+        let items = sut.line_items().items();
+        assert_eq!(items.len(), 3);
+        assert_eq!(**items[0].total_cost(), outstanding);
+        assert_eq!(*items[0].transaction_date(), *entry.due_date());
+        assert_eq!(items[1].name(), "Interest 10.5% p.a. for 30 days");
+        assert_eq!(**items[1].total_cost(), interest);
+        assert_eq!(items[2].name(), "Reminder fee");
+        assert_eq!(
+            **sut.totals().grand_total(),
+            outstanding + interest + dec!(60)
+        );
+        assert_eq!(sut.information().number(), entry.number());
+        assert_eq!(*sut.information().invoice_date(), date);
+        assert_eq!(*sut.information().due_date(), date);
+        assert_eq!(
+            *sut.information().reminded_due_date(),
+            Some(*entry.due_date())
+        );
+        assert_eq!(sut.payment_info().currency(), entry.currency());
+    }
+
+    #[test]
+    fn prepare_reminder_without_charges_restates_outstanding_amount() {
+        let sut = prepare(Data::sample(), days_after_due(30)).unwrap();
+        assert_eq!(sut.line_items().items().len(), 1);
+        assert_eq!(
+            sut.totals().grand_total(),
+            LedgerEntry::sample().totals().grand_total()
+        );
+    }
+
+    #[test]
+    fn prepare_reminder_fails_if_not_overdue() {
+        let entry = LedgerEntry::sample();
+        let result = prepare(data_with_charges(), *entry.due_date());
+        assert_eq!(
+            result,
+            Err(Error::InvoiceNotOverdue {
+                number: **entry.number(),
+                due_date: *entry.due_date(),
+            })
+        );
+    }
+
+    #[test]
+    fn create_reminder_does_not_record_it_in_ledger() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(data_with_charges(), tempdir.path()).unwrap();
+        record_invoice_in_ledger_with_base_path(LedgerEntry::sample(), tempdir.path()).unwrap();
+        let render = |_, _, _| Ok::<Pdf, Error>(Pdf::from(Vec::from(b"%PDF-1.4")));
+        let out = tempdir.path().join("reminder.pdf");
+        let input = ValidReminderInput::builder()
+            .invoice(LedgerEntry::sample().number().clone())
+            .date(days_after_due(10))
+            .maybe_output_path(out.clone())
+            .build();
+
+        let named_pdf =
+            create_reminder_pdf_with_data_base_path(tempdir.path(), input, render).unwrap();
+
+        assert_eq!(named_pdf.saved_at(), &out);
+        assert_eq!(named_pdf.prepared_data().line_items().items().len(), 3);
+        assert_eq!(
+            *ledger(tempdir.path()).unwrap(),
+            vec![LedgerEntry::sample()]
+        );
+    }
+}
@@ -1,13 +1,17 @@
 mod calendar_logic;
 mod command;
+mod create_credit_note_pdf;
 mod create_invoice_pdf;
+mod create_reminder_pdf;
 mod prepare_data;
 mod read_write_data;
 mod send_email;
 
 pub use calendar_logic::*;
 pub use command::*;
+pub use create_credit_note_pdf::*;
 pub use create_invoice_pdf::*;
+pub use create_reminder_pdf::*;
 pub use klirr_foundation::save_pdf_location_to_tmp_file;
 pub use klirr_foundation::{
     AesGcm256, AesGcmSealedBox, AesNonce, EncryptedAppPassword, EncryptionKey, PbHkdfSha256, Salt,
@@ -9,8 +9,8 @@ pub type BankHolidaysFetcher<T = ()> = klirr_foundation::BankHolidaysFetcher<T>;
 ///
 /// Returns an empty set (no deduction) when:
 /// - `worked_holidays` is set (the per-invoice `--worked-holidays` override),
-/// - the rate granularity is `Month`/`Fortnight` (holidays only affect day- and
-///   hour-billed invoices),
+/// - every service rate granularity is `Month`/`Fortnight` (holidays only
+///   affect day- and hour-billed services),
 /// - the vendor has not opted into `off_on_bank_holidays`,
 /// - the vendor's free-text country cannot be mapped to an ISO country code, or
 /// - the holiday API request fails (and nothing is cached).
@@ -34,11 +34,15 @@ pub fn resolve_bank_holidays(
         return BankHolidays::default();
     }
 
-    let granularity = data.service_fees().rate().granularity();
-    if !matches!(granularity, Granularity::Day | Granularity::Hour) {
+    if !data.service_fees().services().iter().any(|service| {
+        matches!(
+            service.rate().granularity(),
+            Granularity::Day | Granularity::Hour
+        )
+    }) {
         debug!(
-            "Rate granularity {granularity:?} is unaffected by bank holidays; \
-             skipping holiday resolution."
+            "No service is billed per day or hour, so bank holidays do not affect \
+             any rate; skipping holiday resolution."
         );
         return BankHolidays::default();
     }
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::{Currency, Error, ExchangeRates, FetchExchangeRates, Item, Result, UnitPrice};
+use crate::{Currency, Date, Error, ExchangeRates, FetchExchangeRates, Item, Result, UnitPrice};
 use indexmap::IndexMap;
 
 pub type ExchangeRatesMap = IndexMap<Currency, UnitPrice>;
@@ -40,6 +40,27 @@ impl<T> FetchExchangeRates for ExchangeRatesFetcher<T> {
             .rates(rates)
             .build())
     }
+
+    fn fetch_rate(&self, from: Currency, to: Currency, date: Date) -> Result<UnitPrice> {
+        let item = klirr_foundation::ExchangeRateItem::builder()
+            .transaction_date(date.to_datetime().date())
+            .source_currency(from.to_string())
+            .build();
+        let rates = self
+            .fetch_for_items(to.to_string(), vec![item])
+            .map_err(map_exchange_rates_error)?;
+        rates
+            .get(&from.to_string())
+            .map(|rate| UnitPrice::from(*rate))
+            .ok_or(Error::FoundNoExchangeRate {
+                target: to,
+                base: from,
+            })
+    }
+
+    fn rates_source(&self) -> String {
+        self.source()
+    }
 }
 
 fn map_exchange_rates_error(error: klirr_foundation::ExchangeRatesError) -> Error {
@@ -64,6 +85,7 @@ fn map_exchange_rates_error(error: klirr_foundation::ExchangeRatesError) -> Erro
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::HasSample;
     use rust_decimal::dec;
     use tempfile::tempdir;
 
@@ -90,6 +112,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fetch_rate_of_same_currency_is_one() {
+        let tempdir = tempdir().unwrap();
+        let fetcher = ExchangeRatesFetcher::builder()
+            .path_to_cache(tempdir.path().to_path_buf())
+            .extra(())
+            .build();
+
+        let rate = fetcher
+            .fetch_rate(Currency::SEK, Currency::SEK, Date::sample())
+            .unwrap();
+
+        assert_eq!(rate, UnitPrice::from(dec!(1.0)));
+    }
+
     #[test]
     fn map_exchange_rates_error_maps_network_error() {
         let error = map_exchange_rates_error(klirr_foundation::ExchangeRatesError::NetworkError {
@@ -1,6 +1,7 @@
 use crate::{
-    Currency, Data, ExchangeRates, ExchangeRatesMap, Item, LineItemsPricedInSourceCurrency,
-    PreparedData, Result, ValidInput, normalize_period_end_date_for_cadence, resolve_bank_holidays,
+    Currency, Data, Date, ExchangeRates, ExchangeRatesMap, Item, LineItemsPricedInSourceCurrency,
+    PreparedData, Result, UnitPrice, ValidInput, normalize_period_end_date_for_cadence,
+    resolve_bank_holidays,
 };
 use log::debug;
 use log::info;
@@ -9,20 +10,28 @@ pub trait FetchExchangeRates {
     fn fetch_for_items(&self, target_currency: Currency, items: Vec<Item>)
     -> Result<ExchangeRates>;
 
+    /// The amount in `to` of one unit of `from` on `date`.
+    fn fetch_rate(&self, from: Currency, to: Currency, date: Date) -> Result<UnitPrice>;
+
+    /// Where the rates come from, stated on invoices showing converted
+    /// amounts, e.g. `"European Central Bank reference rates"`.
+    fn rates_source(&self) -> String;
+
     fn fetch_for_line_items(
         &self,
         target_currency: Currency,
         line_items: &LineItemsPricedInSourceCurrency,
     ) -> Result<ExchangeRates> {
-        let Ok(expenses) = line_items.clone().try_unwrap_expenses() else {
-            debug!("No expenses found, skipping exchange rate fetching.");
+        let items = line_items.items_to_convert(target_currency);
+        if items.is_empty() {
+            debug!("No items to convert found, skipping exchange rate fetching.");
             return Ok(ExchangeRates::builder()
                 .target_currency(target_currency)
                 .rates(ExchangeRatesMap::new())
                 .build());
-        };
-        debug!("☑️ Fetching rates for #{} expenses...", expenses.len());
-        self.fetch_for_items(target_currency, expenses)
+        }
+        debug!("☑️ Fetching rates for #{} items...", items.len());
+        self.fetch_for_items(target_currency, items)
     }
 }
 
@@ -43,7 +52,27 @@ pub fn prepare_invoice_input_data(
     let partial = data.to_partial(input, &bank_holidays)?;
     let currency = *partial.payment_info().currency();
     let exchange_rates = fetcher.fetch_for_line_items(currency, partial.line_items())?;
-    let data_typst_compat = partial.to_typst(exchange_rates)?;
+    let data_typst_compat = with_reporting_totals(partial.to_typst(exchange_rates)?, &fetcher)?;
     info!("✅ Prepared invoice input data for PDF generation.");
     Ok(data_typst_compat)
 }
+
+/// Adds the totals converted into the reporting currency of the payment
+/// information at the rate of the invoice date, unless it has none or it is
+/// the currency of the invoice.
+fn with_reporting_totals(
+    prepared: PreparedData,
+    fetcher: &impl FetchExchangeRates,
+) -> Result<PreparedData> {
+    let currency = *prepared.payment_info().currency();
+    let Some(reporting_currency) = *prepared.payment_info().reporting_currency() else {
+        return Ok(prepared);
+    };
+    if reporting_currency == currency {
+        return Ok(prepared);
+    }
+    let date = *prepared.information().invoice_date();
+    debug!("☑️ Fetching {currency}/{reporting_currency}@{date} rate for reporting totals...");
+    let rate = fetcher.fetch_rate(currency, reporting_currency, date)?;
+    Ok(prepared.with_reporting_totals(reporting_currency, rate, date, fetcher.rates_source()))
+}
@@ -2,8 +2,10 @@ use serde::de::DeserializeOwned;
 
 use crate::deserialize_contents_of_ron;
 use crate::{
-    CompanyInformation, Data, EncryptedEmailSettings, Error, ExpensedPeriods, Path, PathBuf,
-    PaymentInformation, ProtoInvoiceInfo, Result, ServiceFees, Version,
+    ClientId, Clients, CompanyInformation, CreditNotes, Data, Date, EncryptedEmailSettings, Error,
+    ExpenseCsvFormat, ExpensedPeriods, InvoiceReceipt, Item, Ledger, Path, PathBuf,
+    PaymentInformation, Payments, PerDiemRates, ProtoInvoiceInfo, RECEIPTS_DIR_NAME, Receipt,
+    ReceiptFile, ReceiptFormat, RecurringItems, Result, ServiceFees, Timesheet, Version,
 };
 use klirr_foundation::RonError;
 pub use klirr_foundation::{data_dir, data_dir_create_if};
@@ -18,8 +20,14 @@ const DATA_FILE_NAME_PAYMENT: &str = "payment";
 const DATA_FILE_NAME_SERVICE_FEES: &str = "service_fees";
 const DATA_FILE_NAME_PROTO_INVOICE_INFO: &str = "invoice_info";
 const DATA_FILE_NAME_EXPENSES: &str = "expenses";
+const DATA_FILE_NAME_CREDIT_NOTES: &str = "credit_notes";
+const DATA_FILE_NAME_RECURRING_ITEMS: &str = "recurring_items";
+const DATA_FILE_NAME_PER_DIEM_RATES: &str = "per_diem_rates";
+const DATA_FILE_NAME_LEDGER: &str = "ledger";
+const DATA_FILE_NAME_PAYMENTS: &str = "payments";
 const DATA_FILE_NAME_CACHED_RATES: &str = "cached_rates";
 const DATA_FILE_NAME_VERSION: &str = "version";
+const DATA_DIR_NAME_CLIENTS: &str = "clients";
 
 pub(crate) fn save_to_disk<T: Serialize>(model: &T, path: impl AsRef<Path>) -> Result<()> {
     let path = path.as_ref();
@@ -42,13 +50,44 @@ pub fn save_data_with_base_path(data: Data, base_path: impl AsRef<Path>) -> Resu
     save_to_disk(data.version(), version_path(base_path))?;
     save_to_disk(data.vendor(), vendor_path(base_path))?;
     save_to_disk(data.client(), client_path(base_path))?;
+    for (id, client) in data.clients().iter() {
+        save_registered_client_with_base_path(id, client, base_path)?;
+    }
     save_to_disk(data.information(), proto_invoice_info_path(base_path))?;
     save_to_disk(data.payment_info(), payment_info_path(base_path))?;
     save_to_disk(data.service_fees(), service_fees_path(base_path))?;
     save_to_disk(data.expensed_periods(), expensed_periods_path(base_path))?;
+    if !data.credit_notes().is_empty() {
+        save_credit_notes_with_base_path(data.credit_notes(), base_path)?;
+    }
+    if !data.ledger().is_empty() {
+        save_to_disk(data.ledger(), ledger_path(base_path))?;
+    }
+    if !data.recurring_items().is_empty() {
+        save_to_disk(data.recurring_items(), recurring_items_path(base_path))?;
+    }
     Ok(())
 }
 
+/// Saves `client` as `clients/<id>.ron`, creating the `clients` directory if needed.
+pub fn save_registered_client_with_base_path(
+    id: &ClientId,
+    client: &CompanyInformation,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    let base_path = base_path.as_ref();
+    std::fs::create_dir_all(clients_dir(base_path)).map_err(Error::failed_to_write_data_to_disk)?;
+    save_to_disk(client, registered_client_path(base_path, id))
+}
+
+/// Saves `credit_notes` as `credit_notes.ron`.
+pub fn save_credit_notes_with_base_path(
+    credit_notes: &CreditNotes,
+    base_path: impl AsRef<Path>,
+) -> Result<()> {
+    save_to_disk(credit_notes, credit_notes_path(base_path))
+}
+
 pub fn path_to_ron_file_with_base(base_path: impl AsRef<Path>, name: &str) -> PathBuf {
     klirr_foundation::path_to_ron_file_with_base(base_path, name)
 }
@@ -90,6 +129,22 @@ pub fn client_path(base_path: impl AsRef<Path>) -> PathBuf {
     path_to_ron_file_with_base(base_path, DATA_FILE_NAME_CLIENT)
 }
 
+/// Directory of the client registry, containing one `<id>.ron` file per client.
+pub fn clients_dir(base_path: impl AsRef<Path>) -> PathBuf {
+    base_path.as_ref().join(DATA_DIR_NAME_CLIENTS)
+}
+
+/// Path to the file of the client with `id` in the client registry.
+pub fn registered_client_path(base_path: impl AsRef<Path>, id: &ClientId) -> PathBuf {
+    path_to_ron_file_with_base(clients_dir(base_path), id)
+}
+
+/// Directory of the receipts of the expenses, containing one directory per
+/// period-end date, see [`RECEIPTS_DIR_NAME`].
+pub fn receipts_dir(base_path: impl AsRef<Path>) -> PathBuf {
+    base_path.as_ref().join(RECEIPTS_DIR_NAME)
+}
+
 pub fn vendor_path(base_path: impl AsRef<Path>) -> PathBuf {
     path_to_ron_file_with_base(base_path, DATA_FILE_NAME_VENDOR)
 }
@@ -110,10 +165,62 @@ pub fn expensed_periods_path(base_path: impl AsRef<Path>) -> PathBuf {
     path_to_ron_file_with_base(base_path, DATA_FILE_NAME_EXPENSES)
 }
 
+pub fn credit_notes_path(base_path: impl AsRef<Path>) -> PathBuf {
+    path_to_ron_file_with_base(base_path, DATA_FILE_NAME_CREDIT_NOTES)
+}
+
+pub fn recurring_items_path(base_path: impl AsRef<Path>) -> PathBuf {
+    path_to_ron_file_with_base(base_path, DATA_FILE_NAME_RECURRING_ITEMS)
+}
+
+pub fn per_diem_rates_path(base_path: impl AsRef<Path>) -> PathBuf {
+    path_to_ron_file_with_base(base_path, DATA_FILE_NAME_PER_DIEM_RATES)
+}
+
+pub fn ledger_path(base_path: impl AsRef<Path>) -> PathBuf {
+    path_to_ron_file_with_base(base_path, DATA_FILE_NAME_LEDGER)
+}
+
+pub fn payments_path(base_path: impl AsRef<Path>) -> PathBuf {
+    path_to_ron_file_with_base(base_path, DATA_FILE_NAME_PAYMENTS)
+}
+
 fn client(base_path: impl AsRef<Path>) -> Result<CompanyInformation> {
     deserialize_contents_of_ron(client_path(base_path))
 }
 
+/// Reads all clients in the client registry, sorted by id. Returns an empty
+/// registry if the `clients` directory does not exist.
+pub fn clients(base_path: impl AsRef<Path>) -> Result<Clients> {
+    let dir = clients_dir(base_path);
+    if !dir.exists() {
+        return Ok(Clients::default());
+    }
+    let entries =
+        std::fs::read_dir(&dir).map_err(Error::file_not_found(dir.display().to_string()))?;
+    let mut ids = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(Error::file_not_found(dir.display().to_string()))?
+            .path();
+        if path.extension().and_then(|e| e.to_str()) != Some("ron") {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        ids.push(stem.parse::<ClientId>()?);
+    }
+    ids.sort();
+    ids.into_iter()
+        .map(|id| {
+            let client = deserialize_contents_of_ron(path_to_ron_file_with_base(&dir, &id))?;
+            Ok((id, client))
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Clients::new)
+}
+
 fn vendor(base_path: impl AsRef<Path>) -> Result<CompanyInformation> {
     deserialize_contents_of_ron(vendor_path(base_path))
 }
@@ -134,6 +241,153 @@ pub fn expensed_periods(base_path: impl AsRef<Path>) -> Result<ExpensedPeriods>
     deserialize_contents_of_ron(expensed_periods_path(base_path))
 }
 
+/// Reads the issued credit notes, returns no credit notes if
+/// `credit_notes.ron` does not exist.
+pub fn credit_notes(base_path: impl AsRef<Path>) -> Result<CreditNotes> {
+    let path = credit_notes_path(base_path);
+    if !path.exists() {
+        return Ok(CreditNotes::default());
+    }
+    deserialize_contents_of_ron(path)
+}
+
+/// Reads the recurring items, returns no recurring items if
+/// `recurring_items.ron` does not exist.
+pub fn recurring_items(base_path: impl AsRef<Path>) -> Result<RecurringItems> {
+    let path = recurring_items_path(base_path);
+    if !path.exists() {
+        return Ok(RecurringItems::default());
+    }
+    deserialize_contents_of_ron(path)
+}
+
+/// Reads and validates the per diem rates, returns no rates if
+/// `per_diem_rates.ron` does not exist.
+pub fn per_diem_rates(base_path: impl AsRef<Path>) -> Result<PerDiemRates> {
+    let path = per_diem_rates_path(base_path);
+    if !path.exists() {
+        return Ok(PerDiemRates::default());
+    }
+    let rates: PerDiemRates = deserialize_contents_of_ron(path)?;
+    rates.validate()?;
+    Ok(rates)
+}
+
+/// Reads the ledger of issued invoices, returns an empty ledger if
+/// `ledger.ron` does not exist.
+pub fn ledger(base_path: impl AsRef<Path>) -> Result<Ledger> {
+    let path = ledger_path(base_path);
+    if !path.exists() {
+        return Ok(Ledger::default());
+    }
+    deserialize_contents_of_ron(path)
+}
+
+/// Reads the payments of issued invoices, returns no payments if
+/// `payments.ron` does not exist.
+pub fn payments(base_path: impl AsRef<Path>) -> Result<Payments> {
+    let path = payments_path(base_path);
+    if !path.exists() {
+        return Ok(Payments::default());
+    }
+    deserialize_contents_of_ron(path)
+}
+
+/// Reads the timesheet CSV file at `path`, see [`Timesheet::from_csv`].
+///
+/// # Errors
+/// Returns [`Error::FileNotFound`] if the file cannot be opened, or
+/// [`Error::InvalidTimesheet`] if it cannot be parsed.
+pub fn read_timesheet(path: impl AsRef<Path>, itemized: bool) -> Result<Timesheet> {
+    let path = path.as_ref();
+    let file =
+        std::fs::File::open(path).map_err(Error::file_not_found(path.display().to_string()))?;
+    Timesheet::from_csv(file, itemized)
+}
+
+/// Reads the expenses CSV file at `path` in `format`, see
+/// [`ExpenseCsvFormat::parse`].
+///
+/// # Errors
+/// Returns [`Error::FileNotFound`] if the file cannot be opened, or an error
+/// if it cannot be parsed.
+pub fn read_expenses_csv(path: impl AsRef<Path>, format: &ExpenseCsvFormat) -> Result<Vec<Item>> {
+    let path = path.as_ref();
+    let file =
+        std::fs::File::open(path).map_err(Error::file_not_found(path.display().to_string()))?;
+    format.parse(file)
+}
+
+/// Copies the receipt `file` into the receipts directory of `period_end_date`,
+/// see [`receipts_dir`], and returns the receipt. The copy keeps the file name,
+/// suffixed with a number if another file of that name is already stored, and
+/// a file already stored with the same content is reused.
+///
+/// # Errors
+/// Returns [`Error::UnsupportedReceiptFormat`] if `file` is neither a PDF nor
+/// a supported image, [`Error::FileNotFound`] if it cannot be read, or
+/// [`Error::FailedToWriteDataToDisk`] if it cannot be copied.
+pub fn save_receipt_file_with_base_path(
+    file: impl AsRef<Path>,
+    period_end_date: &Date,
+    base_path: impl AsRef<Path>,
+) -> Result<Receipt> {
+    let file = file.as_ref();
+    ReceiptFormat::from_path(file)?;
+    let content = std::fs::read(file).map_err(Error::file_not_found(file.display().to_string()))?;
+    let file_name = file
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let (stem, extension) = file_name.rsplit_once('.').unwrap_or((&file_name, ""));
+    let relative_dir = format!("{RECEIPTS_DIR_NAME}/{period_end_date}");
+    let dir = base_path.as_ref().join(&relative_dir);
+    std::fs::create_dir_all(&dir).map_err(Error::failed_to_write_data_to_disk)?;
+    let mut number = 1;
+    loop {
+        let name = if number == 1 {
+            file_name.clone()
+        } else {
+            format!("{stem}-{number}.{extension}")
+        };
+        let path = dir.join(&name);
+        let receipt = Receipt::new(format!("{relative_dir}/{name}"))?;
+        if !path.exists() {
+            std::fs::write(&path, &content).map_err(Error::failed_to_write_data_to_disk)?;
+            info!("✅ Saved receipt at: {}", path.display());
+            return Ok(receipt);
+        }
+        if std::fs::read(&path).is_ok_and(|existing| existing == content) {
+            return Ok(receipt);
+        }
+        number += 1;
+    }
+}
+
+/// Reads the files of the `receipts` of an invoice from the data directory.
+///
+/// # Errors
+/// Returns [`Error::FileNotFound`] if a receipt file cannot be read.
+pub fn read_receipt_files<'a>(
+    receipts: impl IntoIterator<Item = &'a InvoiceReceipt>,
+    base_path: impl AsRef<Path>,
+) -> Result<Vec<ReceiptFile>> {
+    let base_path = base_path.as_ref();
+    receipts
+        .into_iter()
+        .map(|receipt| {
+            let path = base_path.join(receipt.receipt().as_str());
+            let content =
+                std::fs::read(&path).map_err(Error::file_not_found(path.display().to_string()))?;
+            Ok(ReceiptFile::builder()
+                .receipt(receipt.receipt().clone())
+                .mime_type(receipt.mime_type().clone())
+                .content(content)
+                .build())
+        })
+        .collect()
+}
+
 pub fn read_email_data_from_disk_with_base_path(
     base_path: impl AsRef<Path>,
 ) -> Result<EncryptedEmailSettings> {
@@ -175,20 +429,28 @@ pub fn read_data_from_disk_with_base_path(base_path: impl AsRef<Path>) -> Result
     let version = version(base_path)?;
     validate_data_version(version)?;
     let client = client(base_path)?;
+    let clients = clients(base_path)?;
     let vendor = vendor(base_path)?;
     let payment_info = payment_info(base_path)?;
     let service_fees = service_fees(base_path)?;
     let proto_invoice_info = proto_invoice_info(base_path)?;
     let expensed_periods = expensed_periods(base_path)?;
+    let credit_notes = credit_notes(base_path)?;
+    let ledger = ledger(base_path)?;
+    let recurring_items = recurring_items(base_path)?;
 
     let input_data = Data::builder()
         .version(version)
         .client(client)
+        .clients(clients)
         .vendor(vendor)
         .payment_info(payment_info)
         .service_fees(service_fees)
         .information(proto_invoice_info)
         .expensed_periods(expensed_periods)
+        .credit_notes(credit_notes)
+        .ledger(ledger)
+        .recurring_items(recurring_items)
         .build();
     debug!("✅ Read data from disk!");
     input_data.validate()
@@ -234,6 +496,118 @@ mod tests {
         assert_eq!(persisted_version, Version::current());
     }
 
+    #[test]
+    fn write_read_data_with_registered_clients() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let mut information = ProtoInvoiceInfo::sample();
+        information.insert_client_offset(
+            ClientId::sample(),
+            ProtoInvoiceInfo::sample_other().offset().clone(),
+        );
+        let data = Data::sample()
+            .with_clients(Clients::sample())
+            .with_information(information);
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        assert!(registered_client_path(tempdir.path(), &ClientId::sample()).exists());
+
+        let loaded_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(loaded_data, data);
+    }
+
+    #[test]
+    fn write_read_data_with_credit_notes() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        assert!(credit_notes(tempdir.path()).unwrap().is_empty());
+        let mut data = Data::sample();
+        data.set_credit_notes(CreditNotes::sample());
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        let loaded = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(loaded, data);
+    }
+
+    #[test]
+    fn write_read_data_with_ledger() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let mut data = Data::sample();
+        data.set_ledger(Ledger::sample());
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        let loaded = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(loaded, data);
+    }
+
+    #[test]
+    fn write_read_data_with_recurring_items() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        assert!(!recurring_items_path(tempdir.path()).exists());
+        let data = Data::sample().with_recurring_items(RecurringItems::sample_other());
+        save_data_with_base_path(data.clone(), tempdir.path()).unwrap();
+        let loaded = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(loaded, data);
+    }
+
+    #[test]
+    fn clients_is_empty_when_clients_dir_is_missing() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        assert!(clients(tempdir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn clients_ignores_non_ron_files() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_registered_client_with_base_path(
+            &ClientId::sample(),
+            &CompanyInformation::sample_client(),
+            tempdir.path(),
+        )
+        .unwrap();
+        std::fs::write(clients_dir(tempdir.path()).join("notes.txt"), "hi").unwrap();
+        let clients = clients(tempdir.path()).unwrap();
+        assert_eq!(clients.len(), 1);
+    }
+
+    #[test]
+    fn read_timesheet_from_file() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let path = tempdir.path().join("hours.csv");
+        std::fs::write(&path, "date,hours\n2025-05-12,8\n2025-05-13,4.5\n").unwrap();
+        let timesheet = read_timesheet(&path, true).unwrap();
+        assert_eq!(*timesheet.hours(), rust_decimal::dec!(12.5));
+        assert!(timesheet.itemized());
+    }
+
+    #[test]
+    fn read_expenses_csv_from_file() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let path = tempdir.path().join("expenses.csv");
+        std::fs::write(
+            &path,
+            "name,unit_price,currency,transaction_date\n\"Sandwich, vegan\",6,EUR,2025-05-31\n",
+        )
+        .unwrap();
+        let expenses = read_expenses_csv(&path, &ExpenseCsvFormat::default()).unwrap();
+        assert_eq!(expenses.len(), 1);
+        assert_eq!(expenses[0].name(), "Sandwich, vegan");
+    }
+
+    #[test]
+    fn per_diem_rates_are_empty_when_file_is_missing_and_read_when_saved() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        assert!(per_diem_rates(tempdir.path()).unwrap().is_empty());
+        save_to_disk(&PerDiemRates::sample(), per_diem_rates_path(tempdir.path())).unwrap();
+        assert_eq!(
+            per_diem_rates(tempdir.path()).unwrap(),
+            PerDiemRates::sample()
+        );
+    }
+
+    #[test]
+    fn read_timesheet_fails_when_file_is_missing() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let result = read_timesheet(tempdir.path().join("missing.csv"), false);
+        assert!(matches!(result, Err(Error::FileNotFound { .. })));
+    }
+
     #[test]
     fn read_data_fails_when_version_does_not_match_current() {
         let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
@@ -247,4 +621,56 @@ mod tests {
                 if found == Version::V0 && current == Version::current()
         ));
     }
+
+    #[test]
+    fn save_receipt_file_reuses_same_file_and_renames_other_file() {
+        let source = tempfile::tempdir().unwrap();
+        let data = tempfile::tempdir().unwrap();
+        let period_end_date = "2025-05-31".parse::<Date>().unwrap();
+        let hotel = source.path().join("hotel.pdf");
+        std::fs::write(&hotel, b"%PDF hotel").unwrap();
+        let other_dir = source.path().join("other");
+        std::fs::create_dir(&other_dir).unwrap();
+        let other_hotel = other_dir.join("hotel.pdf");
+        std::fs::write(&other_hotel, b"%PDF other hotel").unwrap();
+
+        let saved =
+            save_receipt_file_with_base_path(&hotel, &period_end_date, data.path()).unwrap();
+        let again =
+            save_receipt_file_with_base_path(&hotel, &period_end_date, data.path()).unwrap();
+        let other =
+            save_receipt_file_with_base_path(&other_hotel, &period_end_date, data.path()).unwrap();
+
+        assert_eq!(saved.as_str(), "receipts/2025-05-31/hotel.pdf");
+        assert_eq!(again, saved);
+        assert_eq!(other.as_str(), "receipts/2025-05-31/hotel-2.pdf");
+
+        let receipts = [hotel_receipt(saved), hotel_receipt(other)];
+        let files = read_receipt_files(&receipts, data.path()).unwrap();
+        assert_eq!(files[0].content(), b"%PDF hotel");
+        assert_eq!(files[1].content(), b"%PDF other hotel");
+    }
+
+    fn hotel_receipt(receipt: Receipt) -> InvoiceReceipt {
+        InvoiceReceipt::builder()
+            .expense("Hotel")
+            .receipt(receipt)
+            .mime_type("application/pdf")
+            .page(false)
+            .build()
+    }
+
+    #[test]
+    fn save_receipt_file_fails_for_unsupported_format() {
+        let data = tempfile::tempdir().unwrap();
+        let result = save_receipt_file_with_base_path(
+            data.path().join("notes.txt"),
+            &"2025-05-31".parse().unwrap(),
+            data.path(),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedReceiptFormat { .. })
+        ));
+    }
 }
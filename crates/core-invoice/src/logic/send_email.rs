@@ -1,4 +1,7 @@
-use crate::{DecryptedEmailSettings, Email, EmailCredentials, EmailWithSender, NamedPdf, Result};
+use crate::{
+    Attachment, DecryptedEmailSettings, Email, EmailCredentials, EmailWithSender, NamedPdf,
+    ReceiptFile, Result,
+};
 use log::warn;
 
 use lettre::{Message, SmtpTransport, Transport, transport::smtp::authentication::Credentials};
@@ -11,6 +14,19 @@ pub fn send_email_with_settings_for_pdf(
     send_email_with_credentials(email, credentials)
 }
 
+/// Sends the invoice `named_pdf` by email like [`send_email_with_settings_for_pdf`],
+/// with the `receipt_files` of its expenses attached after the invoice, see
+/// [`crate::read_receipt_files`].
+pub fn send_email_with_settings_for_invoice_pdf(
+    named_pdf: &NamedPdf,
+    receipt_files: Vec<ReceiptFile>,
+    email_settings: &DecryptedEmailSettings,
+) -> Result<()> {
+    let (email, credentials) = email_settings.compose(named_pdf);
+    let email = email.with_attachments(receipt_files.into_iter().map(Attachment::Receipt));
+    send_email_with_credentials(email, credentials)
+}
+
 /// Sends an email using the provided credentials using lettre crate.
 fn send_email_with_credentials(email: Email, credentials: EmailCredentials) -> Result<()> {
     let email_with_sender = EmailWithSender::builder()
@@ -1,9 +1,12 @@
 use crate::{
-    BankHolidays, Cadence, CompanyInformation, DataFromDiskWithItemsOfKind,
-    DataWithItemsPricedInSourceCurrency, Error, ExpensedPeriods, HasSample, InvoiceInfoFull,
-    InvoicedItems, Item, LineItemsPricedInSourceCurrency, OutputPath, PaymentInformation,
-    ProtoInvoiceInfo, Quantity, Result, ServiceFees, TimeOff, ValidInput, calculate_invoice_number,
-    normalize_period_end_date_for_cadence, quantity_in_period,
+    BankHolidays, Cadence, ClientId, Clients, CompanyInformation, CreditNotes,
+    DataFromDiskWithItemsOfKind, DataWithItemsPricedInSourceCurrency, Date, DaysOff, Error,
+    ExpensedPeriods, Granularity, HasSample, InvoiceInfoFull, InvoiceNumber, InvoiceReceipt,
+    InvoicedItems, Item, Ledger, LineItemsPricedInSourceCurrency, OutputPath, PaymentInformation,
+    ProtoInvoiceInfo, Quantity, RecordOfPeriodsOff, RecurringItems, Result, ServiceFees, TimeOff,
+    Timesheet, TimestampedInvoiceNumber, ValidInput, calculate_invoice_number,
+    normalize_period_end_date_for_cadence, period_bounds, quantity_in_period,
+    validate_rate_for_retainer,
 };
 use bon::Builder;
 use derive_more::Display;
@@ -53,19 +56,44 @@ pub struct Data {
     vendor: CompanyInformation,
     #[getset(get = "pub", set_with = "pub")]
     client: CompanyInformation,
-    #[getset(get = "pub")]
+    /// Additional clients, persisted in `clients/<id>.ron`, selectable with
+    /// `klirr invoice --client <id>`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    clients: Clients,
+    #[getset(get = "pub", set_with = "pub")]
     payment_info: PaymentInformation,
     #[getset(get = "pub")]
     service_fees: ServiceFees,
     #[getset(get = "pub", set = "pub")]
     expensed_periods: ExpensedPeriods,
+    /// Issued credit notes, persisted in `credit_notes.ron`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set = "pub")]
+    credit_notes: CreditNotes,
+    /// Issued invoices, persisted in `ledger.ron`, from which expense
+    /// invoices in a separate series keep their numbers.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set = "pub")]
+    ledger: Ledger,
+    /// Fixed fees billed on service invoices, persisted in
+    /// `recurring_items.ron`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    recurring_items: RecurringItems,
 }
 
 impl Data {
     /// Validates invoice information and returns `self` when valid.
     ///
     /// # Errors
-    /// Returns an error if invoice metadata is invalid.
+    /// Returns an error if invoice metadata is invalid, if a registered client
+    /// has no invoice number offset or if an offset belongs to no registered
+    /// client.
     ///
     /// # Examples
     /// ```
@@ -87,40 +115,144 @@ impl Data {
             information,
             vendor,
             client,
+            clients,
             payment_info,
             service_fees,
             expensed_periods,
+            credit_notes,
+            ledger,
+            recurring_items,
         } = self;
         information.validate()?;
+        for id in clients.keys() {
+            information.offset_for_client(Some(id))?;
+        }
+        if let Some(id) = information
+            .client_offsets()
+            .keys()
+            .find(|id| !clients.contains_key(*id))
+        {
+            return Err(Error::UnknownClient {
+                client: id.to_string(),
+            });
+        }
         let payment_info = payment_info.validate()?;
+        recurring_items.validate()?;
         Ok(Self {
             version,
             information,
             vendor,
             client,
+            clients,
             payment_info,
             service_fees,
             expensed_periods,
+            credit_notes,
+            ledger,
+            recurring_items,
         })
     }
 
+    /// Returns the client with `id` from the client registry, or the default
+    /// client if `id` is `None`.
+    ///
+    /// # Errors
+    /// Returns [`Error::UnknownClient`] if `id` is not registered.
+    pub fn client_for(&self, id: Option<&ClientId>) -> Result<&CompanyInformation> {
+        match id {
+            None => Ok(&self.client),
+            Some(id) => self.clients.get(id).ok_or_else(|| Error::UnknownClient {
+                client: id.to_string(),
+            }),
+        }
+    }
+
+    /// Formats invoice `number` of the period ending at
+    /// `target_period_end_date` with the number series of the invoice
+    /// information, see [`crate::InvoiceNumberSeries`].
+    ///
+    /// Expense invoices in a separate series are numbered by the periods
+    /// elapsed since the offset period, so the number of an invoice only
+    /// depends on its period, not on the order invoices are generated in. If
+    /// a series restarts yearly, the periods of earlier years are not counted.
+    fn formatted_invoice_number(
+        &self,
+        client_id: Option<&ClientId>,
+        number: &InvoiceNumber,
+        target_period_end_date: &Date,
+        is_expenses: bool,
+    ) -> Result<String> {
+        let series = self.information().number_series();
+        let year = *target_period_end_date.year();
+        let offset = self.information().offset_for_client(client_id)?;
+        let cadence = *self.service_fees().cadence();
+        let separate_expenses = series.expenses().as_ref().filter(|_| is_expenses);
+        let number_of_period = |period_end: &Date| match separate_expenses {
+            Some(_) => calculate_invoice_number(
+                &TimestampedInvoiceNumber::builder()
+                    .offset(0)
+                    .date(*offset.date())
+                    .build(),
+                period_end,
+                cadence,
+                false,
+                &RecordOfPeriodsOff::default(),
+            ),
+            None => calculate_invoice_number(
+                offset,
+                period_end,
+                cadence,
+                false,
+                self.information().record_of_periods_off(),
+            ),
+        };
+        let (format, mut sequential) = match separate_expenses {
+            Some(format) => (format, *number_of_period(target_period_end_date)?),
+            None => (series.services(), **number),
+        };
+        if format.resets_yearly() {
+            // The last period of the previous year is the one before the
+            // period holding January 1st, whose end may fall in the new year.
+            let (start_of_first_period, _) =
+                period_bounds(Date::from_ymd(*year, 1u32, 1u32)?, cadence)?;
+            let end_of_previous_year =
+                Date::from(start_of_first_period.to_datetime() - chrono::Duration::days(1));
+            let offset_period_end = normalize_period_end_date_for_cadence(*offset.date(), cadence)?;
+            if end_of_previous_year >= offset_period_end {
+                sequential -= *number_of_period(&end_of_previous_year)?;
+            }
+        }
+        Ok(format.format(sequential, year))
+    }
+
     fn billable_quantity(
         &self,
         target_period_end_date: &crate::Date,
+        granularity: Granularity,
         cadence: Cadence,
         time_off: &Option<TimeOff>,
         bank_holidays: &BankHolidays,
     ) -> Result<Quantity> {
-        let granularity = self.service_fees().rate().granularity();
         let periods_off = self.information().record_of_periods_off();
+        let no_days_off = DaysOff::default();
+        let days_off = time_off
+            .as_ref()
+            .and_then(TimeOff::days_off)
+            .unwrap_or(&no_days_off);
         let quantity_in_period = quantity_in_period(
             target_period_end_date,
             granularity,
             cadence,
             periods_off,
             bank_holidays,
+            self.service_fees().work_schedule(),
+            days_off,
         )?;
-        let time_off = time_off.map(|d| *d).unwrap_or(Quantity::ZERO);
+        let time_off = time_off
+            .as_ref()
+            .filter(|time_off| time_off.granularity() == Some(granularity))
+            .and_then(TimeOff::quantity)
+            .unwrap_or(Quantity::ZERO);
         let billable = quantity_in_period - time_off;
         if billable < Quantity::ZERO {
             warn!(
@@ -133,11 +265,136 @@ impl Data {
         Ok(billable)
     }
 
+    /// The hours of `timesheet` in `granularity`, days are converted using
+    /// the hours per day of the work schedule, `None` for services billed per
+    /// week or coarser which are not billed by the hour.
+    fn timesheet_quantity(
+        &self,
+        timesheet: &Timesheet,
+        granularity: Granularity,
+    ) -> Option<Quantity> {
+        match granularity {
+            Granularity::Hour => Some(timesheet.hours()),
+            Granularity::Day => Some(Quantity::from(
+                *timesheet.hours() / **self.service_fees().work_schedule().hours_per_day(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// One item per configured service, with the billable quantity of the
+    /// period ending at `target_period_end_date`, followed by the recurring
+    /// items billed in the period.
+    ///
+    /// Only the primary service bills the hours of `timesheet` or deducts
+    /// `time_off`, both track its work, additional services are billed for
+    /// the whole period.
+    ///
+    /// With a [`crate::Retainer`] the primary service is billed as the fee
+    /// of the retainer, followed by an overage row of the primary service
+    /// for the work beyond the included amount, if any.
+    fn service_items(
+        &self,
+        target_period_end_date: &crate::Date,
+        cadence: Cadence,
+        time_off: &Option<TimeOff>,
+        timesheet: Option<&Timesheet>,
+        bank_holidays: &BankHolidays,
+    ) -> Result<Vec<Item>> {
+        // Bill the rate effective in the period, so that regenerating an
+        // invoice of an earlier period bills the rate of that period.
+        let (start, end) = period_bounds(*target_period_end_date, cadence)?;
+        let service_fees = self.service_fees().for_period(start, end)?;
+        let service_fees_granularity = service_fees.rate().granularity();
+        let bills_days_or_hours = matches!(
+            service_fees_granularity,
+            Granularity::Day | Granularity::Hour
+        );
+        if timesheet.is_some() && !bills_days_or_hours {
+            return Err(Error::TimesheetRequiresDailyOrHourlyService {
+                service_fees_granularity,
+            });
+        }
+        match time_off.as_ref().map(TimeOff::granularity) {
+            Some(Some(free_granularity)) if free_granularity != service_fees_granularity => {
+                return Err(Error::InvalidGranularityForTimeOff {
+                    free_granularity,
+                    service_fees_granularity,
+                });
+            }
+            Some(None) if !bills_days_or_hours => {
+                return Err(Error::DaysOffRequireDailyOrHourlyService {
+                    service_fees_granularity,
+                });
+            }
+            _ => {}
+        }
+        if service_fees.retainer().is_some() {
+            validate_rate_for_retainer(service_fees.rate())?;
+        }
+        let mut items = service_fees
+            .services()
+            .into_iter()
+            .enumerate()
+            .map(|(index, service)| {
+                let granularity = service.rate().granularity();
+                let is_primary = index == 0;
+                let timesheet = timesheet.filter(|_| is_primary);
+                let no_time_off = None;
+                let time_off = if is_primary { time_off } else { &no_time_off };
+                let quantity = match timesheet
+                    .and_then(|timesheet| self.timesheet_quantity(timesheet, granularity))
+                {
+                    Some(quantity) => quantity,
+                    None => self.billable_quantity(
+                        target_period_end_date,
+                        granularity,
+                        cadence,
+                        time_off,
+                        bank_holidays,
+                    )?,
+                };
+                Ok(Item::builder()
+                    .name(service.name().clone())
+                    .transaction_date(*target_period_end_date)
+                    .quantity(quantity)
+                    .unit_price(service.unit_price())
+                    .currency(*self.payment_info.currency())
+                    .maybe_vat(*service.vat())
+                    .build())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(retainer) = service_fees.retainer() {
+            // The fee replaces the primary service, which only bills the
+            // work beyond the amount included in the fee.
+            let mut overage = items.remove(0);
+            overage.set_quantity(retainer.overage(*overage.quantity()));
+            let fee = Item::builder()
+                .name(retainer.name().clone())
+                .transaction_date(*target_period_end_date)
+                .quantity(Quantity::ONE)
+                .unit_price(*retainer.fee())
+                .currency(*self.payment_info.currency())
+                .build();
+            let billed = std::iter::once(fee)
+                .chain((*overage.quantity() > Quantity::ZERO).then_some(overage));
+            items.splice(0..0, billed);
+        }
+        items.extend(
+            self.recurring_items
+                .items_for_period(*target_period_end_date, cadence)?,
+        );
+        Ok(items)
+    }
+
     /// Converts data loaded from disk into render-ready invoice input.
     ///
     /// # Errors
-    /// Returns an error if granularity/cadence constraints are violated, or
-    /// when expenses are requested for a period with no recorded expenses.
+    /// Returns an error if granularity/cadence constraints are violated, if
+    /// the requested client is not registered, or when expenses are requested
+    /// for a period with no recorded expenses, or if the rate of the primary
+    /// service changes within the period. Combined invoices are created
+    /// even if the period has no recorded expenses.
     ///
     /// # Examples
     /// ```
@@ -159,11 +416,26 @@ impl Data {
         let cadence = *self.service_fees().cadence();
         let target_period_end_date = normalize_period_end_date_for_cadence(*input.date(), cadence)?;
         let invoice_date = target_period_end_date;
-        let due_date = invoice_date.advance(self.payment_info().terms());
+        let terms = self.payment_info().terms();
+        let due_date = terms.due_date(invoice_date);
         let is_expenses = items.is_expenses();
+        let client_id = input.client().as_ref();
+        let client = self.client_for(client_id)?.clone();
+
+        let timesheet = input.timesheet().as_ref();
+        if let Some(timesheet) = timesheet {
+            if is_expenses {
+                return Err(Error::TimesheetForExpenses);
+            }
+            if items.time_off().is_some() {
+                return Err(Error::TimesheetWithTimeOff);
+            }
+            let (start, end) = period_bounds(target_period_end_date, cadence)?;
+            timesheet.validate_within(start, end)?;
+        }
 
         let number = calculate_invoice_number(
-            self.information().offset(),
+            self.information().offset_for_client(client_id)?,
             &target_period_end_date,
             cadence,
             is_expenses,
@@ -173,8 +445,15 @@ impl Data {
             "calculated invoice_number: {} (cadence: {:?}, target_date: {}, expenses: {})",
             number, cadence, target_period_end_date, is_expenses
         );
+        let formatted_number = self.formatted_invoice_number(
+            client_id,
+            &number,
+            &target_period_end_date,
+            is_expenses,
+        )?;
         let is_expenses_str_or_empty = if is_expenses { "_expenses" } else { "" };
         let vendor_name = self.vendor.company_name().replace(' ', "_");
+        let client_id_str_or_empty = client_id.map(|id| format!("_{id}")).unwrap_or_default();
 
         let output_path = input
             .maybe_output_path()
@@ -183,13 +462,63 @@ impl Data {
             .map(OutputPath::AbsolutePath)
             .unwrap_or_else(|| {
                 OutputPath::Name(format!(
-                    "{}_{}{}_invoice_{}.pdf",
-                    invoice_date, vendor_name, is_expenses_str_or_empty, number
+                    "{}_{}{}{}_invoice_{}.pdf",
+                    invoice_date,
+                    vendor_name,
+                    client_id_str_or_empty,
+                    is_expenses_str_or_empty,
+                    formatted_number
                 ))
             });
 
+        // Expenses are recorded for all clients alike, so with more than one
+        // client they would be billed to each of them.
+        if !matches!(items, InvoicedItems::Service { .. }) && !self.clients.is_empty() {
+            return Err(Error::ExpensesWithSeveralClients {
+                clients: self.clients.len() + 1,
+            });
+        }
+        let line_items = match items {
+            InvoicedItems::Service { time_off } => {
+                LineItemsPricedInSourceCurrency::Service(self.service_items(
+                    &target_period_end_date,
+                    cadence,
+                    time_off,
+                    timesheet,
+                    bank_holidays,
+                )?)
+            }
+            InvoicedItems::Expenses => {
+                let expenses = self.expensed_periods.get(&target_period_end_date)?;
+                if !expenses.iter().any(|expense| *expense.billable()) {
+                    return Err(Error::TargetPeriodHasNoBillableExpenses {
+                        target_period: target_period_end_date.to_string(),
+                    });
+                }
+                LineItemsPricedInSourceCurrency::Expenses(expenses.clone())
+            }
+            InvoicedItems::Combined { time_off } => {
+                let services = self.service_items(
+                    &target_period_end_date,
+                    cadence,
+                    time_off,
+                    timesheet,
+                    bank_holidays,
+                )?;
+                // A combined invoice is still due when no expenses
+                // were recorded for the period.
+                let expenses = self
+                    .expensed_periods
+                    .get(&target_period_end_date)
+                    .unwrap_or_default();
+                LineItemsPricedInSourceCurrency::Combined { services, expenses }
+            }
+        };
+        let receipts = InvoiceReceipt::for_expenses(&line_items.expenses(), *input.receipts());
+
         let full_info = InvoiceInfoFull::builder()
             .due_date(due_date)
+            .terms(terms.invoice_terms(invoice_date))
             .invoice_date(invoice_date)
             .emphasize_color_hex(
                 self.information()
@@ -199,47 +528,29 @@ impl Data {
             )
             .maybe_footer_text(self.information().footer_text().clone())
             .number(number)
+            .formatted_number(formatted_number)
             .maybe_purchase_order(self.information().purchase_order().clone())
+            .days_off(
+                items
+                    .time_off()
+                    .and_then(TimeOff::listed_days_off)
+                    .cloned()
+                    .unwrap_or_default(),
+            )
+            .timesheet(
+                timesheet
+                    .filter(|timesheet| *timesheet.itemized())
+                    .map(|timesheet| timesheet.entries().clone())
+                    .unwrap_or_default(),
+            )
+            .receipts(receipts)
             .build();
 
         let input_unpriced =
             DataFromDiskWithItemsOfKind::<LineItemsPricedInSourceCurrency>::builder()
-                .client(self.client.clone())
+                .client(client)
                 .information(full_info)
-                .line_items(match items {
-                    InvoicedItems::Service { time_off } => {
-                        if let Some(time_off) = time_off {
-                            if time_off.granularity() != self.service_fees().rate().granularity() {
-                                return Err(Error::InvalidGranularityForTimeOff {
-                                    free_granularity: time_off.granularity(),
-                                    service_fees_granularity: self
-                                        .service_fees()
-                                        .rate()
-                                        .granularity(),
-                                });
-                            }
-                        }
-
-                        let quantity = self.billable_quantity(
-                            &target_period_end_date,
-                            cadence,
-                            time_off,
-                            bank_holidays,
-                        )?;
-                        let service = Item::builder()
-                            .name(self.service_fees.name().clone())
-                            .transaction_date(invoice_date)
-                            .quantity(quantity)
-                            .unit_price(self.service_fees.unit_price())
-                            .currency(*self.payment_info.currency())
-                            .build();
-                        LineItemsPricedInSourceCurrency::Service(service)
-                    }
-                    InvoicedItems::Expenses => {
-                        let expenses = self.expensed_periods.get(&target_period_end_date)?;
-                        LineItemsPricedInSourceCurrency::Expenses(expenses.clone())
-                    }
-                })
+                .line_items(line_items)
                 .payment_info(self.payment_info)
                 .vendor(self.vendor)
                 .output_path(output_path)
@@ -276,7 +587,9 @@ impl HasSample for Data {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Granularity, Rate};
+    use crate::{
+        InvoiceNumberFormat, InvoiceNumberSeries, NumberReset, Rate, TimestampedInvoiceNumber,
+    };
     use rust_decimal::dec;
 
     type Sut = Data;
@@ -338,6 +651,207 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn to_partial_bills_each_service_as_own_item() {
+        let service_fees = ServiceFees::builder()
+            .name("Development")
+            .rate(Rate::hourly(dec!(95.0)))
+            .cadence(Cadence::Monthly)
+            .additional_services(vec![crate::Service::sample()])
+            .build()
+            .unwrap();
+        let sut = Data::builder()
+            .information(ProtoInvoiceInfo::sample())
+            .client(CompanyInformation::sample_client())
+            .vendor(CompanyInformation::sample_vendor())
+            .payment_info(PaymentInformation::sample())
+            .service_fees(service_fees)
+            .expensed_periods(ExpensedPeriods::sample())
+            .build();
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service {
+                time_off: Some(TimeOff::Hours(Quantity::from(dec!(16.0)))),
+            })
+            .date("2025-05-31".parse().unwrap())
+            .build();
+
+        let partial = sut.to_partial(input, &BankHolidays::default()).unwrap();
+        let LineItemsPricedInSourceCurrency::Service(items) = partial.line_items() else {
+            panic!("expected service line items");
+        };
+        let rows = items
+            .iter()
+            .map(|item| (item.name().as_str(), *item.quantity()))
+            .collect::<Vec<_>>();
+        // Time off is only deducted from the primary service: 22 working days
+        // of 8 hours minus 16 hours, while the monthly on-call fee stays 1.
+        assert_eq!(
+            rows,
+            vec![
+                ("Development", Quantity::from(dec!(160.0))),
+                ("On-call", Quantity::ONE),
+            ]
+        );
+    }
+
+    fn service_items_with_retainer(included: rust_decimal::Decimal) -> Vec<Item> {
+        let service_fees = ServiceFees::builder()
+            .name("Support beyond retainer")
+            .rate(Rate::hourly(dec!(120.0)))
+            .cadence(Cadence::Monthly)
+            .retainer(
+                crate::Retainer::builder()
+                    .name("Support retainer")
+                    .fee(crate::UnitPrice::from(dec!(4000.0)))
+                    .included(Quantity::from(included))
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        let sut = Data::builder()
+            .information(ProtoInvoiceInfo::sample())
+            .client(CompanyInformation::sample_client())
+            .vendor(CompanyInformation::sample_vendor())
+            .payment_info(PaymentInformation::sample())
+            .service_fees(service_fees)
+            .expensed_periods(ExpensedPeriods::sample())
+            .build();
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service {
+                time_off: Some(TimeOff::Hours(Quantity::from(dec!(16.0)))),
+            })
+            .date("2025-05-31".parse().unwrap())
+            .build();
+        let partial = sut.to_partial(input, &BankHolidays::default()).unwrap();
+        let LineItemsPricedInSourceCurrency::Service(items) = partial.line_items() else {
+            panic!("expected service line items");
+        };
+        items.clone()
+    }
+
+    #[test]
+    fn to_partial_bills_hours_beyond_retainer_as_overage() {
+        let items = service_items_with_retainer(dec!(150.0));
+        let rows = items
+            .iter()
+            .map(|item| (item.name().as_str(), *item.quantity(), **item.unit_price()))
+            .collect::<Vec<_>>();
+        // 176 working hours in May 2025 minus 16 hours off is 160 hours, of
+        // which 10 are beyond the 150 included in the retainer.
+        assert_eq!(
+            rows,
+            vec![
+                ("Support retainer", Quantity::ONE, dec!(4000.0)),
+                (
+                    "Support beyond retainer",
+                    Quantity::from(dec!(10.0)),
+                    dec!(120.0)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_partial_bills_only_retainer_when_within_included_hours() {
+        let items = service_items_with_retainer(dec!(160.0));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name(), "Support retainer");
+    }
+
+    fn data_with_registered_client() -> Data {
+        let mut information = ProtoInvoiceInfo::sample();
+        information
+            .insert_client_offset(ClientId::sample(), TimestampedInvoiceNumber::sample_other());
+        Sut::sample()
+            .with_clients(Clients::sample())
+            .with_information(information)
+    }
+
+    #[test]
+    fn to_partial_refuses_expenses_of_other_clients() {
+        let sut = data_with_registered_client();
+        for client in [None, Some(ClientId::sample())] {
+            for items in [
+                InvoicedItems::Expenses,
+                InvoicedItems::Combined { time_off: None },
+            ] {
+                let input = ValidInput::builder()
+                    .items(items)
+                    .date(crate::Date::sample())
+                    .maybe_client(client.clone())
+                    .build();
+                assert!(matches!(
+                    sut.clone().to_partial(input, &BankHolidays::default()),
+                    Err(Error::ExpensesWithSeveralClients { clients: 2 })
+                ));
+            }
+        }
+        let services = ValidInput::builder()
+            .date(crate::Date::sample())
+            .client(ClientId::sample())
+            .build();
+        assert!(sut.to_partial(services, &BankHolidays::default()).is_ok());
+    }
+
+    #[test]
+    fn validate_succeeds_with_registered_client_with_offset() {
+        assert!(data_with_registered_client().validate().is_ok());
+    }
+
+    #[test]
+    fn validate_fails_when_registered_client_has_no_offset() {
+        let sut = Sut::sample().with_clients(Clients::sample());
+        assert!(matches!(
+            sut.validate(),
+            Err(Error::MissingInvoiceNumberOffsetForClient { client }) if client == "holmes"
+        ));
+    }
+
+    #[test]
+    fn validate_fails_when_offset_belongs_to_unknown_client() {
+        let mut information = ProtoInvoiceInfo::sample();
+        information
+            .insert_client_offset(ClientId::sample(), TimestampedInvoiceNumber::sample_other());
+        let sut = Sut::sample().with_information(information);
+        assert!(matches!(
+            sut.validate(),
+            Err(Error::UnknownClient { client }) if client == "holmes"
+        ));
+    }
+
+    #[test]
+    fn to_partial_for_registered_client_uses_its_offset_and_company() {
+        let sut = data_with_registered_client();
+        let default_client = sut
+            .clone()
+            .to_partial(ValidInput::sample(), &BankHolidays::default())
+            .unwrap();
+        let input = ValidInput::builder()
+            .date(crate::Date::sample())
+            .client(ClientId::sample())
+            .build();
+        let registered_client = sut.to_partial(input, &BankHolidays::default()).unwrap();
+
+        assert_eq!(
+            registered_client.client(),
+            &CompanyInformation::sample_client()
+        );
+        assert_ne!(
+            registered_client.information().number(),
+            default_client.information().number()
+        );
+    }
+
+    #[test]
+    fn to_partial_fails_for_unknown_client() {
+        let input = ValidInput::builder()
+            .date(crate::Date::sample())
+            .client(ClientId::sample_other())
+            .build();
+        let result = data_with_registered_client().to_partial(input, &BankHolidays::default());
+        assert!(matches!(result, Err(Error::UnknownClient { .. })));
+    }
+
     #[test]
     fn expenses() {
         let sut = Sut::sample();
@@ -349,6 +863,252 @@ mod tests {
         assert!(partial.line_items().is_expenses());
     }
 
+    #[test]
+    fn to_partial_adds_recurring_items_to_service_invoices_only() {
+        let sut = Sut::sample().with_recurring_items(crate::RecurringItems::sample());
+        let service = sut
+            .clone()
+            .to_partial(ValidInput::sample(), &BankHolidays::default())
+            .unwrap();
+        let LineItemsPricedInSourceCurrency::Service(items) = service.line_items() else {
+            panic!("expected service line items");
+        };
+        assert_eq!(items.last().unwrap().name(), "Hosting");
+
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .date(crate::Date::sample())
+            .build();
+        let expenses = sut.to_partial(input, &BankHolidays::default()).unwrap();
+        assert!(
+            expenses
+                .line_items()
+                .expenses()
+                .iter()
+                .all(|item| item.name() != "Hosting")
+        );
+    }
+
+    fn data_with_number_series() -> Sut {
+        let series = InvoiceNumberSeries::builder()
+            .services(
+                InvoiceNumberFormat::builder()
+                    .year(true)
+                    .padding(3)
+                    .reset(NumberReset::Yearly)
+                    .build(),
+            )
+            .expenses(
+                InvoiceNumberFormat::builder()
+                    .prefix("EXP-")
+                    .padding(3)
+                    .build(),
+            )
+            .build();
+        Sut::sample().with_information(ProtoInvoiceInfo::sample().with_number_series(series))
+    }
+
+    #[test]
+    fn to_partial_formats_number_with_default_series() {
+        let input = ValidInput::builder().date(crate::Date::sample()).build();
+        let partial = Sut::sample()
+            .to_partial(input, &BankHolidays::default())
+            .unwrap();
+        assert_eq!(
+            partial.information().formatted_number(),
+            &partial.information().number().to_string()
+        );
+    }
+
+    #[test]
+    fn to_partial_restarts_services_number_yearly() {
+        // The offset is invoice 17 for December 2024, so May 2025 is the
+        // fifth invoice of 2025.
+        let input = ValidInput::builder().date(crate::Date::sample()).build();
+        let partial = data_with_number_series()
+            .to_partial(input, &BankHolidays::default())
+            .unwrap();
+        assert_eq!(*partial.information().number(), InvoiceNumber::from(22));
+        assert_eq!(partial.information().formatted_number(), "2025-005");
+        assert!(
+            partial
+                .absolute_path_and_name()
+                .unwrap()
+                .name()
+                .ends_with("_invoice_2025-005.pdf")
+        );
+    }
+
+    fn formatted_services_number(cadence: Cadence, date: &str) -> String {
+        let service_fees = ServiceFees::builder()
+            .name("Consulting")
+            .rate(Rate::daily(dec!(777.0)))
+            .cadence(cadence)
+            .build()
+            .unwrap();
+        let sut = Data::builder()
+            .information(data_with_number_series().information().clone())
+            .client(CompanyInformation::sample_client())
+            .vendor(CompanyInformation::sample_vendor())
+            .payment_info(PaymentInformation::sample())
+            .service_fees(service_fees)
+            .expensed_periods(ExpensedPeriods::sample())
+            .build();
+        let input = ValidInput::builder().date(date.parse().unwrap()).build();
+        sut.to_partial(input, &BankHolidays::default())
+            .unwrap()
+            .information()
+            .formatted_number()
+            .clone()
+    }
+
+    #[test]
+    fn to_partial_restarts_weekly_services_number_yearly() {
+        // The week of 2026-01-02 ends in 2026 but starts in 2025.
+        assert_eq!(
+            formatted_services_number(Cadence::Weekly, "2025-12-24"),
+            "2025-052"
+        );
+        assert_eq!(
+            formatted_services_number(Cadence::Weekly, "2026-01-02"),
+            "2026-001"
+        );
+        assert_eq!(
+            formatted_services_number(Cadence::Weekly, "2026-01-09"),
+            "2026-002"
+        );
+    }
+
+    #[test]
+    fn to_partial_restarts_iso_bi_weekly_services_number_yearly() {
+        assert_eq!(
+            formatted_services_number(Cadence::IsoBiWeekly, "2026-01-02"),
+            "2026-001"
+        );
+        assert_eq!(
+            formatted_services_number(Cadence::IsoBiWeekly, "2026-01-16"),
+            "2026-002"
+        );
+    }
+
+    #[test]
+    fn to_partial_numbers_expenses_in_separate_series() {
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .date(crate::Date::sample())
+            .build();
+        let partial = data_with_number_series()
+            .to_partial(input, &BankHolidays::default())
+            .unwrap();
+        // The offset is December 2024, so May 2025 is the fifth period.
+        assert_eq!(partial.information().formatted_number(), "EXP-005");
+    }
+
+    fn expenses_information(sut: &Sut, date: &str) -> InvoiceInfoFull {
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .date(date.parse().unwrap())
+            .build();
+        sut.clone()
+            .to_partial(input, &BankHolidays::default())
+            .unwrap()
+            .information()
+            .clone()
+    }
+
+    #[test]
+    fn to_partial_numbers_expenses_by_period_regardless_of_order() {
+        let mut sut = data_with_number_series();
+        let mut expensed_periods = sut.expensed_periods().clone();
+        expensed_periods.insert_expenses(
+            &"2025-04-30".parse().unwrap(),
+            vec![Item::sample_expense_coffee()],
+        );
+        sut.set_expensed_periods(expensed_periods);
+
+        // May generated before and after April keeps its number, and April,
+        // generated after May, is not numbered after it.
+        let may_before_april = expenses_information(&sut, "2025-05-31");
+        let april = expenses_information(&sut, "2025-04-30");
+        let may_after_april = expenses_information(&sut, "2025-05-31");
+
+        assert_eq!(may_before_april.formatted_number(), "EXP-005");
+        assert_eq!(april.formatted_number(), "EXP-004");
+        assert_eq!(may_after_april.formatted_number(), "EXP-005");
+    }
+
+    #[test]
+    fn to_partial_fails_for_expenses_without_billable_items() {
+        let mut sut = Sut::sample();
+        sut.set_expensed_periods(ExpensedPeriods::new(indexmap::IndexMap::from([(
+            crate::Date::sample(),
+            vec![Item::sample_expense_coffee().with_billable(false)],
+        )])));
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .date(crate::Date::sample())
+            .build();
+        assert_eq!(
+            sut.to_partial(input, &BankHolidays::default()),
+            Err(Error::TargetPeriodHasNoBillableExpenses {
+                target_period: "2025-05-31".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn to_partial_combined_merges_services_and_expenses_under_service_number() {
+        let date = crate::Date::sample();
+        let services = Sut::sample()
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Service { time_off: None })
+                    .date(date)
+                    .build(),
+                &BankHolidays::default(),
+            )
+            .unwrap();
+        let combined = Sut::sample()
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Combined { time_off: None })
+                    .date(date)
+                    .build(),
+                &BankHolidays::default(),
+            )
+            .unwrap();
+
+        assert!(!combined.line_items().is_expenses());
+        assert_eq!(
+            combined.information().number(),
+            services.information().number()
+        );
+        let LineItemsPricedInSourceCurrency::Combined {
+            services: service_items,
+            expenses,
+        } = combined.line_items()
+        else {
+            panic!("expected combined line items");
+        };
+        assert_eq!(
+            LineItemsPricedInSourceCurrency::Service(service_items.clone()),
+            *services.line_items()
+        );
+        assert_eq!(*expenses, vec![Item::sample_expense_breakfast()]);
+    }
+
+    #[test]
+    fn to_partial_combined_without_expenses_for_period() {
+        let mut sut = Sut::sample();
+        sut.set_expensed_periods(ExpensedPeriods::default());
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Combined { time_off: None })
+            .date(crate::Date::sample())
+            .build();
+        let partial = sut.to_partial(input, &BankHolidays::default()).unwrap();
+        assert!(partial.line_items().expenses().is_empty());
+    }
+
     #[test]
     fn to_partial_with_free_time_with_invalid_granularity_hour_instead_of_expected_day() {
         let service_fees_hour = ServiceFees::builder()
@@ -391,6 +1151,368 @@ mod tests {
         }
     }
 
+    fn dates_off_input(listed: bool) -> ValidInput {
+        let days_off = ["2025-05-16..2025-05-19", "2025-05-20:half"]
+            .into_iter()
+            .map(|days| days.parse::<DaysOff>().unwrap())
+            .collect::<DaysOff>();
+        ValidInput::builder()
+            .items(InvoicedItems::Service {
+                time_off: Some(TimeOff::Dates { days_off, listed }),
+            })
+            .date(crate::Date::sample())
+            .build()
+    }
+
+    fn service_quantity(partial: &DataWithItemsPricedInSourceCurrency) -> Quantity {
+        let LineItemsPricedInSourceCurrency::Service(items) = partial.line_items() else {
+            panic!("expected service line items");
+        };
+        *items[0].quantity()
+    }
+
+    #[test]
+    fn to_partial_deducts_dates_off_on_working_days() {
+        // May 2025 has 22 working days, the weekend of 2025-05-17 and
+        // 2025-05-18 is not deducted.
+        let partial = Sut::sample()
+            .to_partial(dates_off_input(false), &BankHolidays::default())
+            .unwrap();
+        assert_eq!(service_quantity(&partial), Quantity::from(dec!(19.5)));
+        assert!(partial.information().days_off().is_empty());
+    }
+
+    #[test]
+    fn to_partial_does_not_deduct_dates_off_on_bank_holidays_twice() {
+        let holidays = BankHolidays::new(["2025-05-19".parse::<crate::Date>().unwrap()]);
+        let partial = Sut::sample()
+            .to_partial(dates_off_input(true), &holidays)
+            .unwrap();
+        assert_eq!(service_quantity(&partial), Quantity::from(dec!(19.5)));
+        assert_eq!(partial.information().days_off().len(), 5);
+    }
+
+    #[test]
+    fn to_partial_deducts_dates_off_from_primary_service_only() {
+        let service_fees = ServiceFees::builder()
+            .name("Development")
+            .rate(Rate::hourly(dec!(95.0)))
+            .cadence(Cadence::Monthly)
+            .additional_services(vec![
+                crate::Service::builder()
+                    .name("Pairing")
+                    .rate(Rate::hourly(dec!(60.0)))
+                    .build(),
+            ])
+            .build()
+            .unwrap();
+        let sut = Data::builder()
+            .information(ProtoInvoiceInfo::sample())
+            .client(CompanyInformation::sample_client())
+            .vendor(CompanyInformation::sample_vendor())
+            .payment_info(PaymentInformation::sample())
+            .service_fees(service_fees)
+            .expensed_periods(ExpensedPeriods::sample())
+            .build();
+        let partial = sut
+            .to_partial(dates_off_input(false), &BankHolidays::default())
+            .unwrap();
+        let LineItemsPricedInSourceCurrency::Service(items) = partial.line_items() else {
+            panic!("expected service line items");
+        };
+        let rows = items
+            .iter()
+            .map(|item| (item.name().as_str(), *item.quantity()))
+            .collect::<Vec<_>>();
+        // 22 working days of 8 hours, minus 2.5 days off for the primary
+        // service only.
+        assert_eq!(
+            rows,
+            vec![
+                ("Development", Quantity::from(dec!(156.0))),
+                ("Pairing", Quantity::from(dec!(176.0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_partial_fails_for_dates_off_without_daily_or_hourly_service() {
+        let sut = Data::builder()
+            .information(ProtoInvoiceInfo::sample())
+            .vendor(CompanyInformation::sample_vendor())
+            .client(CompanyInformation::sample_client())
+            .payment_info(PaymentInformation::sample())
+            .service_fees(
+                ServiceFees::builder()
+                    .name("Retainer")
+                    .rate(Rate::monthly(dec!(15000.0)))
+                    .cadence(Cadence::Monthly)
+                    .build()
+                    .unwrap(),
+            )
+            .expensed_periods(ExpensedPeriods::sample())
+            .build();
+        let result = sut.to_partial(dates_off_input(false), &BankHolidays::default());
+        assert!(matches!(
+            result,
+            Err(Error::DaysOffRequireDailyOrHourlyService {
+                service_fees_granularity: Granularity::Month
+            })
+        ));
+    }
+
+    fn with_rate_history(cadence: Cadence) -> Data {
+        let service_fees = ServiceFees::builder()
+            .name("Consulting")
+            .rate(Rate::daily(dec!(777.0)))
+            .cadence(cadence)
+            .rate_history(vec![crate::EffectiveRate::sample()])
+            .build()
+            .unwrap();
+        Data::builder()
+            .information(ProtoInvoiceInfo::sample())
+            .client(CompanyInformation::sample_client())
+            .vendor(CompanyInformation::sample_vendor())
+            .payment_info(PaymentInformation::sample())
+            .service_fees(service_fees)
+            .expensed_periods(ExpensedPeriods::sample())
+            .build()
+    }
+
+    fn service_unit_price(sut: Data, date: &str) -> Result<crate::UnitPrice> {
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .date(date.parse().unwrap())
+            .build();
+        let partial = sut.to_partial(input, &BankHolidays::default())?;
+        let LineItemsPricedInSourceCurrency::Service(items) = partial.line_items() else {
+            panic!("expected service line items");
+        };
+        Ok(*items[0].unit_price())
+    }
+
+    #[test]
+    fn to_partial_bills_rate_effective_in_period() {
+        let sut = with_rate_history(Cadence::Monthly);
+        assert_eq!(
+            service_unit_price(sut.clone(), "2025-06-30").unwrap(),
+            crate::UnitPrice::from(dec!(777.0))
+        );
+        assert_eq!(
+            service_unit_price(sut, "2025-07-31").unwrap(),
+            crate::UnitPrice::from(dec!(850.0))
+        );
+    }
+
+    #[test]
+    fn to_partial_fails_for_rate_change_within_period() {
+        let sut = with_rate_history(Cadence::IsoBiWeekly);
+        // ISO fortnights start on a Monday, the one of 2025-07-02 starts before
+        // the rate change on Tuesday 2025-07-01.
+        assert!(matches!(
+            service_unit_price(sut, "2025-07-02"),
+            Err(Error::RateChangeWithinPeriod { .. })
+        ));
+    }
+
+    #[test]
+    fn to_partial_calculates_due_date_and_terms_from_payment_terms() {
+        let partial = |terms: &str| {
+            Data::builder()
+                .information(ProtoInvoiceInfo::sample())
+                .client(CompanyInformation::sample_client())
+                .vendor(CompanyInformation::sample_vendor())
+                .payment_info(PaymentInformation::sample().with_terms(terms.parse().unwrap()))
+                .service_fees(ServiceFees::sample())
+                .expensed_periods(ExpensedPeriods::sample())
+                .build()
+                .to_partial(
+                    ValidInput::builder()
+                        .date("2025-05-31".parse().unwrap())
+                        .build(),
+                    &BankHolidays::default(),
+                )
+                .unwrap()
+        };
+        for (terms, due_date) in [
+            ("Net 30", "2025-06-30"),
+            ("Net 30 EOM", "2025-06-30"),
+            ("Due on receipt", "2025-05-31"),
+            ("Day 15 next month", "2025-06-15"),
+            ("2/10 Net 30", "2025-06-30"),
+        ] {
+            let information = partial(terms).information().clone();
+            assert_eq!(information.due_date().to_string(), due_date, "{terms}");
+        }
+
+        let terms = partial("2/10 Net 30").information().terms().clone();
+        assert_eq!(*terms.kind(), crate::PaymentTermsKind::Discount);
+        let discount = terms.early_payment_discount().unwrap();
+        assert_eq!(discount.deadline().to_string(), "2025-06-10");
+    }
+
+    fn timesheet_input(timesheet: Timesheet) -> ValidInput {
+        ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .date("2025-05-31".parse().unwrap())
+            .timesheet(timesheet)
+            .build()
+    }
+
+    #[test]
+    fn to_partial_bills_hours_of_timesheet() {
+        let service_fees = ServiceFees::builder()
+            .name("Development")
+            .rate(Rate::hourly(dec!(95.0)))
+            .cadence(Cadence::Monthly)
+            .additional_services(vec![crate::Service::sample()])
+            .build()
+            .unwrap();
+        let sut = Data::builder()
+            .information(ProtoInvoiceInfo::sample())
+            .client(CompanyInformation::sample_client())
+            .vendor(CompanyInformation::sample_vendor())
+            .payment_info(PaymentInformation::sample())
+            .service_fees(service_fees)
+            .expensed_periods(ExpensedPeriods::sample())
+            .build();
+        let partial = sut
+            .to_partial(
+                timesheet_input(Timesheet::sample()),
+                &BankHolidays::default(),
+            )
+            .unwrap();
+        let LineItemsPricedInSourceCurrency::Service(items) = partial.line_items() else {
+            panic!("expected service line items");
+        };
+        let rows = items
+            .iter()
+            .map(|item| (item.name().as_str(), *item.quantity()))
+            .collect::<Vec<_>>();
+        // The hourly service bills the 15.5 hours of the timesheet, the
+        // monthly on-call fee stays 1.
+        assert_eq!(
+            rows,
+            vec![
+                ("Development", Quantity::from(dec!(15.5))),
+                ("On-call", Quantity::ONE),
+            ]
+        );
+        assert!(partial.information().timesheet().is_empty());
+    }
+
+    #[test]
+    fn to_partial_bills_timesheet_for_primary_service_only() {
+        let service_fees = ServiceFees::builder()
+            .name("Development")
+            .rate(Rate::hourly(dec!(95.0)))
+            .cadence(Cadence::Monthly)
+            .additional_services(vec![
+                crate::Service::builder()
+                    .name("Pairing")
+                    .rate(Rate::hourly(dec!(60.0)))
+                    .build(),
+            ])
+            .build()
+            .unwrap();
+        let sut = Data::builder()
+            .information(ProtoInvoiceInfo::sample())
+            .client(CompanyInformation::sample_client())
+            .vendor(CompanyInformation::sample_vendor())
+            .payment_info(PaymentInformation::sample())
+            .service_fees(service_fees)
+            .expensed_periods(ExpensedPeriods::sample())
+            .build();
+        let partial = sut
+            .to_partial(
+                timesheet_input(Timesheet::sample()),
+                &BankHolidays::default(),
+            )
+            .unwrap();
+        let LineItemsPricedInSourceCurrency::Service(items) = partial.line_items() else {
+            panic!("expected service line items");
+        };
+        let rows = items
+            .iter()
+            .map(|item| (item.name().as_str(), *item.quantity()))
+            .collect::<Vec<_>>();
+        // The hours of the timesheet are not billed twice, the additional
+        // hourly service bills the 22 working days of 8 hours of May 2025.
+        assert_eq!(
+            rows,
+            vec![
+                ("Development", Quantity::from(dec!(15.5))),
+                ("Pairing", Quantity::from(dec!(176.0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_partial_bills_timesheet_in_days_of_work_schedule() {
+        // The sample bills per day, 15.5 hours of 8 hour days.
+        let partial = Sut::sample()
+            .to_partial(
+                timesheet_input(Timesheet::sample()),
+                &BankHolidays::default(),
+            )
+            .unwrap();
+        assert_eq!(service_quantity(&partial), Quantity::from(dec!(1.9375)));
+    }
+
+    #[test]
+    fn to_partial_itemizes_timesheet() {
+        let partial = Sut::sample()
+            .to_partial(
+                timesheet_input(Timesheet::sample_other()),
+                &BankHolidays::default(),
+            )
+            .unwrap();
+        assert_eq!(
+            partial.information().timesheet(),
+            Timesheet::sample_other().entries()
+        );
+    }
+
+    #[test]
+    fn to_partial_fails_for_timesheet_outside_period() {
+        let input = timesheet_input(Timesheet::sample()).with_date("2025-06-30".parse().unwrap());
+        let result = Sut::sample().to_partial(input, &BankHolidays::default());
+        assert_eq!(
+            result.unwrap_err(),
+            Error::TimesheetEntryOutsidePeriod {
+                date: "2025-05-12".parse().unwrap(),
+                start: "2025-06-01".parse().unwrap(),
+                end: "2025-06-30".parse().unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn to_partial_fails_for_timesheet_with_time_off_or_expenses() {
+        let with_time_off = ValidInput::builder()
+            .items(InvoicedItems::sample())
+            .date("2025-05-31".parse().unwrap())
+            .timesheet(Timesheet::sample())
+            .build();
+        assert_eq!(
+            Sut::sample()
+                .to_partial(with_time_off, &BankHolidays::default())
+                .unwrap_err(),
+            Error::TimesheetWithTimeOff
+        );
+        let expenses = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .date("2025-05-31".parse().unwrap())
+            .timesheet(Timesheet::sample())
+            .build();
+        assert_eq!(
+            Sut::sample()
+                .to_partial(expenses, &BankHolidays::default())
+                .unwrap_err(),
+            Error::TimesheetForExpenses
+        );
+    }
+
     #[test]
     fn billable_quantity_clamped_to_zero_when_time_off_exceeds_period() {
         // Sample uses a daily rate; 100 days off far exceeds any month's working
@@ -404,9 +1526,9 @@ mod tests {
             .build();
 
         let partial = sut.to_partial(input, &BankHolidays::default()).unwrap();
-        let LineItemsPricedInSourceCurrency::Service(item) = partial.line_items() else {
+        let LineItemsPricedInSourceCurrency::Service(items) = partial.line_items() else {
             panic!("expected service line items");
         };
-        assert_eq!(*item.quantity(), Quantity::ZERO);
+        assert_eq!(*items[0].quantity(), Quantity::ZERO);
     }
 }
@@ -3,10 +3,11 @@ pub const INVOICES_FOLDER_NAME: &str = "invoices";
 use klirr_foundation::{PathAndName, ToTypst, resolve_output_path_and_name};
 
 use crate::{
-    CompanyInformation, Error, ExchangeRates, HasSample, InvoiceInfoFull, LineItemsFlat,
-    LineItemsPricedInSourceCurrency, MaybeIsExpenses, OutputPath, PaymentInformation, Result,
+    CompanyInformation, Currency, Date, Error, ExchangeRates, HasSample, InvoiceInfoFull,
+    InvoiceNumber, LineItemsFlat, LineItemsPricedInSourceCurrency, MaybeIsExpenses, OutputPath,
+    PaymentInformation, ReportingTotals, Result, SumTotals, UnitPrice,
 };
-use bon::Builder;
+use bon::bon;
 use getset::Getters;
 use serde::Deserialize;
 use serde::Serialize;
@@ -19,8 +20,8 @@ impl ToTypst for PreparedData {}
 
 /// The input data for the invoice, which includes information about the invoice,
 /// the vendor, and the client and the products/services included in the invoice.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters)]
-pub struct DataFromDiskWithItemsOfKind<Items: Serialize + MaybeIsExpenses> {
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Getters)]
+pub struct DataFromDiskWithItemsOfKind<Items: Serialize + MaybeIsExpenses + SumTotals> {
     /// Information about this specific invoice.
     #[getset(get = "pub")]
     information: InvoiceInfoFull,
@@ -45,9 +46,47 @@ pub struct DataFromDiskWithItemsOfKind<Items: Serialize + MaybeIsExpenses> {
 
     /// Where to save the output PDF file.
     output_path: OutputPath,
+
+    /// The subtotal, VAT and grand total of `line_items`, computed when this
+    /// is built, so that every consumer shares the same exact amounts.
+    #[getset(get = "pub")]
+    totals: Items::Totals,
+
+    /// The totals converted into the reporting currency of `payment_info`,
+    /// if it has one, see [`Self::with_reporting_totals`].
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reporting_totals: Option<ReportingTotals>,
 }
 
-impl<Items: Serialize + MaybeIsExpenses> DataFromDiskWithItemsOfKind<Items> {
+#[bon]
+impl<Items: Serialize + MaybeIsExpenses + SumTotals> DataFromDiskWithItemsOfKind<Items> {
+    #[builder]
+    pub fn new(
+        information: InvoiceInfoFull,
+        vendor: CompanyInformation,
+        client: CompanyInformation,
+        line_items: Items,
+        payment_info: PaymentInformation,
+        output_path: OutputPath,
+    ) -> Self {
+        let totals = line_items.sum_totals(
+            *payment_info.vat(),
+            *client.reverse_charge(),
+            information.terms().early_payment_discount().as_ref(),
+        );
+        Self {
+            information,
+            vendor,
+            client,
+            line_items,
+            payment_info,
+            output_path,
+            totals,
+            reporting_totals: None,
+        }
+    }
+
     /// Returns the absolute path where the invoice will be saved.
     /// If the path is relative (`OutputPath::Name`), it will be created in
     /// `$HOME/invoices`.
@@ -80,7 +119,7 @@ impl<Items: Serialize + MaybeIsExpenses> DataFromDiskWithItemsOfKind<Items> {
     }
 }
 
-impl<Items: Serialize + MaybeIsExpenses + HasSample> HasSample
+impl<Items: Serialize + MaybeIsExpenses + SumTotals + HasSample> HasSample
     for DataFromDiskWithItemsOfKind<Items>
 {
     fn sample() -> Self {
@@ -126,13 +165,65 @@ impl DataWithItemsPricedInSourceCurrency {
     /// ```
     pub fn to_typst(self, exchange_rates: ExchangeRates) -> Result<PreparedData> {
         let line_items = LineItemsFlat::try_from((self.line_items, exchange_rates))?;
-        Ok(PreparedData {
-            line_items,
-            information: self.information,
-            vendor: self.vendor,
-            client: self.client,
-            payment_info: self.payment_info,
-            output_path: self.output_path,
+        Ok(PreparedData::builder()
+            .line_items(line_items)
+            .information(self.information)
+            .vendor(self.vendor)
+            .client(self.client)
+            .payment_info(self.payment_info)
+            .output_path(self.output_path)
+            .build())
+    }
+}
+
+impl PreparedData {
+    /// Adds the totals converted into `currency` at `rate`, the amount in
+    /// `currency` of one unit of the currency of the invoice on `rate_date`,
+    /// as given by `source`, see [`ReportingTotals`].
+    pub fn with_reporting_totals(
+        self,
+        currency: Currency,
+        rate: UnitPrice,
+        rate_date: Date,
+        source: impl Into<String>,
+    ) -> Self {
+        let reporting_totals =
+            ReportingTotals::new(&self.totals, currency, rate, rate_date, source);
+        Self {
+            reporting_totals: Some(reporting_totals),
+            ..self
+        }
+    }
+
+    /// Turns this prepared invoice into credit note `number`, printed as
+    /// `formatted_number` and issued at `date`, crediting `lines` of the
+    /// invoice, see [`LineItemsFlat::credited`].
+    ///
+    /// # Errors
+    /// Returns an error if any of `lines` does not exist on the invoice.
+    pub fn credit_note(
+        self,
+        number: InvoiceNumber,
+        formatted_number: String,
+        date: Date,
+        lines: &[usize],
+        output_path: OutputPath,
+    ) -> Result<Self> {
+        let credit_note = Self::builder()
+            .line_items(self.line_items.credited(lines)?)
+            .information(self.information.credit_note(number, formatted_number, date))
+            .vendor(self.vendor)
+            .client(self.client)
+            .payment_info(self.payment_info)
+            .output_path(output_path)
+            .build();
+        // Credited amounts are reported at the rate of the credited invoice.
+        let reporting_totals = self
+            .reporting_totals
+            .map(|reporting| reporting.for_totals(&credit_note.totals));
+        Ok(Self {
+            reporting_totals,
+            ..credit_note
         })
     }
 }
@@ -179,4 +270,38 @@ mod tests {
         let data = DataFromDiskWithItemsOfKind::<LineItemsPricedInSourceCurrency>::sample();
         assert_eq!(data.output_path, OutputPath::Name("invoice.pdf".into()));
     }
+
+    #[test]
+    fn credit_note_reports_credited_amounts_at_rate_of_invoice() {
+        let rate = UnitPrice::from(rust_decimal::dec!(11.05));
+        let invoice = PreparedData::sample().with_reporting_totals(
+            Currency::SEK,
+            rate,
+            Date::sample(),
+            "European Central Bank",
+        );
+        let credit_note = invoice
+            .credit_note(
+                InvoiceNumber::from(2),
+                "2".to_owned(),
+                Date::sample_other(),
+                &[],
+                OutputPath::Name("credit_note.pdf".into()),
+            )
+            .unwrap();
+        let reporting = credit_note.reporting_totals().clone().unwrap();
+        assert_eq!(*reporting.rate(), rate);
+        assert_eq!(*reporting.rate_date(), Date::sample());
+        assert!(reporting.grand_total().is_sign_negative());
+        assert_eq!(
+            reporting,
+            ReportingTotals::new(
+                credit_note.totals(),
+                Currency::SEK,
+                rate,
+                Date::sample(),
+                "European Central Bank"
+            )
+        );
+    }
 }
@@ -0,0 +1,127 @@
+use crate::{Error, HasSample, Result};
+use derive_more::Deref;
+use derive_more::Display;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Identifier of a client in the client registry, e.g. `"holmes"`.
+///
+/// Used as file name of the client in the data directory, i.e.
+/// `clients/<id>.ron`, so it is restricted to lowercase ASCII letters,
+/// digits, `-` and `_`.
+#[derive(
+    Clone, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, Deref,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct ClientId(String);
+
+impl std::str::FromStr for ClientId {
+    type Err = crate::Error;
+
+    /// Parses a string into a `ClientId`.
+    ///
+    /// # Errors
+    /// Returns an `Error::InvalidClientId` if the string is empty or contains
+    /// characters other than lowercase ASCII letters, digits, `-` and `_`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    ///
+    /// let client_id = ClientId::from_str("holmes").unwrap();
+    /// assert_eq!(client_id.to_string(), "holmes");
+    ///
+    /// assert!(ClientId::from_str("Holmes Ltd").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        if s.is_empty() {
+            return Err(Error::InvalidClientId {
+                invalid_string: s.to_owned(),
+                reason: "must not be empty".to_owned(),
+            });
+        }
+        if let Some(bad) = s
+            .chars()
+            .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-' || *c == '_'))
+        {
+            return Err(Error::InvalidClientId {
+                invalid_string: s.to_owned(),
+                reason: format!(
+                    "contains '{bad}', only lowercase letters, digits, '-' and '_' are allowed"
+                ),
+            });
+        }
+        Ok(Self(s.to_owned()))
+    }
+}
+
+impl TryFrom<String> for ClientId {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl From<ClientId> for String {
+    fn from(value: ClientId) -> Self {
+        value.0
+    }
+}
+
+impl HasSample for ClientId {
+    fn sample() -> Self {
+        "holmes".parse().expect("valid client id")
+    }
+
+    fn sample_other() -> Self {
+        "moriarty".parse().expect("valid client id")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    type Sut = ClientId;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn from_str_accepts_lowercase_digits_dash_and_underscore() {
+        let sut = Sut::from_str("acme-2_eu").unwrap();
+        assert_eq!(*sut, "acme-2_eu");
+    }
+
+    #[test]
+    fn from_str_rejects_empty() {
+        assert!(matches!(
+            Sut::from_str(""),
+            Err(Error::InvalidClientId { .. })
+        ));
+    }
+
+    #[test]
+    fn from_str_rejects_path_separators_and_uppercase() {
+        assert!(Sut::from_str("../etc").is_err());
+        assert!(Sut::from_str("Acme").is_err());
+        assert!(Sut::from_str("acme ltd").is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_invalid() {
+        let result = ron::from_str::<Sut>("\"Not Valid\"");
+        assert!(result.is_err());
+    }
+}
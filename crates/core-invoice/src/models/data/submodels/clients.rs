@@ -0,0 +1,73 @@
+use crate::{ClientId, CompanyInformation, HasSample};
+use derive_more::Deref;
+use derive_more::From;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Registry of additional clients, keyed by [`ClientId`].
+///
+/// Each client is persisted as its own file, `clients/<id>.ron`, in the data
+/// directory. The client in `client.ron` remains the default client, used when
+/// no client is specified.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Deref, From)]
+pub struct Clients(IndexMap<ClientId, CompanyInformation>);
+
+impl Clients {
+    /// Creates a new `Clients` registry from an iterator of id and client pairs.
+    pub fn new(clients: impl IntoIterator<Item = (ClientId, CompanyInformation)>) -> Self {
+        Self(IndexMap::from_iter(clients))
+    }
+
+    /// Inserts or replaces the client with `id`.
+    pub fn insert(&mut self, id: ClientId, client: CompanyInformation) {
+        self.0.insert(id, client);
+    }
+
+    /// Removes the client with `id`, returning it if it was registered.
+    pub fn remove(&mut self, id: &ClientId) -> Option<CompanyInformation> {
+        self.0.shift_remove(id)
+    }
+}
+
+impl HasSample for Clients {
+    fn sample() -> Self {
+        Self::new([(ClientId::sample(), CompanyInformation::sample_client())])
+    }
+
+    fn sample_other() -> Self {
+        Self::new([(
+            ClientId::sample_other(),
+            CompanyInformation::sample_vendor(),
+        )])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Sut = Clients;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut sut = Sut::default();
+        sut.insert(ClientId::sample(), CompanyInformation::sample_client());
+        assert!(sut.contains_key(&ClientId::sample()));
+        assert_eq!(
+            sut.remove(&ClientId::sample()),
+            Some(CompanyInformation::sample_client())
+        );
+        assert!(sut.is_empty());
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{ClientId, Date, HasSample, InvoiceNumber, InvoicedItems};
+use bon::bon;
+use derive_more::Deref;
+use getset::Getters;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// A credit note issued for an earlier invoice.
+///
+/// Recorded in `credit_notes.ron` so that recreating the credit note for the
+/// same invoice and lines yields the same credit note number and date.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters)]
+pub struct IssuedCreditNote {
+    /// Number of the credited invoice, e.g. `90`.
+    #[getset(get = "pub")]
+    invoice: InvoiceNumber,
+
+    /// The items of the credited invoice, used to rebuild it.
+    #[getset(get = "pub")]
+    items: InvoicedItems,
+
+    /// Client of the credited invoice, `None` for the default client.
+    #[getset(get = "pub")]
+    #[serde(default)]
+    client: Option<ClientId>,
+
+    /// The credited 1-based lines of the invoice, sorted, empty if the whole
+    /// invoice is credited.
+    #[getset(get = "pub")]
+    #[serde(default)]
+    lines: Vec<usize>,
+
+    /// When the credit note was issued.
+    #[getset(get = "pub")]
+    date: Date,
+}
+
+#[bon]
+impl IssuedCreditNote {
+    #[builder]
+    pub fn new(
+        #[builder(into)] invoice: InvoiceNumber,
+        items: InvoicedItems,
+        client: Option<ClientId>,
+        #[builder(default)] mut lines: Vec<usize>,
+        date: Date,
+    ) -> Self {
+        lines.sort_unstable();
+        lines.dedup();
+        Self {
+            invoice,
+            items,
+            client,
+            lines,
+            date,
+        }
+    }
+
+    /// Whether `self` and `other` credit the same lines of the same invoice,
+    /// regardless of when they were issued.
+    fn credits_same_as(&self, other: &Self) -> bool {
+        self.invoice == other.invoice
+            && self.items == other.items
+            && self.client == other.client
+            && self.lines == other.lines
+    }
+}
+
+impl HasSample for IssuedCreditNote {
+    fn sample() -> Self {
+        Self::builder()
+            .invoice(22)
+            .items(InvoicedItems::Service { time_off: None })
+            .date(Date::sample())
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .invoice(23)
+            .items(InvoicedItems::Expenses)
+            .client(ClientId::sample())
+            .lines(vec![2, 1])
+            .date(Date::sample_other())
+            .build()
+    }
+}
+
+/// Issued credit notes keyed by credit note number, persisted in
+/// `credit_notes.ron`.
+///
+/// Credit notes are numbered in a series of their own, starting at `1`, so
+/// that they never interfere with the invoice numbers derived from periods.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Deref)]
+pub struct CreditNotes(IndexMap<InvoiceNumber, IssuedCreditNote>);
+
+impl CreditNotes {
+    /// Creates a new `CreditNotes` registry from number and credit note pairs.
+    pub fn new(credit_notes: impl IntoIterator<Item = (InvoiceNumber, IssuedCreditNote)>) -> Self {
+        Self(IndexMap::from_iter(credit_notes))
+    }
+
+    /// Returns the number and credit note to issue for `credit_note`, which is
+    /// the earlier issued credit note if one credits the same lines of the
+    /// same invoice, or else `credit_note` with the next number of the series.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    ///
+    /// let mut credit_notes = CreditNotes::default();
+    /// let (number, issued) = credit_notes.issue(IssuedCreditNote::sample());
+    /// assert_eq!(number, InvoiceNumber::from(1));
+    ///
+    /// credit_notes.insert(number.clone(), issued.clone());
+    /// assert_eq!(credit_notes.issue(issued), (number, IssuedCreditNote::sample()));
+    /// ```
+    pub fn issue(&self, credit_note: IssuedCreditNote) -> (InvoiceNumber, IssuedCreditNote) {
+        if let Some((number, issued)) = self
+            .0
+            .iter()
+            .find(|(_, issued)| issued.credits_same_as(&credit_note))
+        {
+            return (number.clone(), issued.clone());
+        }
+        let next = self.0.keys().map(|number| **number).max().unwrap_or(0) + 1;
+        (InvoiceNumber::from(next), credit_note)
+    }
+
+    /// Returns the sequential number of credit note `number`, issued at
+    /// `date`, within its series. This is `number` itself unless the series
+    /// restarts each year, see [`crate::NumberReset::Yearly`].
+    pub fn sequential_number(
+        &self,
+        number: &InvoiceNumber,
+        date: &Date,
+        resets_yearly: bool,
+    ) -> u16 {
+        if !resets_yearly {
+            return **number;
+        }
+        let earlier_same_year = self
+            .0
+            .iter()
+            .filter(|(other, issued)| ***other < **number && issued.date().year() == date.year())
+            .count();
+        earlier_same_year as u16 + 1
+    }
+
+    /// Records `credit_note` as issued with `number`, keeping any credit note
+    /// already recorded with that number.
+    pub fn insert(&mut self, number: InvoiceNumber, credit_note: IssuedCreditNote) {
+        self.0.entry(number).or_insert(credit_note);
+    }
+}
+
+impl HasSample for CreditNotes {
+    fn sample() -> Self {
+        Self::new([(InvoiceNumber::from(1), IssuedCreditNote::sample())])
+    }
+
+    fn sample_other() -> Self {
+        Self::new([(InvoiceNumber::from(2), IssuedCreditNote::sample_other())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = CreditNotes;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn issued_credit_note_lines_are_sorted_and_deduplicated() {
+        let sut = IssuedCreditNote::builder()
+            .invoice(1)
+            .items(InvoicedItems::Expenses)
+            .lines(vec![3, 1, 3])
+            .date(Date::sample())
+            .build();
+        assert_eq!(sut.lines(), &vec![1, 3]);
+    }
+
+    #[test]
+    fn issue_continues_series() {
+        let sut = Sut::sample();
+        let (number, issued) = sut.issue(IssuedCreditNote::sample_other());
+        assert_eq!(number, InvoiceNumber::from(2));
+        assert_eq!(issued, IssuedCreditNote::sample_other());
+    }
+
+    #[test]
+    fn issue_reuses_number_and_date_of_same_credit_note() {
+        let sut = Sut::sample();
+        let reissued = IssuedCreditNote::builder()
+            .invoice(22)
+            .items(InvoicedItems::Service { time_off: None })
+            .date(Date::sample_other())
+            .build();
+        let (number, issued) = sut.issue(reissued);
+        assert_eq!(number, InvoiceNumber::from(1));
+        assert_eq!(*issued.date(), Date::sample());
+    }
+
+    #[test]
+    fn sequential_number_restarts_yearly() {
+        let sut = Sut::new([
+            (InvoiceNumber::from(1), IssuedCreditNote::sample()),
+            (InvoiceNumber::from(2), IssuedCreditNote::sample_other()),
+        ]);
+        let next_year = Date::from_ymd(**Date::sample().year() + 1, 1u32, 15u32).unwrap();
+        assert_eq!(
+            sut.sequential_number(&InvoiceNumber::from(3), &next_year, false),
+            3
+        );
+        assert_eq!(
+            sut.sequential_number(&InvoiceNumber::from(3), &next_year, true),
+            1
+        );
+        // Only credit note 1 was issued in the same year as `Date::sample`.
+        assert_eq!(
+            sut.sequential_number(&InvoiceNumber::from(3), &Date::sample(), true),
+            2
+        );
+    }
+
+    #[test]
+    fn issue_other_lines_gets_new_number() {
+        let sut = Sut::sample();
+        let partial = IssuedCreditNote::builder()
+            .invoice(22)
+            .items(InvoicedItems::Service { time_off: None })
+            .lines(vec![1])
+            .date(Date::sample())
+            .build();
+        assert_eq!(sut.issue(partial).0, InvoiceNumber::from(2));
+    }
+}
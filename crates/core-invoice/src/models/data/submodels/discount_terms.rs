@@ -0,0 +1,150 @@
+use crate::{Date, Decimal, DueDays, EarlyPaymentDiscount, Error, FromStr, HasSample, NetDays};
+use bon::Builder;
+use derive_more::Display;
+use getset::Getters;
+use rust_decimal::dec;
+use serde_with::DeserializeFromStr;
+use serde_with::SerializeDisplay;
+
+/// Payment terms granting a discount if paid early, e.g. `2/10 Net 30` for
+/// a 2% discount if paid within 10 days, else net payment due in 30 days.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    SerializeDisplay,
+    DeserializeFromStr,
+    Builder,
+    Getters,
+    Display,
+)]
+#[display("{}/{} {}", percent, within, net)]
+pub struct DiscountTerms {
+    /// The discount in percent of the grand total, e.g. `2` for 2%, greater
+    /// than 0 and less than 100.
+    #[getset(get = "pub")]
+    percent: Decimal,
+
+    /// The number of days the discount is granted within, at most the days
+    /// of `net`.
+    #[getset(get = "pub")]
+    within: DueDays,
+
+    /// When payment of the full amount is due.
+    #[getset(get = "pub")]
+    net: NetDays,
+}
+
+impl DiscountTerms {
+    /// The discount granted for an invoice issued at `invoice_date`.
+    pub fn early_payment_discount(&self, invoice_date: Date) -> EarlyPaymentDiscount {
+        EarlyPaymentDiscount::builder()
+            .percent(self.percent)
+            .days(*self.within)
+            .deadline(invoice_date.advance_days(&self.within))
+            .build()
+    }
+}
+
+impl FromStr for DiscountTerms {
+    type Err = crate::Error;
+
+    /// Tries to parse a string in the format "{percent}/{days} Net {days}",
+    /// e.g. "2/10 Net 30", ignoring case.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not in the correct format, if the
+    /// percent is not within `(0, 100)` or if the discount is granted for
+    /// longer than the net terms.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// let terms: DiscountTerms = "2/10 net 30".parse().unwrap();
+    /// assert_eq!(terms.within(), &DueDays::try_from(10u16).unwrap());
+    /// assert_eq!(terms.to_string(), "2/10 Net 30");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::FailedToParsePaymentTerms {
+            invalid_string: s.to_owned(),
+        };
+        let (discount, net) = s.trim().split_once(' ').ok_or_else(invalid)?;
+        let (percent, within) = discount.split_once('/').ok_or_else(invalid)?;
+        let percent = Decimal::from_str(percent).map_err(|_| invalid())?;
+        let within = DueDays::from_str(within).map_err(|_| invalid())?;
+        let net = NetDays::from_str(net)?;
+        if *percent <= dec!(0) || *percent >= dec!(100) || within > *net.due_in() {
+            return Err(invalid());
+        }
+        Ok(Self::builder()
+            .percent(percent)
+            .within(within)
+            .net(net)
+            .build())
+    }
+}
+
+impl HasSample for DiscountTerms {
+    fn sample() -> Self {
+        Self::from_str("2/10 Net 30").expect("valid sample discount terms")
+    }
+
+    fn sample_other() -> Self {
+        Self::from_str("1.5/14 Net 45").expect("valid sample discount terms")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = DiscountTerms;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        for sut in [Sut::sample(), Sut::sample_other()] {
+            assert_eq!(sut.to_string().parse::<Sut>().unwrap(), sut);
+        }
+        assert_eq!(Sut::sample_other().to_string(), "1.5/14 Net 45");
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for invalid in [
+            "2/10",
+            "2 Net 30",
+            "0/10 Net 30",
+            "100/10 Net 30",
+            "2/0 Net 30",
+            "2/31 Net 30",
+            "x/10 Net 30",
+            "2/10 Net",
+        ] {
+            assert!(invalid.parse::<Sut>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn early_payment_discount_deadline() {
+        let discount = Sut::sample().early_payment_discount("2025-05-31".parse().unwrap());
+        assert_eq!(discount.deadline().to_string(), "2025-06-10");
+        assert_eq!(**discount.percent(), dec!(2));
+        assert_eq!(*discount.days(), 10);
+    }
+}
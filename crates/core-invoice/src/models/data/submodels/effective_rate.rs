@@ -0,0 +1,58 @@
+use crate::{Date, HasSample, Rate};
+use bon::Builder;
+use getset::Getters;
+use rust_decimal::dec;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A renegotiated rate of the primary service, billed for periods starting on
+/// or after `effective_from`, see [`crate::ServiceFees::rate_history`].
+///
+/// **The `rate` is VAT-exclusive**, see [`crate::ServiceFees`].
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Getters, Builder)]
+pub struct EffectiveRate {
+    /// The first date the rate applies to, e.g. `2025-07-01`, must be the
+    /// first date of a period.
+    #[getset(get = "pub")]
+    effective_from: Date,
+
+    /// The rate, **excluding VAT**.
+    #[getset(get = "pub")]
+    #[builder(into)]
+    rate: Rate,
+}
+
+impl HasSample for EffectiveRate {
+    fn sample() -> Self {
+        Self::builder()
+            .effective_from("2025-07-01".parse().expect("valid date"))
+            .rate(Rate::daily(dec!(850.0)))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .effective_from("2026-01-01".parse().expect("valid date"))
+            .rate(Rate::daily(dec!(900.0)))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = EffectiveRate;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+}
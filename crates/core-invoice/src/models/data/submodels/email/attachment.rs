@@ -1,8 +1,16 @@
-use crate::NamedPdf;
+use crate::{NamedPdf, ReceiptFile};
 use derive_more::From;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, From)]
 pub enum Attachment {
-    /// A PDF attachment.
-    Pdf(NamedPdf),
+    /// A PDF attachment, boxed since it holds the prepared data of the PDF.
+    Pdf(Box<NamedPdf>),
+    /// A receipt of an expense on the attached invoice, a PDF or an image.
+    Receipt(ReceiptFile),
+}
+
+impl From<NamedPdf> for Attachment {
+    fn from(pdf: NamedPdf) -> Self {
+        Self::Pdf(Box::new(pdf))
+    }
 }
@@ -44,6 +44,12 @@ impl Email {
     pub fn body(&self) -> String {
         self.body.clone().unwrap_or_default()
     }
+
+    /// Returns the email with `attachments` added after its current attachments.
+    pub fn with_attachments(mut self, attachments: impl IntoIterator<Item = Attachment>) -> Self {
+        self.attachments.extend(attachments);
+        self
+    }
 }
 
 impl From<(DecryptedEmailSettings, NamedPdf)> for Email {
@@ -56,7 +62,7 @@ impl From<(DecryptedEmailSettings, NamedPdf)> for Email {
             .public_recipients(settings.recipients().clone())
             .cc_recipients(settings.cc_recipients().clone())
             .bcc_recipients(settings.bcc_recipients().clone())
-            .attachments(IndexSet::from([Attachment::Pdf(pdf)]))
+            .attachments(IndexSet::from([Attachment::from(pdf)]))
             .build()
     }
 }
@@ -69,7 +75,7 @@ impl HasSample for Email {
             .bcc_recipients(IndexSet::from_iter(vec![EmailAddress::sample_erin()]))
             .subject("Sample Email Subject".to_string())
             .body("This is a sample email body.".to_string())
-            .attachments(IndexSet::from_iter(vec![Attachment::Pdf(
+            .attachments(IndexSet::from_iter(vec![Attachment::from(
                 NamedPdf::sample(),
             )]))
             .build()
@@ -81,7 +87,7 @@ impl HasSample for Email {
             .cc_recipients(IndexSet::from_iter(vec![EmailAddress::sample_dave()]))
             .subject("Another Sample Email Subject".to_string())
             .body("This is another sample email body.".to_string())
-            .attachments(IndexSet::from_iter(vec![Attachment::Pdf(
+            .attachments(IndexSet::from_iter(vec![Attachment::from(
                 NamedPdf::sample_other(),
             )]))
             .build()
@@ -36,6 +36,12 @@ impl From<Attachment> for SinglePart {
                 lettre::message::Attachment::new(named_pdf.name().clone())
                     .body(named_pdf.pdf().as_ref().clone(), ContentType::pdf())
             }
+            Attachment::Receipt(receipt_file) => {
+                let content_type = ContentType::parse(receipt_file.mime_type())
+                    .expect("Receipt MIME types are valid content types");
+                lettre::message::Attachment::new(receipt_file.receipt().file_name().to_owned())
+                    .body(receipt_file.content().clone(), content_type)
+            }
         }
     }
 }
@@ -110,7 +116,7 @@ impl From<EmailCredentials> for Credentials {
 mod tests {
     use super::*;
     use crate::HasSample;
-    use crate::{NamedPdf, PathBuf, PreparedData};
+    use crate::{NamedPdf, PathBuf, PreparedData, Receipt, ReceiptFile};
     use base64::{Engine, prelude::BASE64_STANDARD};
     use indexmap::IndexSet;
     use klirr_foundation::Pdf;
@@ -131,6 +137,21 @@ mod tests {
         assert_eq!(hex::encode(single_part.formatted()), "436f6e74656e742d446973706f736974696f6e3a206174746163686d656e743b2066696c656e616d653d22746573742e706466220d0a436f6e74656e742d547970653a206170706c69636174696f6e2f7064660d0a436f6e74656e742d5472616e736665722d456e636f64696e673a206261736536340d0a0d0a3371322b37773d3d0d0a".to_owned());
     }
 
+    #[test]
+    fn test_singlepart_from_receipt_attachment() {
+        let receipt_file = ReceiptFile::builder()
+            .receipt(Receipt::new("receipts/2025-05-31/hotel.png").unwrap())
+            .mime_type("image/png")
+            .content(vec![0xde, 0xad, 0xbe, 0xef])
+            .build();
+        let single_part: SinglePart = Attachment::from(receipt_file).into();
+        let formatted = String::from_utf8(single_part.formatted()).unwrap();
+
+        assert!(formatted.contains("filename=\"hotel.png\""));
+        assert!(formatted.contains("Content-Type: image/png"));
+        assert!(formatted.contains(&BASE64_STANDARD.encode([0xde, 0xad, 0xbe, 0xef])));
+    }
+
     #[test]
     fn test_content_type_pdf() {
         let content_type = ContentType::pdf();
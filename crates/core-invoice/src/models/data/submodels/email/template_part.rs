@@ -26,15 +26,18 @@ impl TemplatePart {
     const INVOICE_DATE: &str = "<INV_DATE>";
     /// The invoice's purchase-order string, or empty when none was set.
     const PURCHASE_ORDER: &str = "<PO>";
+    /// The grand total of the invoice including VAT, with currency.
+    const GRAND_TOTAL: &str = "<TOTAL>";
 
     pub fn tutorial() -> String {
         format!(
-            "Placeholders: '{}', '{}', '{}', '{}', '{}'. Example: 'Invoice {} from {}' → 'Invoice 42 from Lupin et Associés'. '{}' expands to the purchase order or an empty string when none is set. Placeholders are case-sensitive and must include '<' and '>'.",
+            "Placeholders: '{}', '{}', '{}', '{}', '{}', '{}'. Example: 'Invoice {} from {}' → 'Invoice 42 from Lupin et Associés'. '{}' expands to the purchase order or an empty string when none is set. Placeholders are case-sensitive and must include '<' and '>'.",
             Self::NUMBER,
             Self::VENDOR,
             Self::CLIENT,
             Self::INVOICE_DATE,
             Self::PURCHASE_ORDER,
+            Self::GRAND_TOTAL,
             Self::NUMBER,
             Self::VENDOR,
             Self::PURCHASE_ORDER
@@ -43,10 +46,7 @@ impl TemplatePart {
 
     pub fn materialize(&self, data: &PreparedData) -> String {
         let mut raw = self.0.clone();
-        raw = raw.replace(
-            Self::NUMBER,
-            data.information().number().to_string().as_str(),
-        );
+        raw = raw.replace(Self::NUMBER, data.information().formatted_number());
         raw = raw.replace(Self::VENDOR, data.vendor().company_name().as_str());
         raw = raw.replace(Self::CLIENT, data.client().company_name().as_str());
         raw = raw.replace(
@@ -60,6 +60,12 @@ impl TemplatePart {
             .map(|po| po.to_string())
             .unwrap_or_default();
         raw = raw.replace(Self::PURCHASE_ORDER, po.as_str());
+        let grand_total = format!(
+            "{:.2} {}",
+            **data.totals().grand_total(),
+            data.payment_info().currency()
+        );
+        raw = raw.replace(Self::GRAND_TOTAL, grand_total.as_str());
 
         #[cfg(debug_assertions)]
         {
@@ -120,8 +126,10 @@ mod tests {
         // Build a PreparedData with no purchase order set.
         let info = InvoiceInfoFull::builder()
             .number(InvoiceNumber::sample())
+            .formatted_number(InvoiceNumber::sample().to_string())
             .invoice_date(Date::sample())
             .due_date(Date::sample())
+            .terms(crate::InvoiceTerms::sample())
             .build();
         let prepared = PreparedData::builder()
             .information(info)
@@ -135,6 +143,46 @@ mod tests {
         assert_eq!(template.materialize(&prepared), "PO: ''");
     }
 
+    #[test]
+    fn test_number_placeholder_uses_formatted_number() {
+        use crate::{
+            CompanyInformation, InvoiceInfoFull, InvoiceNumber, LineItemsFlat, OutputPath,
+        };
+        use crate::{PaymentInformation, PreparedData};
+        use klirr_foundation::Date;
+        let info = InvoiceInfoFull::builder()
+            .number(InvoiceNumber::from(42))
+            .formatted_number("ACME-0042")
+            .invoice_date(Date::sample())
+            .due_date(Date::sample())
+            .terms(crate::InvoiceTerms::sample())
+            .build();
+        let prepared = PreparedData::builder()
+            .information(info)
+            .vendor(CompanyInformation::sample_vendor())
+            .client(CompanyInformation::sample_client())
+            .line_items(LineItemsFlat::sample())
+            .payment_info(PaymentInformation::sample())
+            .output_path(OutputPath::Name("invoice.pdf".into()))
+            .build();
+        let template = TemplatePart::from("Invoice <INV_NO>");
+        assert_eq!(template.materialize(&prepared), "Invoice ACME-0042");
+    }
+
+    #[test]
+    fn test_grand_total_placeholder() {
+        let data = PreparedData::sample();
+        let template = TemplatePart::from("Total: <TOTAL>");
+        assert_eq!(
+            template.materialize(&data),
+            format!(
+                "Total: {:.2} {}",
+                **data.totals().grand_total(),
+                data.payment_info().currency()
+            )
+        );
+    }
+
     #[test]
     fn test_rng() {
         let template = TemplatePart::from("<RNG>");
@@ -1,5 +1,5 @@
 use super::expenses_for_periods::ExpensesForPeriods;
-use crate::{Date, Error, HasSample, Item, Result};
+use crate::{Date, Error, HasSample, Item, Receipt, Result};
 use getset::Getters;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -87,6 +87,19 @@ impl ExpensedPeriods {
         self.expenses_for_periods.contains_key(period_end_date)
     }
 
+    /// Returns the expense recorded for `period_end_date` that is the same as
+    /// `item` except for its quantity, e.g. because it was already imported.
+    pub fn find_expense(&self, period_end_date: &Date, item: &Item) -> Option<&Item> {
+        self.expenses_for_periods
+            .get(period_end_date)
+            .and_then(|expenses| expenses.find_ignoring_quantity(item))
+    }
+
+    /// Returns the period-end dates for which expenses are recorded.
+    pub fn period_end_dates(&self) -> impl Iterator<Item = &Date> {
+        self.expenses_for_periods.keys()
+    }
+
     /// Returns all expenses for `target_period_end_date`.
     ///
     /// # Errors
@@ -165,6 +178,115 @@ impl ExpensedPeriods {
             }
         }
     }
+
+    /// Returns the expenses of `period_end_date`, checking that it has an
+    /// expense at the 1-based `index`, see [`Self::get`].
+    fn expenses_with_index(
+        &mut self,
+        period_end_date: &Date,
+        index: usize,
+    ) -> Result<&mut ExpensesForPeriods> {
+        let target_period = period_end_date.to_string();
+        let expenses = self
+            .expenses_for_periods
+            .get_mut(period_end_date)
+            .ok_or_else(|| Error::TargetPeriodMustHaveExpenses {
+                target_period: target_period.clone(),
+            })?;
+        if index == 0 || index > expenses.len() {
+            return Err(Error::ExpenseIndexOutOfRange {
+                target_period,
+                index,
+                count: expenses.len(),
+            });
+        }
+        Ok(expenses)
+    }
+
+    /// Removes and returns the expense at the 1-based `index` of the
+    /// expenses of `period_end_date`, as listed by [`Self::get`]. The period
+    /// is removed once it has no expenses left.
+    ///
+    /// # Errors
+    /// Returns [`Error::TargetPeriodMustHaveExpenses`] when no expenses are
+    /// recorded for the period, or [`Error::ExpenseIndexOutOfRange`] when
+    /// the period has no expense at `index`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use indexmap::IndexMap;
+    /// use klirr_core_invoice::*;
+    ///
+    /// let period_end = "2025-01-31".parse::<Date>().unwrap();
+    /// let mut expensed = ExpensedPeriods::new(IndexMap::from([(
+    ///     period_end,
+    ///     vec![Item::sample_expense_coffee()],
+    /// )]));
+    ///
+    /// let removed = expensed.remove_expense(&period_end, 1).unwrap();
+    /// assert_eq!(removed, Item::sample_expense_coffee());
+    /// assert!(!expensed.contains(&period_end));
+    /// ```
+    pub fn remove_expense(&mut self, period_end_date: &Date, index: usize) -> Result<Item> {
+        let expenses = self.expenses_with_index(period_end_date, index)?;
+        let removed = expenses.remove(index - 1);
+        if expenses.is_empty() {
+            self.expenses_for_periods.shift_remove(period_end_date);
+        }
+        Ok(removed)
+    }
+
+    /// Replaces the expense at the 1-based `index` of the expenses of
+    /// `period_end_date` with `item`, and returns the replaced expense.
+    ///
+    /// The item is merged with any other expense of the period that is the
+    /// same except for its quantity, see [`Self::insert_expenses`].
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Self::remove_expense`].
+    pub fn replace_expense(
+        &mut self,
+        period_end_date: &Date,
+        index: usize,
+        item: Item,
+    ) -> Result<Item> {
+        let expenses = self.expenses_with_index(period_end_date, index)?;
+        Ok(expenses.replace(index - 1, item))
+    }
+
+    /// Adds `receipts` to the expense at the 1-based `index` of the expenses
+    /// of `period_end_date`, skipping receipts it already has, and returns
+    /// the expense.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Self::remove_expense`].
+    pub fn attach_receipts(
+        &mut self,
+        period_end_date: &Date,
+        index: usize,
+        receipts: impl IntoIterator<Item = Receipt>,
+    ) -> Result<Item> {
+        let expenses = self.expenses_with_index(period_end_date, index)?;
+        Ok(expenses.add_receipts(index - 1, receipts))
+    }
+
+    /// Moves the expense at the 1-based `index` of the expenses of
+    /// `period_end_date` to the expenses of `to_period_end_date`, and
+    /// returns the moved expense.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`Self::remove_expense`].
+    pub fn move_expense(
+        &mut self,
+        period_end_date: &Date,
+        index: usize,
+        to_period_end_date: &Date,
+    ) -> Result<Item> {
+        let moved = self.remove_expense(period_end_date, index)?;
+        self.insert_expenses(to_period_end_date, vec![moved.clone()]);
+        Ok(moved)
+    }
 }
 
 #[cfg(test)]
@@ -220,4 +342,97 @@ mod tests {
         assert_eq!(retrieved_items.len(), 1);
         assert_eq!(*retrieved_items[0].quantity(), Quantity::from(dec!(7.0))); // 3.0 + 4.0
     }
+
+    fn two_periods() -> (Sut, Date, Date) {
+        let may = Date::from_str("2025-05-31").unwrap();
+        let june = Date::from_str("2025-06-30").unwrap();
+        let sut = Sut::new(IndexMap::from_iter([
+            (
+                may,
+                vec![
+                    Item::sample_expense_coffee(),
+                    Item::sample_expense_breakfast(),
+                ],
+            ),
+            (
+                june,
+                vec![Item::from_str("Sandwich,6,EUR,1,2025-06-02").unwrap()],
+            ),
+        ]));
+        (sut, may, june)
+    }
+
+    #[test]
+    fn remove_expense_keeps_other_expenses_of_period() {
+        let (mut sut, may, _) = two_periods();
+        let removed = sut.remove_expense(&may, 1).unwrap();
+        assert_eq!(removed, Item::sample_expense_coffee());
+        assert_eq!(
+            sut.get(&may).unwrap(),
+            vec![Item::sample_expense_breakfast()]
+        );
+    }
+
+    #[test]
+    fn remove_expense_fails_for_index_out_of_range() {
+        let (mut sut, may, _) = two_periods();
+        for index in [0, 3] {
+            assert_eq!(
+                sut.remove_expense(&may, index),
+                Err(Error::ExpenseIndexOutOfRange {
+                    target_period: "2025-05-31".to_owned(),
+                    index,
+                    count: 2,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn replace_expense_merges_with_same_expense() {
+        let (mut sut, may, _) = two_periods();
+        let replaced = sut
+            .replace_expense(&may, 2, Item::sample_expense_coffee())
+            .unwrap();
+        assert_eq!(replaced, Item::sample_expense_breakfast());
+        let expenses = sut.get(&may).unwrap();
+        assert_eq!(expenses.len(), 1);
+        assert_eq!(
+            *expenses[0].quantity(),
+            *Item::sample_expense_coffee().quantity() + *Item::sample_expense_coffee().quantity()
+        );
+    }
+
+    #[test]
+    fn move_expense_to_other_period() {
+        let (mut sut, may, june) = two_periods();
+        let moved = sut.move_expense(&june, 1, &may).unwrap();
+        assert_eq!(moved.name(), "Sandwich");
+        assert!(!sut.contains(&june));
+        assert_eq!(sut.get(&may).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn attach_receipts_skips_receipts_already_attached() {
+        let (mut sut, may, _) = two_periods();
+        let hotel = Receipt::new("receipts/2025-05-31/hotel.pdf").unwrap();
+        let taxi = Receipt::new("receipts/2025-05-31/taxi.png").unwrap();
+        sut.attach_receipts(&may, 2, [hotel.clone()]).unwrap();
+        let expense = sut
+            .attach_receipts(&may, 2, [hotel.clone(), taxi.clone()])
+            .unwrap();
+        assert_eq!(*expense.receipts(), vec![hotel, taxi]);
+        assert_eq!(sut.get(&may).unwrap()[1], expense);
+    }
+
+    #[test]
+    fn merged_expenses_keep_receipts_of_both() {
+        let (mut sut, may, _) = two_periods();
+        let hotel = Receipt::new("receipts/2025-05-31/hotel.pdf").unwrap();
+        sut.attach_receipts(&may, 1, [hotel.clone()]).unwrap();
+        sut.insert_expenses(&may, vec![Item::sample_expense_coffee()]);
+        let expenses = sut.get(&may).unwrap();
+        assert_eq!(expenses.len(), 2);
+        assert_eq!(*expenses[0].receipts(), vec![hotel]);
+    }
 }
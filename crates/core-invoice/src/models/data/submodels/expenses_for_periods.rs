@@ -1,4 +1,7 @@
-use crate::{Currency, Date, Item, Quantity, UnitPrice, define_item_struct};
+use crate::{
+    Currency, Date, Decimal, ExpenseKind, Item, Quantity, Receipt, UnitPrice, Vat,
+    define_item_struct,
+};
 use bon::Builder;
 use derive_more::Display;
 use getset::Getters;
@@ -15,40 +18,60 @@ pub(super) struct ExpensesForPeriods(Vec<Item>);
 
 /// Ephemeral type used such that expensed items sharing all fields except for quantity
 /// are considered the same, allowing us to merge them into a single item with the total quantity.
+/// The receipts are not part of the identifier either, the receipts of merged items are kept.
 #[derive(Hash, Eq, PartialEq, Display, Clone, Debug, PartialOrd, Ord, Serialize, Deserialize)]
 struct QuantityIgnored;
 define_item_struct!(pub, ExpenseIdentifier, QuantityIgnored);
 
+impl From<&Item> for ExpenseIdentifier {
+    fn from(item: &Item) -> Self {
+        Self::builder()
+            .name(item.name().clone())
+            .transaction_date(*item.transaction_date())
+            .unit_price(*item.unit_price())
+            .currency(*item.currency())
+            .quantity(QuantityIgnored)
+            .maybe_vat(*item.vat())
+            .maybe_kind(item.kind().clone())
+            .maybe_markup(*item.markup())
+            .billable(*item.billable())
+            .build()
+    }
+}
+
 impl ExpensesForPeriods {
     /// Inserts a vector of items into the `ExpensesForPeriods`, merging items that are the same
     /// except for their quantity.
     pub(super) fn insert(&mut self, items: Vec<Item>) {
         self.0.extend(items);
 
-        let mut map = IndexMap::<ExpenseIdentifier, Quantity>::new();
+        let mut map = IndexMap::<ExpenseIdentifier, (Quantity, Vec<Receipt>)>::new();
         for item in &self.0 {
-            let identifier = ExpenseIdentifier::builder()
-                .name(item.name().clone())
-                .transaction_date(*item.transaction_date())
-                .unit_price(*item.unit_price())
-                .currency(*item.currency())
-                .quantity(QuantityIgnored)
-                .build();
-
-            map.entry(identifier)
-                .and_modify(|q| *q += *item.quantity())
-                .or_insert(*item.quantity());
+            let (quantity, receipts) = map
+                .entry(ExpenseIdentifier::from(item))
+                .or_insert((Quantity::ZERO, Vec::new()));
+            *quantity += *item.quantity();
+            for receipt in item.receipts() {
+                if !receipts.contains(receipt) {
+                    receipts.push(receipt.clone());
+                }
+            }
         }
 
         self.0.clear();
 
-        for (identifier, quantity) in map {
+        for (identifier, (quantity, receipts)) in map {
             let item = Item::builder()
                 .name(identifier.name().clone())
                 .transaction_date(*identifier.transaction_date())
                 .unit_price(*identifier.unit_price())
                 .currency(*identifier.currency())
                 .quantity(quantity)
+                .maybe_vat(*identifier.vat())
+                .maybe_kind(identifier.kind().clone())
+                .receipts(receipts)
+                .maybe_markup(*identifier.markup())
+                .billable(*identifier.billable())
                 .build();
             self.0.push(item);
         }
@@ -66,4 +89,55 @@ impl ExpensesForPeriods {
     pub(super) fn items(&self) -> Vec<Item> {
         self.0.clone()
     }
+
+    /// Returns the number of items in this period.
+    pub(super) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if this period has no items.
+    pub(super) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Removes and returns the item at the 0-based `index`.
+    pub(super) fn remove(&mut self, index: usize) -> Item {
+        self.0.remove(index)
+    }
+
+    /// Replaces the item at the 0-based `index` with `item`, merging it with
+    /// any other item that is the same except for its quantity, and returns
+    /// the replaced item.
+    pub(super) fn replace(&mut self, index: usize, item: Item) -> Item {
+        let replaced = std::mem::replace(&mut self.0[index], item);
+        self.insert(Vec::new());
+        replaced
+    }
+
+    /// Adds `receipts` to the item at the 0-based `index`, skipping those it
+    /// already has, and returns the item.
+    pub(super) fn add_receipts(
+        &mut self,
+        index: usize,
+        receipts: impl IntoIterator<Item = Receipt>,
+    ) -> Item {
+        let item = &mut self.0[index];
+        let mut all = item.receipts().clone();
+        for receipt in receipts {
+            if !all.contains(&receipt) {
+                all.push(receipt);
+            }
+        }
+        item.set_receipts(all);
+        item.clone()
+    }
+
+    /// Returns the item of this period that is the same as `item` except for
+    /// its quantity, i.e. the item `item` would be merged into, if any.
+    pub(super) fn find_ignoring_quantity(&self, item: &Item) -> Option<&Item> {
+        let identifier = ExpenseIdentifier::from(item);
+        self.0
+            .iter()
+            .find(|existing| ExpenseIdentifier::from(*existing) == identifier)
+    }
 }
@@ -0,0 +1,216 @@
+use crate::{HasSample, Year};
+use bon::Builder;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// When the sequential part of a formatted invoice number restarts at `1`.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum NumberReset {
+    /// The sequence never restarts, the default.
+    #[default]
+    Never,
+
+    /// The sequence restarts at `1` with the first invoice of each year.
+    Yearly,
+}
+
+/// Template used to format the invoice numbers of a series, e.g.
+/// `ACME-0042` or `2025-017`.
+///
+/// The formatted number is `{prefix}{year}-{number}` where `{year}` (and the
+/// dash following it) is only present if `year` is `true` and `{number}` is
+/// zero padded to `padding` digits. The default format renders the bare
+/// number, e.g. `42`.
+///
+/// The `prefix` is used in output file names, so it should not contain path
+/// separators.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters)]
+pub struct InvoiceNumberFormat {
+    /// Text printed before the number, e.g. `"ACME-"`.
+    #[builder(into, default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    prefix: String,
+
+    /// Whether the year of the invoice date is included, e.g. `2025-017`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    year: bool,
+
+    /// Minimum number of digits of the number, padded with leading zeros.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    padding: u8,
+
+    /// When the number restarts at `1`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    reset: NumberReset,
+}
+
+impl InvoiceNumberFormat {
+    /// Formats `number`, the sequential number within the series, of an
+    /// invoice dated in `year`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    ///
+    /// let format = InvoiceNumberFormat::builder()
+    ///     .prefix("ACME-")
+    ///     .padding(4)
+    ///     .build();
+    /// assert_eq!(format.format(42, Year::from(2025)), "ACME-0042");
+    ///
+    /// let format = InvoiceNumberFormat::builder()
+    ///     .year(true)
+    ///     .padding(3)
+    ///     .reset(NumberReset::Yearly)
+    ///     .build();
+    /// assert_eq!(format.format(17, Year::from(2025)), "2025-017");
+    /// ```
+    pub fn format(&self, number: u16, year: Year) -> String {
+        let year = if self.year {
+            format!("{}-", *year)
+        } else {
+            String::new()
+        };
+        format!(
+            "{}{}{:0padding$}",
+            self.prefix,
+            year,
+            number,
+            padding = self.padding as usize
+        )
+    }
+
+    /// Whether the number restarts at `1` each year.
+    pub fn resets_yearly(&self) -> bool {
+        self.reset == NumberReset::Yearly
+    }
+}
+
+impl HasSample for InvoiceNumberFormat {
+    fn sample() -> Self {
+        Self::default()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .prefix("ACME-")
+            .year(true)
+            .padding(3)
+            .reset(NumberReset::Yearly)
+            .build()
+    }
+}
+
+/// Number formats of the invoice series, see [`InvoiceNumberFormat`].
+///
+/// Services invoices are numbered from the invoice number offset, one number
+/// per period. Expense invoices share the services series unless `expenses`
+/// is set, in which case they are numbered by the recorded expense periods.
+/// Credit notes always have a series of their own, see [`crate::CreditNotes`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Builder, Getters)]
+pub struct InvoiceNumberSeries {
+    /// Format of services invoices, and of expense invoices if `expenses` is
+    /// `None`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    services: InvoiceNumberFormat,
+
+    /// Format of a separate expense invoice series, `None` to number expense
+    /// invoices in the services series.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    expenses: Option<InvoiceNumberFormat>,
+
+    /// Format of credit notes.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    credit_notes: InvoiceNumberFormat,
+}
+
+impl HasSample for InvoiceNumberSeries {
+    fn sample() -> Self {
+        Self::default()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .services(InvoiceNumberFormat::sample_other())
+            .expenses(
+                InvoiceNumberFormat::builder()
+                    .prefix("EXP-")
+                    .padding(3)
+                    .build(),
+            )
+            .credit_notes(InvoiceNumberFormat::builder().prefix("CN-").build())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = InvoiceNumberFormat;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn default_format_is_bare_number() {
+        assert_eq!(Sut::default().format(237, Year::from(2025)), "237");
+    }
+
+    #[test]
+    fn padding_does_not_truncate() {
+        let sut = Sut::builder().padding(2).build();
+        assert_eq!(sut.format(1234, Year::from(2025)), "1234");
+    }
+
+    #[test]
+    fn prefix_year_and_padding() {
+        assert_eq!(
+            Sut::sample_other().format(7, Year::from(2026)),
+            "ACME-2026-007"
+        );
+    }
+
+    #[test]
+    fn series_equality() {
+        assert_eq!(InvoiceNumberSeries::sample(), InvoiceNumberSeries::sample());
+        assert_ne!(
+            InvoiceNumberSeries::sample(),
+            InvoiceNumberSeries::sample_other()
+        );
+    }
+
+    #[test]
+    fn series_deserialize_partial() {
+        let sut = ron::from_str::<InvoiceNumberSeries>(
+            r#"(services: (prefix: "ACME-", padding: 4, reset: Yearly))"#,
+        )
+        .unwrap();
+        assert_eq!(sut.services().format(42, Year::from(2025)), "ACME-0042");
+        assert!(sut.services().resets_yearly());
+        assert_eq!(sut.expenses(), &None);
+        assert_eq!(sut.credit_notes(), &InvoiceNumberFormat::default());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Decimal, HasSample, round_amount};
+use bon::Builder;
+use getset::Getters;
+use rust_decimal::dec;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// What is charged on top of the outstanding amount of overdue invoices when
+/// a payment reminder is sent, e.g. statutory late-payment interest and a
+/// fixed reminder fee. Nothing is charged by default.
+#[derive(
+    Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters,
+)]
+pub struct LatePaymentCharges {
+    /// The annual interest rate in percent accrued on the outstanding amount
+    /// from the due date, e.g. `10.5` for 10.5% per year.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    annual_interest_rate: Decimal,
+
+    /// The fixed fee charged per payment reminder, in the currency of the
+    /// invoice, e.g. `60`.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    reminder_fee: Decimal,
+}
+
+impl LatePaymentCharges {
+    /// The interest accrued on `outstanding` during `days_overdue` days, at
+    /// the annual interest rate over a 365 day year, rounded to cents.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let charges = LatePaymentCharges::builder()
+    ///     .annual_interest_rate(Decimal::from(dec!(10)))
+    ///     .build();
+    /// assert_eq!(*charges.interest(Decimal::from(dec!(3650)), 30), dec!(30));
+    /// ```
+    pub fn interest(&self, outstanding: Decimal, days_overdue: i64) -> Decimal {
+        round_amount(
+            *outstanding * *self.annual_interest_rate / dec!(100)
+                * rust_decimal::Decimal::from(days_overdue)
+                / dec!(365),
+        )
+    }
+}
+
+impl HasSample for LatePaymentCharges {
+    fn sample() -> Self {
+        Self::builder()
+            .annual_interest_rate(Decimal::from(dec!(10.5)))
+            .reminder_fee(Decimal::from(dec!(60)))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .annual_interest_rate(Decimal::from(dec!(8)))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = LatePaymentCharges;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn default_charges_nothing() {
+        let sut = Sut::default();
+        assert_eq!(*sut.interest(Decimal::from(dec!(1000)), 30), dec!(0));
+        assert_eq!(**sut.reminder_fee(), dec!(0));
+    }
+
+    #[test]
+    fn interest_rounds_to_cents() {
+        let sut = Sut::sample();
+        assert_eq!(*sut.interest(Decimal::from(dec!(11000)), 14), dec!(44.30));
+    }
+}
@@ -1,29 +1,49 @@
+mod client_id;
+mod clients;
+mod credit_notes;
+mod discount_terms;
+mod effective_rate;
 mod email;
 mod expensed_periods;
 mod expenses_for_periods;
 mod footer_text;
+mod invoice_number_format;
 mod labeled_field;
+mod late_payment_charges;
 mod net_days;
 mod payment_information;
 mod payment_terms;
 mod proto_invoice_info;
 mod purchase_order;
 mod record_of_periods_off;
+mod recurring_items;
+mod retainer;
+mod service;
 mod service_fees;
 mod time_off;
 mod timestamped_invoice_number;
 
+pub use client_id::*;
+pub use clients::*;
+pub use credit_notes::*;
+pub use discount_terms::*;
+pub use effective_rate::*;
 pub use email::*;
 pub use expensed_periods::*;
 pub use footer_text::*;
+pub use invoice_number_format::*;
 pub use klirr_foundation::{Cadence, Currency, Granularity};
 pub use labeled_field::*;
+pub use late_payment_charges::*;
 pub use net_days::*;
 pub use payment_information::*;
 pub use payment_terms::*;
 pub use proto_invoice_info::*;
 pub use purchase_order::*;
 pub use record_of_periods_off::*;
+pub use recurring_items::*;
+pub use retainer::*;
+pub use service::*;
 pub use service_fees::*;
 pub use time_off::*;
 pub use timestamped_invoice_number::*;
@@ -30,7 +30,8 @@ pub struct NetDays {
 impl FromStr for NetDays {
     type Err = crate::Error;
 
-    /// Tries to parse a string in the format "Net {days}", e.g. "Net 30".
+    /// Tries to parse a string in the format "Net {days}", e.g. "Net 30",
+    /// ignoring case.
     /// /// # Errors
     /// Returns an error if the string is not in the correct format or if
     /// the number of days is invalid.
@@ -42,12 +43,13 @@ impl FromStr for NetDays {
     /// assert_eq!(net_days.due_in(), &DueDays::try_from(35u16).unwrap());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let days = s
-            .split("Net ")
-            .nth(1)
-            .ok_or(Error::FailedToParsePaymentTermsNetDays {
-                invalid_string: s.to_owned(),
-            })?;
+        let lowercased = s.trim().to_lowercase();
+        let days =
+            lowercased
+                .strip_prefix("net ")
+                .ok_or(Error::FailedToParsePaymentTermsNetDays {
+                    invalid_string: s.to_owned(),
+                })?;
         let days =
             DueDays::from_str(days).map_err(|_| Error::FailedToParsePaymentTermsNetDays {
                 invalid_string: s.to_owned(),
@@ -64,6 +66,12 @@ impl NetDays {
     }
 }
 
+impl klirr_foundation::DueInDays for NetDays {
+    fn due_in_days(&self) -> DueDays {
+        self.due_in
+    }
+}
+
 impl HasSample for NetDays {
     fn sample() -> Self {
         Self::builder().due_in(DueDays::sample()).build()
@@ -90,4 +98,11 @@ mod tests {
     fn inequality() {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
+
+    #[test]
+    fn from_str_ignores_case() {
+        let sut: Sut = "net 45".parse().unwrap();
+        assert_eq!(sut.due_in(), &DueDays::try_from(45u16).unwrap());
+        assert_eq!(sut.to_string(), "Net 45");
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{Currency, Error, HasSample, LabeledField, PaymentTerms, Result};
+use crate::{
+    Currency, Error, ExchangeRatesProviderKind, HasSample, LabeledField, LatePaymentCharges,
+    PaymentTerms, Result,
+};
 use bon::Builder;
 use getset::Getters;
 use getset::WithSetters;
@@ -75,6 +78,29 @@ pub struct PaymentInformation {
     #[serde(default)]
     #[getset(get = "pub")]
     payment_method_overrides: Vec<LabeledField>,
+
+    /// The interest and fee charged on overdue invoices in payment
+    /// reminders, nothing by default.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    late_payment: LatePaymentCharges,
+
+    /// The currency VAT is reported in, if other than `currency`, e.g. `SEK`
+    /// for a Swedish vendor invoicing in `EUR`. The subtotal, VAT and grand
+    /// total are then also shown in this currency, converted at the rate of
+    /// the invoice date, see [`crate::ReportingTotals`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set_with = "pub")]
+    reporting_currency: Option<Currency>,
+
+    /// Where exchange rates of expenses and reporting totals come from, the
+    /// Frankfurter API by default, e.g. `Ecb` or `File("rates.csv")` for a
+    /// file of rates in the data directory.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "ExchangeRatesProviderKind::is_default")]
+    #[getset(get = "pub", set_with = "pub")]
+    exchange_rates_provider: ExchangeRatesProviderKind,
 }
 
 impl PaymentInformation {
@@ -126,6 +152,7 @@ impl HasSample for PaymentInformation {
             .currency(Currency::USD)
             .terms(PaymentTerms::sample_other())
             .vat(Vat::sample())
+            .late_payment(LatePaymentCharges::sample())
             .build()
     }
 }
@@ -195,6 +222,59 @@ mod tests {
         assert_eq!(*pi.vat(), Vat::ZERO);
     }
 
+    #[test]
+    fn reporting_currency_is_optional_and_only_serialized_if_set() {
+        let ron = r#"PaymentInformation(
+            iban: "FR76 3000 6000 0112 3456 7890 189",
+            bank_name: "Banque de Paris",
+            bic: "BNPAFRPP",
+            currency: "EUR",
+            terms: "Net 30",
+        )"#;
+        let parsed: PaymentInformation = ron::from_str(ron).unwrap();
+        assert_eq!(*parsed.reporting_currency(), None);
+        assert!(
+            !ron::to_string(&parsed)
+                .unwrap()
+                .contains("reporting_currency")
+        );
+
+        let sek = parsed.with_reporting_currency(Some(Currency::SEK));
+        let roundtripped: PaymentInformation =
+            ron::from_str(&ron::to_string(&sek).unwrap()).unwrap();
+        assert_eq!(*roundtripped.reporting_currency(), Some(Currency::SEK));
+    }
+
+    #[test]
+    fn exchange_rates_provider_defaults_to_frankfurter_and_is_only_serialized_if_set() {
+        let ron = r#"PaymentInformation(
+            iban: "FR76 3000 6000 0112 3456 7890 189",
+            bank_name: "Banque de Paris",
+            bic: "BNPAFRPP",
+            currency: "EUR",
+            terms: "Net 30",
+        )"#;
+        let parsed: PaymentInformation = ron::from_str(ron).unwrap();
+        assert_eq!(
+            *parsed.exchange_rates_provider(),
+            ExchangeRatesProviderKind::Frankfurter
+        );
+        assert!(
+            !ron::to_string(&parsed)
+                .unwrap()
+                .contains("exchange_rates_provider")
+        );
+
+        let file = parsed
+            .with_exchange_rates_provider(ExchangeRatesProviderKind::File("rates.csv".into()));
+        let roundtripped: PaymentInformation =
+            ron::from_str(&ron::to_string(&file).unwrap()).unwrap();
+        assert_eq!(
+            *roundtripped.exchange_rates_provider(),
+            ExchangeRatesProviderKind::File("rates.csv".into())
+        );
+    }
+
     #[test]
     fn samples_have_no_payment_method_overrides() {
         assert!(Sut::sample().payment_method_overrides().is_empty());
@@ -212,6 +292,7 @@ mod tests {
         )"#;
         let parsed: PaymentInformation = ron::from_str(ron).unwrap();
         assert!(parsed.payment_method_overrides().is_empty());
+        assert_eq!(*parsed.late_payment(), LatePaymentCharges::default());
     }
 
     #[test]
@@ -1,20 +1,43 @@
-use crate::{DueDays, FromStr, HasSample, NetDays, Result};
+use crate::{
+    Date, Day, DiscountTerms, DueDays, Error, FromStr, HasSample, InvoiceTerms, NetDays, Result,
+};
 use derive_more::Display;
-use serde::Deserialize;
-use serde::Serialize;
+use serde_with::DeserializeFromStr;
+use serde_with::SerializeDisplay;
 
-/// The payment terms of this invoice, e.g. `Net { due_in: 30 }`
-#[derive(Clone, Debug, Display, Serialize, PartialEq, Eq, Hash, Deserialize)]
-#[serde(untagged)]
+/// The payment terms of this invoice, e.g. `Net 30`
+#[derive(Clone, Debug, Display, SerializeDisplay, PartialEq, Eq, Hash, DeserializeFromStr)]
 pub enum PaymentTerms {
-    /// Net payment due in a specific number of days, e.g. `Net(30)`
+    /// Net payment due in a specific number of days, e.g. `Net 30`
+    #[display("{_0}")]
     Net(NetDays),
+
+    /// Net payment due in a specific number of days after the end of the
+    /// month of the invoice date, e.g. `Net 30 EOM`
+    #[display("{_0} EOM")]
+    NetEndOfMonth(NetDays),
+
+    /// Payment due at the invoice date, i.e. `Due on receipt`
+    #[display("Due on receipt")]
+    DueOnReceipt,
+
+    /// Payment due at a fixed day of the month following the invoice date,
+    /// e.g. `Day 15 next month`, or at the last day of that month if it is
+    /// shorter.
+    #[display("Day {_0} next month")]
+    DayOfNextMonth(Day),
+
+    /// Net payment with a discount if paid early, e.g. `2/10 Net 30`
+    #[display("{_0}")]
+    Discount(DiscountTerms),
 }
 
 impl FromStr for PaymentTerms {
     type Err = crate::Error;
 
-    /// Parses a string into `PaymentTerms`, e.g. "Net 30" into `PaymentTerms::Net(NetDays { due_in: 30 })`.
+    /// Parses a string into `PaymentTerms`, ignoring case, e.g. "Net 30" into
+    /// `PaymentTerms::Net(NetDays { due_in: 30 })`, or any of "Net 30 EOM",
+    /// "Due on receipt", "Day 15 next month" or "2/10 Net 30".
     /// # Errors
     /// Returns an error if the string is not in the correct format or if
     /// the number of days is invalid.
@@ -24,10 +47,31 @@ impl FromStr for PaymentTerms {
     /// use klirr_core_invoice::*;
     /// let payment_terms: PaymentTerms = "Net 30".parse().unwrap();
     /// assert!(matches!(payment_terms, PaymentTerms::Net(_)));
+    ///
+    /// let payment_terms: PaymentTerms = "2/10 net 30".parse().unwrap();
+    /// assert!(matches!(payment_terms, PaymentTerms::Discount(_)));
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let net_days = NetDays::from_str(s)?;
-        Ok(PaymentTerms::Net(net_days))
+        let lowercased = s.trim().to_lowercase();
+        if lowercased == "due on receipt" {
+            return Ok(PaymentTerms::DueOnReceipt);
+        }
+        if let Some(day) = lowercased
+            .strip_prefix("day ")
+            .and_then(|rest| rest.strip_suffix(" next month"))
+        {
+            let day = Day::from_str(day).map_err(|_| Error::FailedToParsePaymentTerms {
+                invalid_string: s.to_owned(),
+            })?;
+            return Ok(PaymentTerms::DayOfNextMonth(day));
+        }
+        if let Some(net) = lowercased.strip_suffix(" eom") {
+            return NetDays::from_str(net).map(PaymentTerms::NetEndOfMonth);
+        }
+        if lowercased.contains('/') {
+            return DiscountTerms::from_str(&lowercased).map(PaymentTerms::Discount);
+        }
+        NetDays::from_str(&lowercased).map(PaymentTerms::Net)
     }
 }
 
@@ -42,14 +86,50 @@ impl PaymentTerms {
     pub fn net30() -> Self {
         PaymentTerms::Net(NetDays::net30())
     }
-}
 
-impl klirr_foundation::DueInDays for PaymentTerms {
-    fn due_in_days(&self) -> DueDays {
+    /// The date payment is due of an invoice issued at `invoice_date`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// let invoice_date: Date = "2025-01-20".parse().unwrap();
+    /// let due_date = |terms: &str| {
+    ///     terms
+    ///         .parse::<PaymentTerms>()
+    ///         .unwrap()
+    ///         .due_date(invoice_date)
+    ///         .to_string()
+    /// };
+    /// assert_eq!(due_date("Net 30"), "2025-02-19");
+    /// assert_eq!(due_date("Net 30 EOM"), "2025-03-02");
+    /// assert_eq!(due_date("Due on receipt"), "2025-01-20");
+    /// assert_eq!(due_date("Day 31 next month"), "2025-02-28");
+    /// assert_eq!(due_date("2/10 Net 30"), "2025-02-19");
+    /// ```
+    pub fn due_date(&self, invoice_date: Date) -> Date {
         match self {
-            PaymentTerms::Net(days) => *days.due_in(),
+            PaymentTerms::Net(net) => invoice_date.advance(net),
+            PaymentTerms::NetEndOfMonth(net) => invoice_date.end_of_month().advance(net),
+            PaymentTerms::DueOnReceipt => invoice_date,
+            PaymentTerms::DayOfNextMonth(day) => {
+                let next_month = invoice_date
+                    .end_of_month()
+                    .advance_days(&DueDays::try_from(1u16).expect("1 is valid due days"));
+                Date::builder()
+                    .year(*next_month.year())
+                    .month(*next_month.month())
+                    .day((*day).min(next_month.last_day_of_month()))
+                    .build()
+            }
+            PaymentTerms::Discount(discount) => invoice_date.advance(discount.net()),
         }
     }
+
+    /// The terms as rendered on an invoice issued at `invoice_date`.
+    pub fn invoice_terms(&self, invoice_date: Date) -> InvoiceTerms {
+        InvoiceTerms::new(self, invoice_date)
+    }
 }
 
 impl HasSample for PaymentTerms {
@@ -128,9 +208,70 @@ mod tests {
         // so "Net 35", "Net 60", etc. failed to parse.
         for days in [35u16, 45, 60, 90, 120, 365] {
             let terms: PaymentTerms = format!("Net {days}").parse().unwrap();
-            let PaymentTerms::Net(net) = terms;
+            let PaymentTerms::Net(net) = &terms else {
+                panic!("expected net terms, got {terms:?}");
+            };
             assert_eq!(net.due_in(), &DueDays::try_from(days).unwrap());
             assert_eq!(terms.to_string(), format!("Net {days}"));
         }
     }
+
+    #[test]
+    fn display_roundtrip_of_all_variants() {
+        for terms in [
+            "Net 30",
+            "Net 30 EOM",
+            "Due on receipt",
+            "Day 15 next month",
+            "2/10 Net 30",
+        ] {
+            let sut: Sut = terms.parse().unwrap();
+            assert_eq!(sut.to_string(), terms);
+        }
+    }
+
+    #[test]
+    fn serializes_as_display() {
+        let sut: Sut = "Net 30 EOM".parse().unwrap();
+        assert_ron_snapshot!(sut);
+        let sut: Sut = "2/10 Net 30".parse().unwrap();
+        assert_eq!(
+            ron::de::from_str::<Sut>(&ron::ser::to_string(&sut).unwrap()).unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_terms() {
+        for invalid in [
+            "Due on",
+            "Day 32 next month",
+            "Day 15",
+            "Net EOM",
+            "2/40 Net 30",
+            "Net 30 EOM extra",
+        ] {
+            assert!(
+                invalid.parse::<Sut>().is_err(),
+                "Expected error for '{invalid}'"
+            );
+        }
+    }
+
+    #[test]
+    fn due_date_of_day_of_next_month() {
+        let sut: Sut = "Day 15 next month".parse().unwrap();
+        let due = |date: &str| sut.due_date(date.parse().unwrap()).to_string();
+        assert_eq!(due("2025-01-31"), "2025-02-15");
+        assert_eq!(due("2025-12-01"), "2026-01-15");
+    }
+
+    #[test]
+    fn due_date_of_net_end_of_month() {
+        let sut: Sut = "Net 10 EOM".parse().unwrap();
+        assert_eq!(
+            sut.due_date("2025-05-31".parse().unwrap()).to_string(),
+            "2025-06-10"
+        );
+    }
 }
@@ -1,9 +1,10 @@
 use crate::{
-    Error, FooterText, HasSample, HexColor, PurchaseOrder, RecordOfPeriodsOff, Result,
-    TimestampedInvoiceNumber,
+    ClientId, Error, FooterText, HasSample, HexColor, InvoiceNumberSeries, PurchaseOrder,
+    RecordOfPeriodsOff, Result, TimestampedInvoiceNumber,
 };
 use bon::Builder;
 use getset::{Getters, Setters, WithSetters};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 /// Partial information about the invoice which can be used to derive [`crate::InvoiceInfoFull`].
@@ -16,6 +17,17 @@ pub struct ProtoInvoiceInfo {
     #[getset(get = "pub", set_with = "pub")]
     offset: TimestampedInvoiceNumber,
 
+    /// Invoice number offsets of clients in the client registry, see
+    /// [`crate::Clients`]. Each registered client has its own number series,
+    /// `offset` is used for the default client.
+    ///
+    /// `#[serde(default)]` keeps existing RON files (written before this
+    /// field existed) deserializable with no registered clients.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    client_offsets: IndexMap<ClientId, TimestampedInvoiceNumber>,
+
     /// Period-end dates when no invoice was issued, i.e. full periods off.
     #[builder(default)]
     #[getset(get = "pub", set = "pub")]
@@ -34,6 +46,13 @@ pub struct ProtoInvoiceInfo {
     /// Hex color used for invoice emphasis styling, e.g. `"#e6007a"`.
     #[getset(get = "pub", set_with = "pub")]
     emphasize_color_hex: Option<HexColor>,
+
+    /// How invoice numbers are formatted, e.g. `ACME-0042`, see
+    /// [`InvoiceNumberSeries`]. Defaults to bare numbers.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub", set_with = "pub")]
+    number_series: InvoiceNumberSeries,
 }
 
 impl ProtoInvoiceInfo {
@@ -42,6 +61,36 @@ impl ProtoInvoiceInfo {
         periods_off.insert(period_end_date);
         self.set_record_of_periods_off(periods_off);
     }
+
+    /// Inserts or replaces the invoice number offset for `client`.
+    pub fn insert_client_offset(&mut self, client: ClientId, offset: TimestampedInvoiceNumber) {
+        self.client_offsets.insert(client, offset);
+    }
+
+    /// Removes the invoice number offset for `client`, if any.
+    pub fn remove_client_offset(&mut self, client: &ClientId) {
+        self.client_offsets.shift_remove(client);
+    }
+
+    /// Returns the invoice number offset of `client`, or the default `offset`
+    /// if `client` is `None`.
+    ///
+    /// # Errors
+    /// Returns [`Error::MissingInvoiceNumberOffsetForClient`] if `client` has
+    /// no offset.
+    pub fn offset_for_client(
+        &self,
+        client: Option<&ClientId>,
+    ) -> Result<&TimestampedInvoiceNumber> {
+        match client {
+            None => Ok(&self.offset),
+            Some(client) => self.client_offsets.get(client).ok_or_else(|| {
+                Error::MissingInvoiceNumberOffsetForClient {
+                    client: client.to_string(),
+                }
+            }),
+        }
+    }
 }
 
 impl HasSample for ProtoInvoiceInfo {
@@ -68,11 +117,14 @@ impl HasSample for ProtoInvoiceInfo {
 
 impl ProtoInvoiceInfo {
     pub fn validate(&self) -> Result<()> {
-        if self.record_of_periods_off.contains(self.offset.date()) {
-            return Err(Error::OffsetPeriodMustNotBeInRecordOfPeriodsOff {
-                offset_period: format!("{:?}", self.offset.date()),
-                period_kind: "Date".to_owned(),
-            });
+        let offsets = std::iter::once(&self.offset).chain(self.client_offsets.values());
+        for offset in offsets {
+            if self.record_of_periods_off.contains(offset.date()) {
+                return Err(Error::OffsetPeriodMustNotBeInRecordOfPeriodsOff {
+                    offset_period: format!("{:?}", offset.date()),
+                    period_kind: "Date".to_owned(),
+                });
+            }
         }
         Ok(())
     }
@@ -97,10 +149,55 @@ mod tests {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
 
+    #[test]
+    fn offset_for_client() {
+        let client_offset = TimestampedInvoiceNumber::sample_other();
+        let sut = Sut::sample().with_client_offsets(IndexMap::from_iter([(
+            ClientId::sample(),
+            client_offset.clone(),
+        )]));
+        assert_eq!(sut.offset_for_client(None).unwrap(), sut.offset());
+        assert_eq!(
+            sut.offset_for_client(Some(&ClientId::sample())).unwrap(),
+            &client_offset
+        );
+        assert!(matches!(
+            sut.offset_for_client(Some(&ClientId::sample_other())),
+            Err(Error::MissingInvoiceNumberOffsetForClient { client }) if client == "moriarty"
+        ));
+    }
+
+    #[test]
+    fn validate_fails_when_client_offset_is_in_periods_off() {
+        let client_offset = TimestampedInvoiceNumber::sample_other();
+        let mut sut = Sut::sample();
+        sut.insert_client_offset(ClientId::sample(), client_offset.clone());
+        assert!(sut.validate().is_ok());
+        sut.insert_period_off(*client_offset.date());
+        assert!(matches!(
+            sut.validate(),
+            Err(Error::OffsetPeriodMustNotBeInRecordOfPeriodsOff { .. })
+        ));
+    }
+
+    #[test]
+    fn deserialize_without_client_offsets() {
+        let ron = r#"(
+    offset: (offset: 17, date: "2025-05-31"),
+    record_of_periods_off: ([]),
+    purchase_order: None,
+    footer_text: None,
+    emphasize_color_hex: None,
+)"#;
+        let sut = ron::from_str::<Sut>(ron).unwrap();
+        assert!(sut.client_offsets().is_empty());
+        assert_eq!(sut.number_series(), &InvoiceNumberSeries::default());
+    }
+
     #[test]
     fn test_advance() {
         let date = Date::from_str("2025-05-31").unwrap();
-        let advanced = date.advance(&PaymentTerms::net30());
+        let advanced = PaymentTerms::net30().due_date(date);
         assert_eq!(advanced, Date::from_str("2025-06-30").unwrap());
     }
 }
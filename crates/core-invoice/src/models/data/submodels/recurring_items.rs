@@ -0,0 +1,288 @@
+use crate::{
+    Cadence, Currency, Date, Error, HasSample, Item, Quantity, Result, UnitPrice, Vat,
+    period_bounds,
+};
+use bon::Builder;
+use derive_more::Deref;
+use getset::Getters;
+use rust_decimal::dec;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A fixed fee billed every period of its cadence, e.g. a monthly hosting
+/// fee, added as its own row on the service invoices of those periods.
+///
+/// The item is billed in full for every period of its cadence which overlaps
+/// its `start` and `end` dates, it is not prorated.
+///
+/// **The `unit_price` is VAT-exclusive**, see [`crate::ServiceFees`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters, Builder)]
+pub struct RecurringItem {
+    /// Description of the item, e.g. `"Hosting"`
+    #[getset(get = "pub")]
+    #[builder(into)]
+    name: String,
+
+    /// The price per period of the cadence, **excluding VAT**.
+    #[getset(get = "pub")]
+    #[builder(into)]
+    unit_price: UnitPrice,
+
+    /// The currency of the price, converted into the currency of the invoice
+    /// if they differ.
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// How often the item is billed, e.g. `Monthly` for once per month,
+    /// regardless of the cadence of the invoices.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    cadence: Cadence,
+
+    /// The first date the item is billed for, `None` if it always was.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    start: Option<Date>,
+
+    /// The last date the item is billed for, `None` until it is ended.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end: Option<Date>,
+
+    /// The VAT rate of this item, `None` for the VAT rate of the invoice.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    vat: Option<Vat>,
+}
+
+impl RecurringItem {
+    /// Validates that the item does not end before it starts.
+    ///
+    /// # Errors
+    /// Returns [`Error::RecurringItemEndsBeforeStart`] if `end` is before
+    /// `start`.
+    pub fn validate(&self) -> Result<()> {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) if end < start => Err(Error::RecurringItemEndsBeforeStart {
+                name: self.name.clone(),
+                start,
+                end,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the item is billed for any date within `start..=end`.
+    fn is_active_within(&self, start: Date, end: Date) -> bool {
+        self.start.is_none_or(|first| first <= end) && self.end.is_none_or(|last| last >= start)
+    }
+
+    /// The number of periods of the cadence of this item which end within
+    /// `start..=end`, both inclusive, and during which the item is active.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let item = RecurringItem::builder()
+    ///     .name("Support")
+    ///     .unit_price(UnitPrice::from(dec!(250)))
+    ///     .currency(Currency::EUR)
+    ///     .cadence(Cadence::BiWeekly)
+    ///     .build();
+    /// let periods = item
+    ///     .periods_within("2025-05-01".parse().unwrap(), "2025-05-31".parse().unwrap())
+    ///     .unwrap();
+    /// assert_eq!(periods, 2);
+    /// ```
+    pub fn periods_within(&self, start: Date, end: Date) -> Result<u16> {
+        let mut periods = 0;
+        let (_, mut period_end) = period_bounds(start, self.cadence)?;
+        while period_end <= end {
+            let (period_start, _) = period_bounds(period_end, self.cadence)?;
+            if self.is_active_within(period_start, period_end) {
+                periods += 1;
+            }
+            let next_day = Date::from(period_end.to_datetime() + chrono::Duration::days(1));
+            (_, period_end) = period_bounds(next_day, self.cadence)?;
+        }
+        Ok(periods)
+    }
+}
+
+impl HasSample for RecurringItem {
+    fn sample() -> Self {
+        Self::builder()
+            .name("Hosting")
+            .unit_price(UnitPrice::from(dec!(49.0)))
+            .currency(Currency::EUR)
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .name("Support retainer")
+            .unit_price(UnitPrice::from(dec!(1200.0)))
+            .currency(Currency::USD)
+            .cadence(Cadence::Quarterly)
+            .start(Date::sample_other())
+            .end(Date::sample())
+            .build()
+    }
+}
+
+/// Fixed fees billed on service invoices every period of their cadence,
+/// persisted in `recurring_items.ron`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Deref)]
+pub struct RecurringItems(Vec<RecurringItem>);
+
+impl RecurringItems {
+    /// Creates a new `RecurringItems` from `items`.
+    pub fn new(items: impl IntoIterator<Item = RecurringItem>) -> Self {
+        Self(Vec::from_iter(items))
+    }
+
+    /// Validates every item, see [`RecurringItem::validate`].
+    ///
+    /// # Errors
+    /// Returns an error for the first item which ends before it starts.
+    pub fn validate(&self) -> Result<()> {
+        self.0.iter().try_for_each(RecurringItem::validate)
+    }
+
+    /// The items billed in the invoice period of `cadence` ending at
+    /// `period_end`, each with the number of its periods ending within the
+    /// invoice period as quantity, dated `period_end`. Items with no period
+    /// ending within the invoice period are left out, e.g. a monthly item is
+    /// billed on the second half of the month with a bi-weekly cadence.
+    pub fn items_for_period(&self, period_end: Date, cadence: Cadence) -> Result<Vec<Item>> {
+        let (start, end) = period_bounds(period_end, cadence)?;
+        let mut items = Vec::new();
+        for recurring in self.0.iter() {
+            let periods = recurring.periods_within(start, end)?;
+            if periods == 0 {
+                continue;
+            }
+            items.push(
+                Item::builder()
+                    .name(recurring.name.clone())
+                    .transaction_date(period_end)
+                    .quantity(Quantity::from(rust_decimal::Decimal::from(periods)))
+                    .unit_price(recurring.unit_price)
+                    .currency(recurring.currency)
+                    .maybe_vat(recurring.vat)
+                    .build(),
+            );
+        }
+        Ok(items)
+    }
+}
+
+impl HasSample for RecurringItems {
+    fn sample() -> Self {
+        Self::new([RecurringItem::sample()])
+    }
+
+    fn sample_other() -> Self {
+        Self::new([RecurringItem::sample_other()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = RecurringItems;
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn monthly_item_is_billed_once_per_monthly_invoice() {
+        let items = Sut::sample()
+            .items_for_period(date("2025-05-31"), Cadence::Monthly)
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name(), "Hosting");
+        assert_eq!(*items[0].quantity(), Quantity::ONE);
+        assert_eq!(*items[0].transaction_date(), date("2025-05-31"));
+    }
+
+    #[test]
+    fn monthly_item_is_billed_on_bi_weekly_invoice_ending_the_month() {
+        let sut = Sut::sample();
+        let first_half = sut
+            .items_for_period(date("2025-05-15"), Cadence::BiWeekly)
+            .unwrap();
+        let second_half = sut
+            .items_for_period(date("2025-05-31"), Cadence::BiWeekly)
+            .unwrap();
+        assert!(first_half.is_empty());
+        assert_eq!(second_half.len(), 1);
+    }
+
+    #[test]
+    fn monthly_item_is_billed_three_times_per_quarterly_invoice() {
+        let items = Sut::sample()
+            .items_for_period(date("2025-06-30"), Cadence::Quarterly)
+            .unwrap();
+        assert_eq!(*items[0].quantity(), Quantity::from(dec!(3)));
+    }
+
+    #[test]
+    fn item_is_not_billed_outside_of_start_and_end() {
+        let item = RecurringItem::builder()
+            .name("Hosting")
+            .unit_price(UnitPrice::from(dec!(49.0)))
+            .currency(Currency::EUR)
+            .start(date("2025-05-20"))
+            .end(date("2025-07-10"))
+            .build();
+        let sut = Sut::new([item]);
+        let billed = |period_end: &str| {
+            sut.items_for_period(date(period_end), Cadence::Monthly)
+                .unwrap()
+                .len()
+        };
+        assert_eq!(billed("2025-04-30"), 0);
+        assert_eq!(billed("2025-05-31"), 1);
+        assert_eq!(billed("2025-07-31"), 1);
+        assert_eq!(billed("2025-08-31"), 0);
+    }
+
+    #[test]
+    fn validate_fails_when_item_ends_before_it_starts() {
+        let item = RecurringItem::builder()
+            .name("Hosting")
+            .unit_price(UnitPrice::from(dec!(49.0)))
+            .currency(Currency::EUR)
+            .start(date("2025-05-20"))
+            .end(date("2025-05-19"))
+            .build();
+        assert_eq!(
+            Sut::new([item]).validate(),
+            Err(Error::RecurringItemEndsBeforeStart {
+                name: "Hosting".to_owned(),
+                start: date("2025-05-20"),
+                end: date("2025-05-19"),
+            })
+        );
+        assert!(Sut::sample_other().validate().is_ok());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{HasSample, Quantity, UnitPrice};
+use bon::Builder;
+use getset::Getters;
+use rust_decimal::dec;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A fixed fee per invoiced period covering an included amount of work of
+/// the primary service, e.g. `40` hours per month. Work beyond the included
+/// amount is billed as overage at the rate of the primary service.
+///
+/// **The `fee` is VAT-exclusive**, see [`crate::ServiceFees`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters, Builder)]
+pub struct Retainer {
+    /// Description of the retainer row, e.g. `"Support retainer"`
+    #[getset(get = "pub")]
+    #[builder(into)]
+    name: String,
+
+    /// The fixed fee per invoiced period, **excluding VAT**.
+    #[getset(get = "pub")]
+    #[builder(into)]
+    fee: UnitPrice,
+
+    /// The amount of work covered by the fee, in the time unit of the rate of
+    /// the primary service, i.e. days or hours.
+    #[getset(get = "pub")]
+    #[builder(into)]
+    included: Quantity,
+}
+
+impl Retainer {
+    /// The amount of `worked` beyond the included amount, zero if all of it
+    /// is covered by the fee.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let retainer = Retainer::sample();
+    /// assert_eq!(retainer.overage(Quantity::from(dec!(52))), Quantity::from(dec!(12)));
+    /// assert_eq!(retainer.overage(Quantity::from(dec!(30))), Quantity::ZERO);
+    /// ```
+    pub fn overage(&self, worked: Quantity) -> Quantity {
+        if worked > self.included {
+            worked - self.included
+        } else {
+            Quantity::ZERO
+        }
+    }
+}
+
+impl HasSample for Retainer {
+    fn sample() -> Self {
+        Self::builder()
+            .name("Support retainer")
+            .fee(UnitPrice::from(dec!(4000.0)))
+            .included(Quantity::from(dec!(40.0)))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .name("Development retainer")
+            .fee(UnitPrice::from(dec!(7500.0)))
+            .included(Quantity::from(dec!(10.0)))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Retainer;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn overage_is_zero_when_exactly_included_amount_is_worked() {
+        assert_eq!(
+            Sut::sample().overage(Quantity::from(dec!(40))),
+            Quantity::ZERO
+        );
+    }
+}
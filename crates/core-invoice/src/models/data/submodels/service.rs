@@ -0,0 +1,69 @@
+use crate::{HasSample, Rate, UnitPrice, Vat};
+use bon::Builder;
+use getset::Getters;
+use rust_decimal::dec;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A single billable service, e.g. `"On-call"` at a monthly flat fee, rendered
+/// as its own row on service invoices.
+///
+/// **The `rate` is VAT-exclusive**, see [`crate::ServiceFees`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters, Builder)]
+pub struct Service {
+    /// Description of the service, e.g. `"Development"`
+    #[getset(get = "pub")]
+    #[builder(into)]
+    name: String,
+
+    /// The rate of this service, **excluding VAT**.
+    #[getset(get = "pub")]
+    #[builder(into)]
+    rate: Rate,
+
+    /// The VAT rate of this service, `None` for the VAT rate of the invoice.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    vat: Option<Vat>,
+}
+
+impl Service {
+    pub fn unit_price(&self) -> UnitPrice {
+        self.rate.unit_price()
+    }
+}
+
+impl HasSample for Service {
+    fn sample() -> Self {
+        Self::builder()
+            .name("On-call")
+            .rate(Rate::monthly(dec!(1500.0)))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .name("Development")
+            .rate(Rate::hourly(dec!(95.0)))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Service;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Cadence, Error, HasSample, Rate, Result, UnitPrice};
+use crate::{
+    Cadence, Date, EffectiveRate, Error, Granularity, HasSample, Rate, Result, Retainer, Service,
+    UnitPrice, WorkSchedule,
+};
 use bon::bon;
 use getset::Getters;
 use getset::WithSetters;
@@ -6,9 +9,13 @@ use rust_decimal::dec;
 use serde::Deserialize;
 use serde::Serialize;
 
-/// Represents the fees for a consulting service, including the name, rate,
+/// Represents the fees for consulting services, including the name, rate,
 /// and billing cadence.
 ///
+/// The `name` and `rate` describe the primary service, any
+/// `additional_services` are billed alongside it, each on its own row of the
+/// invoice, sharing the same subtotal.
+///
 /// **The `rate` is VAT-exclusive.** Any VAT is configured separately on
 /// [`crate::PaymentInformation::vat`] and applied to the resulting subtotal at
 /// render time, never embedded in the unit price stored here.
@@ -21,9 +28,23 @@ pub struct ServiceFees {
     /// The invoice rate, **excluding VAT**. VAT — if any — is configured on
     /// [`crate::PaymentInformation::vat`] and added on top of the computed
     /// subtotal when the invoice is rendered.
+    ///
+    /// If the rate has been renegotiated, this is the rate before the first
+    /// change in `rate_history`.
     #[getset(get = "pub", set_with = "pub")]
     rate: Rate,
 
+    /// Renegotiated rates of the primary service, each billed for the
+    /// periods starting on or after its `effective_from` date, so that
+    /// regenerating the invoice of an earlier period still bills the rate of
+    /// that period.
+    ///
+    /// Defaults to empty, so `service_fees.ron` files persisted before this
+    /// field existed still load.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rate_history: Vec<EffectiveRate>,
+
     /// How often you invoice, cannot be
     #[getset(get = "pub")]
     cadence: Cadence,
@@ -42,6 +63,36 @@ pub struct ServiceFees {
     #[getset(get = "pub")]
     #[serde(default)]
     off_on_bank_holidays: bool,
+
+    /// Services billed in addition to the primary service, e.g. on-call at a
+    /// monthly flat fee next to development at an hourly rate. Every rate must
+    /// be valid for the `cadence`.
+    ///
+    /// Defaults to empty, so `service_fees.ron` files persisted before this
+    /// field existed still load.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    additional_services: Vec<Service>,
+
+    /// Your working hours and weekdays, used to calculate the billable days
+    /// and hours of a period for day- and hour-granularity rates.
+    ///
+    /// Defaults to eight hours per day, Monday to Friday, so
+    /// `service_fees.ron` files persisted before this field existed still
+    /// load and bill as before.
+    #[getset(get = "pub", set_with = "pub")]
+    #[serde(default)]
+    work_schedule: WorkSchedule,
+
+    /// A fixed fee per invoiced period covering an included amount of work
+    /// of the primary service, which must be billed per day or per hour. The
+    /// fee is billed as one row and only the work beyond the included amount
+    /// is billed at `rate`, as an overage row.
+    ///
+    /// Defaults to `None`, billing all work at `rate`.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retainer: Option<Retainer>,
 }
 
 #[bon]
@@ -52,24 +103,154 @@ impl ServiceFees {
         rate: impl Into<Rate>,
         cadence: Cadence,
         #[builder(default)] off_on_bank_holidays: bool,
+        #[builder(default)] additional_services: Vec<Service>,
+        #[builder(default)] work_schedule: WorkSchedule,
+        #[builder(default)] rate_history: Vec<EffectiveRate>,
+        retainer: Option<Retainer>,
     ) -> Result<Self, Error> {
         let rate = rate.into();
-        if !cadence.validate(rate.granularity()) {
-            return Err(Error::CannotInvoiceForMonthWhenCadenceIsBiWeekly);
+        if retainer.is_some() {
+            std::iter::once(&rate)
+                .chain(rate_history.iter().map(EffectiveRate::rate))
+                .try_for_each(validate_rate_for_retainer)?;
+        }
+        if let Some(granularity) = std::iter::once(&rate)
+            .chain(rate_history.iter().map(EffectiveRate::rate))
+            .chain(additional_services.iter().map(Service::rate))
+            .map(Rate::granularity)
+            .find(|granularity| !cadence.validate(*granularity))
+        {
+            return Err(match (granularity, cadence) {
+                (Granularity::Month, Cadence::BiWeekly) => {
+                    Error::CannotInvoiceForMonthWhenCadenceIsBiWeekly
+                }
+                _ => Error::GranularityDoesNotFitCadence {
+                    granularity,
+                    cadence,
+                },
+            });
         }
         Ok(Self {
             name: name.as_ref().to_owned(),
             rate,
+            rate_history,
             cadence,
             off_on_bank_holidays,
+            additional_services,
+            work_schedule,
+            retainer,
         })
     }
 }
 
+/// Validates that `rate` bills per day or per hour, so that the work beyond
+/// the amount included in a [`Retainer`] can be counted.
+///
+/// # Errors
+/// Returns [`Error::RetainerRequiresDailyOrHourlyRate`] for any other rate.
+pub(crate) fn validate_rate_for_retainer(rate: &Rate) -> Result<()> {
+    match rate.granularity() {
+        Granularity::Day | Granularity::Hour => Ok(()),
+        service_fees_granularity => Err(Error::RetainerRequiresDailyOrHourlyRate {
+            service_fees_granularity,
+        }),
+    }
+}
+
 impl ServiceFees {
     pub fn unit_price(&self) -> UnitPrice {
         self.rate.unit_price()
     }
+
+    /// The rate of the primary service for the period from `start` to `end`,
+    /// both inclusive, the latest rate of `rate_history` effective from on or
+    /// before `start`, else `rate`.
+    ///
+    /// # Errors
+    /// Returns [`Error::RateChangeWithinPeriod`] if a rate of `rate_history`
+    /// becomes effective after `start` but on or before `end`, since the
+    /// period would be billed at two rates.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let fees = ServiceFees::builder()
+    ///     .name("Consulting")
+    ///     .rate(Rate::daily(dec!(777.0)))
+    ///     .cadence(Cadence::Monthly)
+    ///     .rate_history(vec![EffectiveRate::sample()])
+    ///     .build()
+    ///     .unwrap();
+    /// let june = fees
+    ///     .rate_for_period("2025-06-01".parse().unwrap(), "2025-06-30".parse().unwrap())
+    ///     .unwrap();
+    /// let july = fees
+    ///     .rate_for_period("2025-07-01".parse().unwrap(), "2025-07-31".parse().unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(june, Rate::daily(dec!(777.0)));
+    /// assert_eq!(july, Rate::daily(dec!(850.0)));
+    /// ```
+    pub fn rate_for_period(&self, start: Date, end: Date) -> Result<Rate> {
+        if let Some(change) = self
+            .rate_history
+            .iter()
+            .find(|change| start < *change.effective_from() && *change.effective_from() <= end)
+        {
+            return Err(Error::RateChangeWithinPeriod {
+                effective_from: *change.effective_from(),
+                start,
+                end,
+            });
+        }
+        Ok(self
+            .rate_history
+            .iter()
+            .filter(|change| *change.effective_from() <= start)
+            .max_by_key(|change| *change.effective_from())
+            .map(|change| *change.rate())
+            .unwrap_or(self.rate))
+    }
+
+    /// These fees with the rate of the period from `start` to `end` as
+    /// `rate` and no `rate_history`, see [`Self::rate_for_period`].
+    ///
+    /// # Errors
+    /// Returns [`Error::RateChangeWithinPeriod`] if the rate changes within
+    /// the period.
+    pub fn for_period(&self, start: Date, end: Date) -> Result<Self> {
+        Ok(Self {
+            rate: self.rate_for_period(start, end)?,
+            rate_history: Vec::new(),
+            ..self.clone()
+        })
+    }
+
+    /// All services to bill, the primary service first followed by the
+    /// `additional_services` in the order they were configured.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    ///
+    /// let fees = ServiceFees::sample();
+    /// assert_eq!(fees.services().len(), 1);
+    /// assert_eq!(fees.services()[0].name(), fees.name());
+    /// ```
+    pub fn services(&self) -> Vec<Service> {
+        std::iter::once(
+            Service::builder()
+                .name(self.name.clone())
+                .rate(self.rate)
+                .build(),
+        )
+        .chain(self.additional_services.iter().cloned())
+        .collect()
+    }
 }
 
 impl HasSample for ServiceFees {
@@ -145,5 +326,189 @@ mod tests {
         let fees: Sut = crate::deserialize_ron_str(legacy).unwrap();
         assert!(!fees.off_on_bank_holidays());
         assert_eq!(fees.name(), "Agreed Consulting Service");
+        assert!(fees.additional_services().is_empty());
+        assert_eq!(fees.work_schedule(), &WorkSchedule::default());
+    }
+
+    #[test]
+    fn deserializes_work_schedule() {
+        let ron = r#"(
+            name: "Development",
+            rate: Hourly(UnitPrice(95.0)),
+            cadence: Monthly,
+            work_schedule: (
+                hours_per_day: 7.5,
+                working_days: ["Mon", "Tue", "Wed", "Thu"],
+                part_time_percent: Some(80),
+            ),
+        )"#;
+        let fees: Sut = crate::deserialize_ron_str(ron).unwrap();
+        assert_eq!(fees.work_schedule(), &WorkSchedule::sample_other());
+    }
+
+    #[test]
+    fn services_lists_primary_then_additional() {
+        let sut = Sut::builder()
+            .name("Development")
+            .rate(Rate::hourly(dec!(95.0)))
+            .cadence(Cadence::Monthly)
+            .additional_services(vec![Service::sample()])
+            .build()
+            .unwrap();
+        let names = sut
+            .services()
+            .iter()
+            .map(|s| s.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Development", "On-call"]);
+    }
+
+    #[test]
+    fn builder_rejects_additional_service_invalid_for_cadence() {
+        let result = Sut::builder()
+            .name("Development")
+            .rate(Rate::hourly(dec!(95.0)))
+            .cadence(Cadence::BiWeekly)
+            .additional_services(vec![Service::sample()])
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::CannotInvoiceForMonthWhenCadenceIsBiWeekly)
+        );
+    }
+
+    #[test]
+    fn builder_rejects_weekly_rate_for_monthly_cadence() {
+        let result = Sut::builder()
+            .name("Development")
+            .rate(Rate::weekly(dec!(4500.0)))
+            .cadence(Cadence::Monthly)
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::GranularityDoesNotFitCadence {
+                granularity: Granularity::Week,
+                cadence: Cadence::Monthly
+            })
+        );
+    }
+
+    #[test]
+    fn builder_accepts_monthly_rate_for_quarterly_cadence() {
+        let result = Sut::builder()
+            .name("Retainer")
+            .rate(Rate::monthly(dec!(15000.0)))
+            .cadence(Cadence::Quarterly)
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn builder_rejects_retainer_for_monthly_rate() {
+        let result = Sut::builder()
+            .name("Support")
+            .rate(Rate::monthly(dec!(4000.0)))
+            .cadence(Cadence::Monthly)
+            .retainer(Retainer::sample())
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::RetainerRequiresDailyOrHourlyRate {
+                service_fees_granularity: Granularity::Month
+            })
+        );
+    }
+
+    fn d(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    fn with_rate_history() -> Sut {
+        Sut::builder()
+            .name("Consulting")
+            .rate(Rate::daily(dec!(777.0)))
+            .cadence(Cadence::Monthly)
+            .rate_history(vec![EffectiveRate::sample_other(), EffectiveRate::sample()])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn rate_for_period_picks_latest_effective_rate() {
+        let sut = with_rate_history();
+        let rate = |start: &str, end: &str| sut.rate_for_period(d(start), d(end)).unwrap();
+        assert_eq!(rate("2025-06-01", "2025-06-30"), Rate::daily(dec!(777.0)));
+        assert_eq!(rate("2025-07-01", "2025-07-31"), Rate::daily(dec!(850.0)));
+        assert_eq!(rate("2025-12-01", "2025-12-31"), Rate::daily(dec!(850.0)));
+        assert_eq!(rate("2026-01-01", "2026-01-31"), Rate::daily(dec!(900.0)));
+    }
+
+    #[test]
+    fn rate_for_period_fails_for_change_within_period() {
+        let result = with_rate_history().rate_for_period(d("2025-06-16"), d("2025-07-15"));
+        assert_eq!(
+            result,
+            Err(Error::RateChangeWithinPeriod {
+                effective_from: d("2025-07-01"),
+                start: d("2025-06-16"),
+                end: d("2025-07-15"),
+            })
+        );
+    }
+
+    #[test]
+    fn for_period_replaces_rate_and_clears_history() {
+        let sut = with_rate_history()
+            .for_period(d("2025-07-01"), d("2025-07-31"))
+            .unwrap();
+        assert_eq!(*sut.rate(), Rate::daily(dec!(850.0)));
+        assert!(sut.rate_history().is_empty());
+    }
+
+    #[test]
+    fn builder_rejects_rate_history_invalid_for_cadence() {
+        let result = Sut::builder()
+            .name("Development")
+            .rate(Rate::daily(dec!(777.0)))
+            .cadence(Cadence::BiWeekly)
+            .rate_history(vec![
+                EffectiveRate::builder()
+                    .effective_from(d("2025-07-01"))
+                    .rate(Rate::monthly(dec!(15000.0)))
+                    .build(),
+            ])
+            .build();
+        assert_eq!(
+            result,
+            Err(Error::CannotInvoiceForMonthWhenCadenceIsBiWeekly)
+        );
+    }
+
+    #[test]
+    fn deserializes_rate_history() {
+        let ron = r#"(
+            name: "Consulting",
+            rate: Daily(UnitPrice(777.0)),
+            cadence: Monthly,
+            rate_history: [
+                (effective_from: "2025-07-01", rate: Daily(UnitPrice(850.0))),
+            ],
+        )"#;
+        let fees: Sut = crate::deserialize_ron_str(ron).unwrap();
+        assert_eq!(fees.rate_history(), &vec![EffectiveRate::sample()]);
+    }
+
+    #[test]
+    fn deserializes_additional_services() {
+        let ron = r#"(
+            name: "Development",
+            rate: Hourly(UnitPrice(95.0)),
+            cadence: Monthly,
+            additional_services: [
+                (name: "On-call", rate: Monthly(UnitPrice(1500.0))),
+            ],
+        )"#;
+        let fees: Sut = crate::deserialize_ron_str(ron).unwrap();
+        assert_eq!(fees.additional_services(), &vec![Service::sample()]);
     }
 }
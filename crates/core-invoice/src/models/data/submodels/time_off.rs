@@ -1,35 +1,69 @@
-use crate::{Granularity, HasSample, Quantity};
+use crate::{DaysOff, Granularity, HasSample, Quantity};
+use derive_more::Display;
 use serde::Deserialize;
 use serde::Serialize;
 
-/// Represents the free quantity of items, e.g. the number of days off during a
-/// period, or the number of hours off during a period. Month is not part of this
-/// because the most coarse grained granularity for cadence is Month, and if you
-/// have been free a whole month that should be recorded in record of periods off.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+/// Represents the time off during a period, either a free quantity of items,
+/// e.g. the number of days off or the number of hours off during a period, or
+/// the specific dates off. Month is not part of this because the most coarse
+/// grained granularity for cadence is Month, and if you have been free a whole
+/// month that should be recorded in record of periods off.
+#[derive(Clone, Debug, Display, Serialize, Deserialize, PartialEq)]
 pub enum TimeOff {
     /// Amount of hours off during a period
+    #[display("{_0} hours")]
     Hours(Quantity),
     /// Amount of days off during a period
+    #[display("{_0} days")]
     Days(Quantity),
+    /// Specific dates off during a period, deducted from its working days for
+    /// services billed per day or per hour, so that dates off on a weekend or
+    /// bank holiday are not deducted twice.
+    #[display("{days_off}")]
+    Dates {
+        /// The dates off, e.g. `2025-05-12..2025-05-16`.
+        days_off: DaysOff,
+        /// Whether the dates off are listed on the invoice.
+        #[serde(default)]
+        listed: bool,
+    },
 }
 
 impl TimeOff {
-    pub fn granularity(&self) -> Granularity {
+    /// The granularity of a quantity of time off, `None` for dates off which
+    /// apply to days and hours alike.
+    pub fn granularity(&self) -> Option<Granularity> {
         match self {
-            Self::Hours(_) => Granularity::Hour,
-            Self::Days(_) => Granularity::Day,
+            Self::Hours(_) => Some(Granularity::Hour),
+            Self::Days(_) => Some(Granularity::Day),
+            Self::Dates { .. } => None,
         }
     }
-}
 
-impl std::ops::Deref for TimeOff {
-    type Target = Quantity;
+    /// The quantity of time off, `None` for dates off.
+    pub fn quantity(&self) -> Option<Quantity> {
+        match self {
+            Self::Hours(q) | Self::Days(q) => Some(*q),
+            Self::Dates { .. } => None,
+        }
+    }
+
+    /// The dates off, `None` for a quantity of time off.
+    pub fn days_off(&self) -> Option<&DaysOff> {
+        match self {
+            Self::Hours(_) | Self::Days(_) => None,
+            Self::Dates { days_off, .. } => Some(days_off),
+        }
+    }
 
-    fn deref(&self) -> &Self::Target {
+    /// The dates off to list on the invoice, if any.
+    pub fn listed_days_off(&self) -> Option<&DaysOff> {
         match self {
-            Self::Hours(q) => q,
-            Self::Days(q) => q,
+            Self::Dates {
+                days_off,
+                listed: true,
+            } => Some(days_off),
+            _ => None,
         }
     }
 }
@@ -63,10 +97,32 @@ mod tests {
     }
 
     #[test]
-    fn deref() {
+    fn quantity() {
         let hours = Sut::Hours(Quantity::sample());
         let days = Sut::Days(Quantity::sample_other());
-        assert_eq!(*hours, Quantity::sample());
-        assert_eq!(*days, Quantity::sample_other());
+        assert_eq!(hours.quantity(), Some(Quantity::sample()));
+        assert_eq!(days.quantity(), Some(Quantity::sample_other()));
+    }
+
+    #[test]
+    fn dates() {
+        let sut = Sut::Dates {
+            days_off: DaysOff::sample(),
+            listed: false,
+        };
+        assert_eq!(sut.granularity(), None);
+        assert_eq!(sut.quantity(), None);
+        assert_eq!(sut.days_off(), Some(&DaysOff::sample()));
+        assert_eq!(sut.listed_days_off(), None);
+    }
+
+    #[test]
+    fn listed_dates() {
+        let sut = Sut::Dates {
+            days_off: DaysOff::sample_other(),
+            listed: true,
+        };
+        assert_eq!(sut.listed_days_off(), Some(&DaysOff::sample_other()));
+        assert_eq!(Sut::sample().listed_days_off(), None);
     }
 }
@@ -1,4 +1,4 @@
-use crate::{Currency, Granularity, Language, Version};
+use crate::{Cadence, Currency, Date, Granularity, Language, Version};
 use thiserror::Error as ThisError;
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -41,6 +41,71 @@ pub enum Error {
         service_fees_granularity: Granularity,
     },
 
+    /// Dates off were given, but the primary service is not billed per day
+    /// or per hour.
+    #[error(
+        "Dates off can only be deducted from a primary service billed per day or per hour, but it is billed per '{service_fees_granularity}', specify a quantity of time off instead."
+    )]
+    DaysOffRequireDailyOrHourlyService {
+        /// The granularity of the primary service.
+        service_fees_granularity: Granularity,
+    },
+
+    /// A retainer was configured, but the primary service is not billed per
+    /// day or per hour, so no work beyond the included amount can be counted.
+    #[error(
+        "A retainer requires the primary service to be billed per day or per hour, but it is billed per '{service_fees_granularity}'."
+    )]
+    RetainerRequiresDailyOrHourlyRate {
+        /// The granularity of the primary service.
+        service_fees_granularity: Granularity,
+    },
+
+    /// A timesheet was given, but the primary service is not billed per day
+    /// or per hour.
+    #[error(
+        "A timesheet can only be billed for a primary service billed per day or per hour, but it is billed per '{service_fees_granularity}'."
+    )]
+    TimesheetRequiresDailyOrHourlyService {
+        /// The granularity of the primary service.
+        service_fees_granularity: Granularity,
+    },
+
+    /// A timesheet was given together with time off, the timesheet already
+    /// records the hours actually worked.
+    #[error("A timesheet cannot be combined with time off, only list the hours worked in it.")]
+    TimesheetWithTimeOff,
+
+    /// A timesheet was given for an expenses invoice.
+    #[error("A timesheet can only be billed on service or combined invoices, not on expenses.")]
+    TimesheetForExpenses,
+
+    /// A timesheet row is dated outside of the invoiced period.
+    #[error(
+        "Timesheet has hours worked at {date}, outside of the invoiced period {start} to {end}"
+    )]
+    TimesheetEntryOutsidePeriod {
+        /// The date of the row outside of the period.
+        date: Date,
+        /// The first date of the invoiced period.
+        start: Date,
+        /// The last date of the invoiced period.
+        end: Date,
+    },
+
+    /// The rate of the primary service changes within the invoiced period.
+    #[error(
+        "The rate changes within the invoiced period {start} to {end}, at {effective_from}, rates must become effective at the start of a period."
+    )]
+    RateChangeWithinPeriod {
+        /// The date the new rate becomes effective.
+        effective_from: Date,
+        /// The first date of the invoiced period.
+        start: Date,
+        /// The last date of the invoiced period.
+        end: Date,
+    },
+
     /// Granularity too coarse,
     #[error(
         "Granularity too coarse '{granularity}', max is: '{max_granularity}', for period: '{target_period}'"
@@ -58,6 +123,28 @@ pub enum Error {
     #[error("Cannot invoice for month when cadence is bi-weekly")]
     CannotInvoiceForMonthWhenCadenceIsBiWeekly,
 
+    /// A whole number of periods of the granularity does not fit in the
+    /// periods of the cadence, e.g. weeks in months.
+    #[error(
+        "Cannot invoice per {granularity} when cadence is {cadence}, pick a rate whose periods fit in the invoicing periods"
+    )]
+    GranularityDoesNotFitCadence {
+        /// The granularity of the rate.
+        granularity: Granularity,
+        /// The invoicing cadence.
+        cadence: Cadence,
+    },
+
+    /// The period label is of a kind which does not match the cadence, e.g.
+    /// an ISO week for a monthly cadence.
+    #[error("Period '{label}' does not match cadence {cadence}")]
+    PeriodLabelDoesNotMatchCadence {
+        /// The period label which was provided.
+        label: String,
+        /// The invoicing cadence.
+        cadence: Cadence,
+    },
+
     /// Cannot invoice for fortnight when cadence is monthly.
     #[error("Cannot invoice for fortnight when cadence is monthly")]
     CannotInvoiceForFortnightWhenCadenceIsMonthly,
@@ -191,6 +278,38 @@ pub enum Error {
         target_period: String,
     },
 
+    /// Every expense of the target period is recorded as not billable.
+    #[error(
+        "Target period {target_period} has no billable expenses, every expense of the period is recorded as not billable."
+    )]
+    TargetPeriodHasNoBillableExpenses {
+        /// Target period label without billable expenses.
+        target_period: String,
+    },
+
+    /// Expenses are invoiced while clients are registered besides the default
+    /// client, since recorded expenses are not kept per client.
+    #[error(
+        "Cannot invoice expenses with {clients} clients, expenses are recorded for all clients alike and would be billed to each of them, remove the other clients with `klirr data client remove`."
+    )]
+    ExpensesWithSeveralClients {
+        /// Number of clients, the default client included.
+        clients: usize,
+    },
+
+    /// No expense is recorded at the index of the period.
+    #[error(
+        "Target period {target_period} has no expense #{index}, it has {count} expenses, list them with `klirr data expenses list`."
+    )]
+    ExpenseIndexOutOfRange {
+        /// Target period label of the expense.
+        target_period: String,
+        /// The 1-based index of the expense.
+        index: usize,
+        /// The number of expenses recorded for the period.
+        count: usize,
+    },
+
     /// Failed to parse year
     #[error("Failed to parse year: {invalid_string}")]
     FailedToParseYear {
@@ -291,6 +410,15 @@ pub enum Error {
         invalid_string: String,
     },
 
+    /// Failed to parse PaymentTerms from a string, e.g. when the format is incorrect.
+    #[error(
+        "Failed to parse payment terms from string: {invalid_string}, expected e.g. 'Net 30', 'Net 30 EOM', 'Due on receipt', 'Day 15 next month' or '2/10 Net 30'"
+    )]
+    FailedToParsePaymentTerms {
+        /// String that failed payment-terms parsing.
+        invalid_string: String,
+    },
+
     /// Failed to find the localization file for a specific language.
     #[error("Failed to find the localization file for language: {language}")]
     L10nNotFound {
@@ -323,6 +451,68 @@ pub enum Error {
         reason: String,
     },
 
+    /// The work schedule of the service fees is invalid.
+    #[error("Invalid work schedule: {reason}")]
+    InvalidWorkSchedule {
+        /// Why the work schedule is invalid, e.g. it has no working days.
+        reason: String,
+    },
+
+    /// Failed to parse dates off, e.g. `2025-05-12..2025-05-16`.
+    #[error("Invalid days off {invalid_string:?}: {reason}")]
+    InvalidDaysOff {
+        /// String that failed to parse as dates off.
+        invalid_string: String,
+        /// Why the string is not valid dates off.
+        reason: String,
+    },
+
+    /// Failed to parse a timesheet CSV file.
+    #[error("Invalid timesheet: {reason}")]
+    InvalidTimesheet {
+        /// Why the timesheet is not valid, e.g. the row that failed to parse.
+        reason: String,
+    },
+
+    /// Failed to read expenses from a CSV file, e.g. a column is missing.
+    #[error("Invalid expenses CSV: {reason}")]
+    InvalidExpenseCsv {
+        /// Why the CSV file is not valid, e.g. the missing column.
+        reason: String,
+    },
+
+    /// Failed to parse the unit of the distance of a mileage expense.
+    #[error("Invalid distance unit: '{invalid_string}', expected 'km' or 'mi'")]
+    InvalidDistanceUnit {
+        /// Raw text that failed parsing.
+        invalid_string: String,
+    },
+
+    /// A per diem expense or rate is not valid, e.g. more meals are provided
+    /// than there are days.
+    #[error("Invalid per diem: {reason}")]
+    InvalidPerDiem {
+        /// Why the per diem is not valid.
+        reason: String,
+    },
+
+    /// No per diem rate is configured for the destination of a per diem
+    /// expense.
+    #[error(
+        "No per diem rate for destination '{destination}', add one to 'per_diem_rates.ron' in your data directory"
+    )]
+    PerDiemRateNotFound {
+        /// The destination without a rate.
+        destination: String,
+    },
+
+    /// A receipt is neither a PDF nor an image the invoice can show.
+    #[error("Unsupported receipt file: '{path}', expected a PDF, PNG, JPEG, GIF or SVG file")]
+    UnsupportedReceiptFormat {
+        /// The path of the receipt.
+        path: String,
+    },
+
     /// Too many `payment_method_overrides` entries supplied; the Typst
     /// layout has only two slots (IBAN slot + BIC slot) that overrides
     /// can occupy.
@@ -370,6 +560,122 @@ pub enum Error {
         /// Underlying response parse error message.
         underlying: String,
     },
+
+    /// Failed to parse a client id, e.g. when it contains invalid characters.
+    #[error("Invalid client id: '{invalid_string}', {reason}")]
+    InvalidClientId {
+        /// String that failed client-id parsing.
+        invalid_string: String,
+        /// Parsing failure reason.
+        reason: String,
+    },
+
+    /// The client is not in the client registry.
+    #[error("Unknown client: '{client}', list registered clients with `klirr data client list`")]
+    UnknownClient {
+        /// Id of the client that was not found.
+        client: String,
+    },
+
+    /// A client with the same id is already in the client registry.
+    #[error("Client '{client}' already exists, remove it first with `klirr data client remove`")]
+    ClientAlreadyExists {
+        /// Id of the already registered client.
+        client: String,
+    },
+
+    /// A registered client has no invoice number offset.
+    #[error("Client '{client}' has no invoice number offset")]
+    MissingInvoiceNumberOffsetForClient {
+        /// Id of the client missing an offset.
+        client: String,
+    },
+
+    /// The invoice number precedes the invoice number offset, so its period
+    /// cannot be determined.
+    #[error("Invoice number {number} precedes the invoice number offset {offset}")]
+    InvoiceNumberPrecedesOffset {
+        /// The invoice number which was looked up.
+        number: u16,
+        /// The invoice number offset.
+        offset: u16,
+    },
+
+    /// A line selected for a credit note does not exist on the credited invoice.
+    #[error("Invalid credit note line {line}, the invoice has {line_count} lines")]
+    InvalidCreditNoteLine {
+        /// The 1-based line number which was selected.
+        line: usize,
+        /// Number of lines on the credited invoice.
+        line_count: usize,
+    },
+
+    /// No invoice with the number has been recorded in the ledger.
+    #[error("No invoice with number {number} in the ledger")]
+    InvoiceNotInLedger {
+        /// The invoice number which was looked up.
+        number: u16,
+    },
+
+    /// Several invoices in the ledger have the number, e.g. a services and an
+    /// expenses invoice of different periods.
+    #[error(
+        "Several invoices with number {number} in the ledger, of kinds: {kinds}, specify the kind"
+    )]
+    AmbiguousInvoiceInLedger {
+        /// The invoice number which was looked up.
+        number: u16,
+        /// The kinds of the invoices with the number.
+        kinds: String,
+    },
+
+    /// The credited invoice, rebuilt from the current data, differs from the
+    /// invoice recorded in the ledger, e.g. because its expenses or rates were
+    /// changed after it was issued.
+    #[error(
+        "Invoice {number} was issued with a grand total of {issued}, but rebuilding it from the current data gives {rebuilt}, undo the changes made since it was issued to credit it"
+    )]
+    CreditedInvoiceChanged {
+        /// The number of the credited invoice.
+        number: u16,
+        /// The grand total of the issued invoice, as recorded in the ledger.
+        issued: String,
+        /// The grand total of the invoice rebuilt from the current data.
+        rebuilt: String,
+    },
+
+    /// A partial payment was recorded without an amount.
+    #[error("The amount of a partial payment must be specified")]
+    MissingPaymentAmount,
+
+    /// A payment was recorded with an amount of zero or less, which would
+    /// increase or not change the outstanding amount.
+    #[error("The amount of a payment must be positive, got: {amount}")]
+    NonPositivePaymentAmount {
+        /// The amount of the payment.
+        amount: String,
+    },
+
+    /// A payment reminder was requested for an invoice which is not overdue,
+    /// i.e. it is paid, written off or not yet past its due date.
+    #[error("Invoice {number} is not overdue, it was due {due_date}")]
+    InvoiceNotOverdue {
+        /// The invoice number of the reminder.
+        number: u16,
+        /// The due date of the invoice.
+        due_date: Date,
+    },
+
+    /// A recurring item ends before it starts, so it is never billed.
+    #[error("Recurring item '{name}' ends at {end}, before it starts at {start}")]
+    RecurringItemEndsBeforeStart {
+        /// The name of the recurring item.
+        name: String,
+        /// The first date the item is billed for.
+        start: Date,
+        /// The last date the item is billed for.
+        end: Date,
+    },
 }
 
 impl Error {
@@ -552,6 +858,16 @@ impl From<klirr_foundation::ModelError> for Error {
                 invalid_string,
                 reason,
             },
+            klirr_foundation::ModelError::InvalidWorkSchedule { reason } => {
+                Self::InvalidWorkSchedule { reason }
+            }
+            klirr_foundation::ModelError::InvalidDaysOff {
+                invalid_string,
+                reason,
+            } => Self::InvalidDaysOff {
+                invalid_string,
+                reason,
+            },
         }
     }
 }
@@ -0,0 +1,499 @@
+use crate::{
+    Cadence, Currency, Date, Error, ExpensedPeriods, HasSample, Item, Result,
+    normalize_period_end_date_for_cadence,
+};
+use bon::Builder;
+use getset::Getters;
+use indexmap::IndexMap;
+use log::warn;
+
+/// The sign of the amounts of expenses in an expense CSV file, rows with the
+/// opposite sign, e.g. incoming payments in a bank export, are skipped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExpenseSign {
+    /// Expenses have positive amounts.
+    #[default]
+    Positive,
+
+    /// Expenses have negative amounts, as outgoing payments in many bank
+    /// exports, and are read as their absolute value.
+    Negative,
+}
+
+/// How expenses are read from a CSV file with a header row, e.g. a bank
+/// export, mapping each field of an expense to a column by its header.
+///
+/// Columns are found by header, in any order, and other columns are ignored.
+/// The `quantity` column is optional, defaulting to `1`, as is the `vat`
+/// column, defaulting to the VAT rate of the invoice. The `currency` column
+/// may be left out if a `currency` is set.
+///
+/// Expenses have positive amounts unless `expense_sign` says otherwise, rows
+/// with the opposite sign are skipped with a warning, see [`ExpenseSign`].
+/// Dates are read as `2025-05-31` unless a `date_format` is set, and numbers
+/// with a point as decimal separator unless a `decimal_separator` is set.
+///
+/// Rows the same as an earlier row of the file are skipped with a warning,
+/// e.g. when an export lists a transaction twice.
+#[derive(Clone, Debug, PartialEq, Eq, Builder, Getters)]
+pub struct ExpenseCsvFormat {
+    /// The field delimiter, e.g. `b';'` for many bank exports.
+    #[builder(default = b',')]
+    #[getset(get = "pub")]
+    delimiter: u8,
+
+    /// Header of the column with the name of the expense.
+    #[builder(into, default = "name".to_owned())]
+    #[getset(get = "pub")]
+    name_column: String,
+
+    /// Header of the column with the cost per item, **excluding VAT**.
+    #[builder(into, default = "unit_price".to_owned())]
+    #[getset(get = "pub")]
+    unit_price_column: String,
+
+    /// Header of the column with the currency of the expense.
+    #[builder(into, default = "currency".to_owned())]
+    #[getset(get = "pub")]
+    currency_column: String,
+
+    /// Header of the optional column with the quantity of the expense.
+    #[builder(into, default = "quantity".to_owned())]
+    #[getset(get = "pub")]
+    quantity_column: String,
+
+    /// Header of the column with the date of the expense, e.g. `2025-05-31`.
+    #[builder(into, default = "transaction_date".to_owned())]
+    #[getset(get = "pub")]
+    transaction_date_column: String,
+
+    /// Header of the optional column with the VAT rate of the expense.
+    #[builder(into, default = "vat".to_owned())]
+    #[getset(get = "pub")]
+    vat_column: String,
+
+    /// The currency of every expense if the file has no currency column.
+    #[getset(get = "pub")]
+    currency: Option<Currency>,
+
+    /// The sign of the amounts of expenses, positive by default.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    expense_sign: ExpenseSign,
+
+    /// The [`chrono` format] of the dates, e.g. `%d.%m.%Y` for `31.05.2025`,
+    /// if not `2025-05-31`.
+    ///
+    /// [`chrono` format]: chrono::format::strftime
+    #[builder(into)]
+    #[getset(get = "pub")]
+    date_format: Option<String>,
+
+    /// The decimal separator of amounts, quantities and VAT rates, e.g. `,`
+    /// for `1.234,50`, defaults to `.`. With any other separator than `.`,
+    /// points and spaces are read as thousands separators and ignored.
+    #[builder(default = '.')]
+    #[getset(get = "pub")]
+    decimal_separator: char,
+}
+
+impl Default for ExpenseCsvFormat {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl ExpenseCsvFormat {
+    /// Parses expenses from CSV in this format, quoted values may contain the
+    /// delimiter, e.g.:
+    ///
+    /// ```text
+    /// name,unit_price,currency,quantity,transaction_date
+    /// "Sandwich, vegan",6,EUR,1,2025-05-31
+    /// Lunch,11,GBP,2,2025-06-02
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidExpenseCsv`] if the CSV cannot be read or a
+    /// required column is missing, or [`Error::InvalidExpenseItem`] if a row
+    /// cannot be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    ///
+    /// let csv = "Date;Text;Amount\n2025-05-31;Sandwich, vegan;-6.5\n";
+    /// let format = ExpenseCsvFormat::builder()
+    ///     .delimiter(b';')
+    ///     .name_column("Text")
+    ///     .unit_price_column("Amount")
+    ///     .transaction_date_column("Date")
+    ///     .currency(Currency::EUR)
+    ///     .expense_sign(ExpenseSign::Negative)
+    ///     .build();
+    /// let expenses = format.parse(csv.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(expenses[0].name(), "Sandwich, vegan");
+    /// assert_eq!(expenses[0].unit_price().to_string(), "6.5");
+    /// ```
+    pub fn parse(&self, reader: impl std::io::Read) -> Result<Vec<Item>> {
+        let invalid_csv = |e: csv::Error| Error::InvalidExpenseCsv {
+            reason: e.to_string(),
+        };
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let headers = reader.headers().map_err(invalid_csv)?.clone();
+        let column = |header: &str| headers.iter().position(|h| h == header);
+        let required_column = |header: &str| {
+            column(header).ok_or_else(|| Error::InvalidExpenseCsv {
+                reason: format!(
+                    "missing column '{header}', found columns: {}",
+                    headers.iter().collect::<Vec<_>>().join(", ")
+                ),
+            })
+        };
+        let name = required_column(&self.name_column)?;
+        let unit_price = required_column(&self.unit_price_column)?;
+        let transaction_date = required_column(&self.transaction_date_column)?;
+        let currency = match self.currency {
+            Some(_) => column(&self.currency_column),
+            None => Some(required_column(&self.currency_column)?),
+        };
+        let quantity = column(&self.quantity_column);
+        let vat = column(&self.vat_column);
+        let default_currency = self.currency.map(|c| c.to_string()).unwrap_or_default();
+
+        let mut expenses: Vec<Item> = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(invalid_csv)?;
+            let line = record.position().map(|p| p.line()).unwrap_or_default();
+            let source = format!(
+                "line {line}: {}",
+                record.iter().collect::<Vec<_>>().join(",")
+            );
+            let field = |index: usize| record.get(index).unwrap_or_default();
+            let number = |index: usize| self.number(field(index));
+            let amount = number(unit_price);
+            let amount = match (self.expense_sign, amount.strip_prefix('-')) {
+                (ExpenseSign::Positive, None) => amount.as_str(),
+                (ExpenseSign::Negative, Some(absolute)) => absolute,
+                _ => {
+                    warn!("Skipping {source}, its amount has the sign of income");
+                    continue;
+                }
+            };
+            let expense = Item::from_fields(
+                &source,
+                [
+                    field(name),
+                    amount,
+                    currency.map_or(default_currency.as_str(), field),
+                    &quantity.map_or("1".to_owned(), number),
+                    &self.date(&source, field(transaction_date))?,
+                ],
+                vat.map(number).filter(|vat| !vat.is_empty()).as_deref(),
+            )?;
+            if expenses.contains(&expense) {
+                warn!("Skipping {source}, the same as an earlier row of the file");
+                continue;
+            }
+            expenses.push(expense);
+        }
+        Ok(expenses)
+    }
+
+    /// Reads `value` with a point as decimal separator.
+    fn number(&self, value: &str) -> String {
+        if self.decimal_separator == '.' {
+            return value.to_owned();
+        }
+        value
+            .chars()
+            .filter(|c| *c != '.' && !c.is_whitespace())
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect()
+    }
+
+    /// Reads the date `value` of the row `source` as `2025-05-31`.
+    fn date(&self, source: &str, value: &str) -> Result<String> {
+        let Some(date_format) = &self.date_format else {
+            return Ok(value.to_owned());
+        };
+        chrono::NaiveDate::parse_from_str(value, date_format)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .map_err(Error::invalid_expense_item(source, "transaction_date"))
+    }
+}
+
+/// Expenses read from a CSV file, each placed in the invoice period of its
+/// transaction date, split into those to record and those already recorded.
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+pub struct ExpenseImport {
+    /// The expenses to record, keyed by period-end date.
+    #[getset(get = "pub")]
+    new_expenses: IndexMap<Date, Vec<Item>>,
+
+    /// The expenses already recorded for their period, the same except for
+    /// the quantity, which are skipped, keyed by period-end date.
+    #[getset(get = "pub")]
+    duplicates: IndexMap<Date, Vec<Item>>,
+}
+
+impl ExpenseImport {
+    /// Places each of `expenses` in the period of `cadence` its transaction
+    /// date falls in, skipping those already in `expensed_periods`, with a
+    /// warning if recorded with another quantity.
+    pub fn new(
+        expenses: impl IntoIterator<Item = Item>,
+        cadence: Cadence,
+        expensed_periods: &ExpensedPeriods,
+    ) -> Result<Self> {
+        let mut import = Self::default();
+        for expense in expenses {
+            let period_end_date =
+                normalize_period_end_date_for_cadence(*expense.transaction_date(), cadence)?;
+            let target = match expensed_periods.find_expense(&period_end_date, &expense) {
+                Some(recorded) => {
+                    if recorded.quantity() != expense.quantity() {
+                        warn!(
+                            "Skipping {expense}, recorded for the period ending {period_end_date} with quantity {}, record it by hand if it is another expense",
+                            recorded.quantity()
+                        );
+                    }
+                    &mut import.duplicates
+                }
+                None => &mut import.new_expenses,
+            };
+            target.entry(period_end_date).or_default().push(expense);
+        }
+        import.new_expenses.sort_keys();
+        import.duplicates.sort_keys();
+        Ok(import)
+    }
+
+    /// The number of expenses to record.
+    pub fn new_count(&self) -> usize {
+        self.new_expenses.values().map(Vec::len).sum()
+    }
+
+    /// The number of expenses skipped as already recorded.
+    pub fn duplicate_count(&self) -> usize {
+        self.duplicates.values().map(Vec::len).sum()
+    }
+}
+
+impl HasSample for ExpenseImport {
+    fn sample() -> Self {
+        Self::new(
+            [Item::sample_expense_coffee()],
+            Cadence::Monthly,
+            &ExpensedPeriods::default(),
+        )
+        .expect("valid sample import")
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            [Item::sample_expense_breakfast()],
+            Cadence::Monthly,
+            &ExpensedPeriods::sample(),
+        )
+        .expect("valid sample import")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Quantity, UnitPrice, Vat};
+    use rust_decimal::dec;
+    use std::str::FromStr;
+    use test_log::test;
+
+    type Sut = ExpenseImport;
+
+    fn d(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn parse_default_format_with_quoted_name_and_optional_columns() {
+        let csv = "\
+transaction_date,name,unit_price,currency,vat
+2025-05-31,\"Sandwich, vegan\",6,EUR,
+2025-06-02,Hotel,90,SEK,12
+";
+        let expenses = ExpenseCsvFormat::default().parse(csv.as_bytes()).unwrap();
+        assert_eq!(
+            expenses,
+            vec![
+                Item::builder()
+                    .name("Sandwich, vegan".to_owned())
+                    .unit_price(UnitPrice::from(dec!(6)))
+                    .currency(Currency::EUR)
+                    .quantity(Quantity::ONE)
+                    .transaction_date(d("2025-05-31"))
+                    .build(),
+                Item::builder()
+                    .name("Hotel".to_owned())
+                    .unit_price(UnitPrice::from(dec!(90)))
+                    .currency(Currency::SEK)
+                    .quantity(Quantity::ONE)
+                    .transaction_date(d("2025-06-02"))
+                    .vat(Vat::from_percent(dec!(12)).unwrap())
+                    .build(),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_skips_amounts_with_sign_of_income() {
+        let csv = "\
+name,unit_price,currency,transaction_date
+Coffee,-2.5,EUR,2025-05-31
+Refund,4,EUR,2025-05-31
+";
+        let positive = ExpenseCsvFormat::default().parse(csv.as_bytes()).unwrap();
+        assert_eq!(
+            positive.iter().map(Item::name).collect::<Vec<_>>(),
+            vec!["Refund"]
+        );
+
+        let negative = ExpenseCsvFormat::builder()
+            .expense_sign(ExpenseSign::Negative)
+            .build()
+            .parse(csv.as_bytes())
+            .unwrap();
+        assert_eq!(
+            negative.iter().map(Item::name).collect::<Vec<_>>(),
+            vec!["Coffee"]
+        );
+        assert_eq!(*negative[0].unit_price(), UnitPrice::from(dec!(2.5)));
+    }
+
+    #[test]
+    fn parse_reads_dates_and_decimals_in_given_format() {
+        let csv = "\
+Datum;Text;Belopp;Antal
+31.05.2025;Hotel;1.234,50;1,5
+02.06.2025;Lunch;-11,25;1
+";
+        let expenses = ExpenseCsvFormat::builder()
+            .delimiter(b';')
+            .name_column("Text")
+            .unit_price_column("Belopp")
+            .quantity_column("Antal")
+            .transaction_date_column("Datum")
+            .currency(Currency::SEK)
+            .date_format("%d.%m.%Y")
+            .decimal_separator(',')
+            .build()
+            .parse(csv.as_bytes())
+            .unwrap();
+        assert_eq!(
+            expenses,
+            vec![
+                Item::builder()
+                    .name("Hotel".to_owned())
+                    .unit_price(UnitPrice::from(dec!(1234.50)))
+                    .currency(Currency::SEK)
+                    .quantity(Quantity::from(dec!(1.5)))
+                    .transaction_date(d("2025-05-31"))
+                    .build(),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_fails_on_date_not_in_given_format() {
+        let csv = "name,unit_price,currency,transaction_date\nCoffee,2.5,EUR,2025-05-31\n";
+        let result = ExpenseCsvFormat::builder()
+            .date_format("%d.%m.%Y")
+            .build()
+            .parse(csv.as_bytes());
+        assert!(
+            matches!(result, Err(Error::InvalidExpenseItem { reason, .. }) if reason.contains("transaction_date"))
+        );
+    }
+
+    #[test]
+    fn parse_skips_rows_repeated_in_file() {
+        let csv = "\
+name,unit_price,currency,quantity,transaction_date
+Coffee,2.5,EUR,1,2025-05-31
+Coffee,2.5,EUR,1,2025-05-31
+Coffee,2.5,EUR,2,2025-05-31
+";
+        let expenses = ExpenseCsvFormat::default().parse(csv.as_bytes()).unwrap();
+        assert_eq!(
+            expenses
+                .iter()
+                .map(|expense| **expense.quantity())
+                .collect::<Vec<_>>(),
+            vec![dec!(1), dec!(2)]
+        );
+    }
+
+    #[test]
+    fn parse_fails_on_missing_column() {
+        let csv = "name,unit_price,transaction_date\nCoffee,2.5,2025-05-31\n";
+        let result = ExpenseCsvFormat::default().parse(csv.as_bytes());
+        assert!(
+            matches!(result, Err(Error::InvalidExpenseCsv { reason }) if reason.contains("'currency'"))
+        );
+    }
+
+    #[test]
+    fn parse_fails_on_invalid_row_with_its_line() {
+        let csv = "name,unit_price,currency,transaction_date\nCoffee,2.5,EUR,2025-05-31\nLunch,eleven,EUR,2025-05-31\n";
+        let result = ExpenseCsvFormat::default().parse(csv.as_bytes());
+        assert!(
+            matches!(result, Err(Error::InvalidExpenseItem { invalid_string, .. }) if invalid_string.starts_with("line 3:"))
+        );
+    }
+
+    #[test]
+    fn new_places_expenses_in_period_of_transaction_date_and_skips_recorded() {
+        let recorded = ExpensedPeriods::sample();
+        let in_june = Item::from_str("Lunch,11,GBP,2,2025-06-02").unwrap();
+        let mut breakfast_again = Item::sample_expense_breakfast();
+        breakfast_again.set_quantity(Quantity::from(dec!(3)));
+        let sut = Sut::new(
+            [
+                in_june.clone(),
+                Item::sample_expense_coffee(),
+                breakfast_again.clone(),
+            ],
+            Cadence::Monthly,
+            &recorded,
+        )
+        .unwrap();
+        assert_eq!(
+            *sut.new_expenses(),
+            IndexMap::from([
+                (d("2025-05-31"), vec![Item::sample_expense_coffee()]),
+                (d("2025-06-30"), vec![in_june]),
+            ])
+        );
+        assert_eq!(
+            *sut.duplicates(),
+            IndexMap::from([(d("2025-05-31"), vec![breakfast_again])])
+        );
+        assert_eq!(sut.new_count(), 2);
+        assert_eq!(sut.duplicate_count(), 1);
+    }
+}
@@ -0,0 +1,502 @@
+use crate::{Currency, Date, Decimal, Error, HasSample, Item, Quantity, Result, UnitPrice};
+use bon::Builder;
+use derive_more::Display;
+use getset::Getters;
+use rust_decimal::dec;
+use serde::Deserialize;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// The unit of the quantity of a line item, rendered after the quantity on
+/// the invoice, e.g. `120 km`, localized by [`crate::L10nQuantityUnits`].
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuantityUnit {
+    /// Distance driven in kilometers.
+    Kilometers,
+    /// Distance driven in miles.
+    Miles,
+    /// Days of a daily allowance.
+    Days,
+    /// Breakfasts provided, deducted from a daily allowance.
+    Breakfasts,
+    /// Lunches provided, deducted from a daily allowance.
+    Lunches,
+    /// Dinners provided, deducted from a daily allowance.
+    Dinners,
+}
+
+/// The unit of the distance of a mileage expense.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DistanceUnit {
+    /// Kilometers, parsed from `"km"`.
+    Kilometers,
+    /// Miles, parsed from `"mi"`.
+    Miles,
+}
+
+impl FromStr for DistanceUnit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "km" | "kilometers" => Ok(Self::Kilometers),
+            "mi" | "miles" => Ok(Self::Miles),
+            _ => Err(Error::InvalidDistanceUnit {
+                invalid_string: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<DistanceUnit> for QuantityUnit {
+    fn from(unit: DistanceUnit) -> Self {
+        match unit {
+            DistanceUnit::Kilometers => Self::Kilometers,
+            DistanceUnit::Miles => Self::Miles,
+        }
+    }
+}
+
+/// The meals deducted from a daily allowance when provided, each in percent
+/// of the full-day allowance, e.g. `20` for breakfast and `40` for lunch and
+/// dinner in Germany.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Getters, Builder)]
+pub struct MealDeductions {
+    /// Percent of the full-day allowance deducted per breakfast provided.
+    #[getset(get = "pub")]
+    breakfast: Decimal,
+
+    /// Percent of the full-day allowance deducted per lunch provided.
+    #[getset(get = "pub")]
+    lunch: Decimal,
+
+    /// Percent of the full-day allowance deducted per dinner provided.
+    #[getset(get = "pub")]
+    dinner: Decimal,
+}
+
+impl MealDeductions {
+    /// Validates that no deduction is negative and that all meals of a day
+    /// together do not deduct more than the full-day allowance.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidPerDiem`] if the deductions are not valid.
+    pub fn validate(&self) -> Result<()> {
+        let deductions = [self.breakfast, self.lunch, self.dinner];
+        if deductions.iter().any(|percent| percent.is_sign_negative()) {
+            return Err(Error::InvalidPerDiem {
+                reason: "meal deductions cannot be negative".to_owned(),
+            });
+        }
+        if *self.breakfast + *self.lunch + *self.dinner > dec!(100) {
+            return Err(Error::InvalidPerDiem {
+                reason: "meal deductions cannot exceed 100% of the daily allowance".to_owned(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl HasSample for MealDeductions {
+    fn sample() -> Self {
+        Self::builder()
+            .breakfast(dec!(20).into())
+            .lunch(dec!(40).into())
+            .dinner(dec!(40).into())
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .breakfast(dec!(15).into())
+            .lunch(dec!(35).into())
+            .dinner(dec!(35).into())
+            .build()
+    }
+}
+
+/// The number of meals provided during a trip, e.g. by a hotel or a client,
+/// deducted from the daily allowance.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Getters, Builder,
+)]
+pub struct ProvidedMeals {
+    /// Breakfasts provided.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    breakfasts: u16,
+
+    /// Lunches provided.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    lunches: u16,
+
+    /// Dinners provided.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    dinners: u16,
+}
+
+/// The statutory daily allowance of a destination, with the deductions for
+/// provided meals, persisted in `per_diem_rates.ron`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Getters, Builder)]
+pub struct PerDiemRate {
+    /// The destination the allowance is for, e.g. a country code `"DE"`, or a
+    /// city with an allowance of its own, `"FR-Paris"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    destination: String,
+
+    /// The allowance for a full day.
+    #[getset(get = "pub")]
+    full_day: UnitPrice,
+
+    /// The currency of the allowance.
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// The deductions for provided meals.
+    #[getset(get = "pub")]
+    meal_deductions: MealDeductions,
+}
+
+impl HasSample for PerDiemRate {
+    fn sample() -> Self {
+        Self::builder()
+            .destination("DE")
+            .full_day(UnitPrice::from(dec!(28)))
+            .currency(Currency::EUR)
+            .meal_deductions(MealDeductions::sample())
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .destination("SE")
+            .full_day(UnitPrice::from(dec!(290)))
+            .currency(Currency::SEK)
+            .meal_deductions(MealDeductions::sample_other())
+            .build()
+    }
+}
+
+/// The table of per diem rates by destination, persisted in
+/// `per_diem_rates.ron`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, derive_more::Deref)]
+pub struct PerDiemRates(Vec<PerDiemRate>);
+
+impl PerDiemRates {
+    /// Creates a new `PerDiemRates` from `rates`.
+    pub fn new(rates: impl IntoIterator<Item = PerDiemRate>) -> Self {
+        Self(Vec::from_iter(rates))
+    }
+
+    /// Returns the rate of `destination`, compared case-insensitively.
+    ///
+    /// # Errors
+    /// Returns [`Error::PerDiemRateNotFound`] if the table has no rate for
+    /// `destination`.
+    pub fn rate_for(&self, destination: &str) -> Result<&PerDiemRate> {
+        self.0
+            .iter()
+            .find(|rate| rate.destination.eq_ignore_ascii_case(destination))
+            .ok_or_else(|| Error::PerDiemRateNotFound {
+                destination: destination.to_owned(),
+            })
+    }
+
+    /// Validates the meal deductions of every rate, see
+    /// [`MealDeductions::validate`].
+    pub fn validate(&self) -> Result<()> {
+        self.0
+            .iter()
+            .try_for_each(|rate| rate.meal_deductions.validate())
+    }
+}
+
+impl HasSample for PerDiemRates {
+    fn sample() -> Self {
+        Self::new([PerDiemRate::sample()])
+    }
+
+    fn sample_other() -> Self {
+        Self::new([PerDiemRate::sample_other()])
+    }
+}
+
+/// A daily allowance, with the meals provided during the trip and the
+/// deductions for them at the time it was recorded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Getters, Builder)]
+pub struct PerDiem {
+    /// The destination of the trip, see [`PerDiemRate::destination`].
+    #[builder(into)]
+    #[getset(get = "pub")]
+    destination: String,
+
+    /// The meals provided during the trip.
+    #[builder(default)]
+    #[serde(default)]
+    #[getset(get = "pub")]
+    provided_meals: ProvidedMeals,
+
+    /// The deductions for the provided meals.
+    #[getset(get = "pub")]
+    meal_deductions: MealDeductions,
+}
+
+impl PerDiem {
+    /// Validates that no more meals than `days` are provided per kind of
+    /// meal, and the meal deductions, see [`MealDeductions::validate`].
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidPerDiem`] if the per diem is not valid.
+    pub fn validate(&self, days: Quantity) -> Result<()> {
+        self.meal_deductions.validate()?;
+        let meals = [
+            self.provided_meals.breakfasts,
+            self.provided_meals.lunches,
+            self.provided_meals.dinners,
+        ];
+        if meals
+            .iter()
+            .any(|meals| rust_decimal::Decimal::from(*meals) > *days)
+        {
+            return Err(Error::InvalidPerDiem {
+                reason: format!("more meals provided than the {days} days of the trip"),
+            });
+        }
+        Ok(())
+    }
+
+    /// The provided meals with their unit and the deduction, in percent of
+    /// the full-day allowance, per meal, leaving out meals not provided.
+    pub fn deductions(&self) -> Vec<(QuantityUnit, u16, Decimal)> {
+        [
+            (
+                QuantityUnit::Breakfasts,
+                self.provided_meals.breakfasts,
+                self.meal_deductions.breakfast,
+            ),
+            (
+                QuantityUnit::Lunches,
+                self.provided_meals.lunches,
+                self.meal_deductions.lunch,
+            ),
+            (
+                QuantityUnit::Dinners,
+                self.provided_meals.dinners,
+                self.meal_deductions.dinner,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, meals, _)| *meals > 0)
+        .collect()
+    }
+}
+
+/// How an expense is priced when it follows a statutory formula rather than
+/// being a purchase, see [`crate::Item::priced_rows`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExpenseKind {
+    /// Distance driven, the `quantity` of the item is the distance and the
+    /// `unit_price` the rate per distance unit.
+    Mileage(DistanceUnit),
+    /// A daily allowance, the `quantity` of the item is the number of days
+    /// and the `unit_price` the full-day allowance, less deductions for the
+    /// provided meals.
+    PerDiem(PerDiem),
+}
+
+impl ExpenseKind {
+    /// The unit of the quantity of an item of this kind.
+    pub fn unit(&self) -> QuantityUnit {
+        match self {
+            Self::Mileage(unit) => QuantityUnit::from(*unit),
+            Self::PerDiem(_) => QuantityUnit::Days,
+        }
+    }
+}
+
+/// A trip to record a per diem expense for, before its allowance is looked up
+/// in the [`PerDiemRates`], see [`PerDiemTrip::into_item`].
+#[derive(Clone, Debug, PartialEq, Eq, Getters, Builder)]
+pub struct PerDiemTrip {
+    /// Description of the expense, e.g. `"Berlin workshop"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    name: String,
+
+    /// The destination of the trip, see [`PerDiemRate::destination`].
+    #[builder(into)]
+    #[getset(get = "pub")]
+    destination: String,
+
+    /// The number of days of the trip, e.g. `2.5` if the last day is paid
+    /// as a half day.
+    #[getset(get = "pub")]
+    days: Quantity,
+
+    /// The date of the trip.
+    #[getset(get = "pub")]
+    transaction_date: Date,
+
+    /// The meals provided during the trip.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    provided_meals: ProvidedMeals,
+}
+
+impl PerDiemTrip {
+    /// Creates the per diem expense of this trip, with the allowance of its
+    /// destination in `rates`, see [`Item::per_diem`].
+    pub fn into_item(self, rates: &PerDiemRates) -> Result<Item> {
+        Item::per_diem(
+            self.name,
+            &self.destination,
+            self.days,
+            self.transaction_date,
+            self.provided_meals,
+            rates,
+        )
+    }
+}
+
+impl FromStr for PerDiemTrip {
+    type Err = Error;
+
+    /// Parses a string in the format: "name, destination, days,
+    /// transaction_date", optionally followed by the number of provided
+    /// breakfasts, lunches and dinners, e.g. "Berlin workshop, DE, 3,
+    /// 2025-05-14, 2, 1, 0".
+    fn from_str(s: &str) -> Result<Self> {
+        let parts: Vec<&str> = s.split(',').map(str::trim).collect();
+        if parts.len() != 4 && parts.len() != 7 {
+            return Err(Error::InvalidExpenseItem {
+                invalid_string: s.to_string(),
+                reason: "Expected 4 or 7 comma-separated values, on format: \"Berlin workshop, DE, 3, 2025-05-14\", optionally followed by the number of provided breakfasts, lunches and dinners, e.g. \", 2, 1, 0\"".to_string(),
+            });
+        }
+        let days: Quantity = parts[2]
+            .parse::<rust_decimal::Decimal>()
+            .map_err(Error::invalid_expense_item(s, "days"))?
+            .into();
+        let transaction_date =
+            Date::from_str(parts[3]).map_err(Error::invalid_expense_item(s, "transaction_date"))?;
+        let meals = |index: usize, field: &str| {
+            parts
+                .get(index)
+                .map_or(Ok(0), |meals| meals.parse::<u16>())
+                .map_err(Error::invalid_expense_item(s, field))
+        };
+        let provided_meals = ProvidedMeals::builder()
+            .breakfasts(meals(4, "breakfasts")?)
+            .lunches(meals(5, "lunches")?)
+            .dinners(meals(6, "dinners")?)
+            .build();
+        Ok(Self::builder()
+            .name(parts[0])
+            .destination(parts[1])
+            .days(days)
+            .transaction_date(transaction_date)
+            .provided_meals(provided_meals)
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = PerDiemRates;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn rate_for_destination_ignores_case() {
+        let sut = Sut::sample();
+        assert_eq!(sut.rate_for("de").unwrap(), &PerDiemRate::sample());
+        assert_eq!(
+            sut.rate_for("FR"),
+            Err(Error::PerDiemRateNotFound {
+                destination: "FR".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn meal_deductions_cannot_exceed_full_day() {
+        assert!(MealDeductions::sample().validate().is_ok());
+        let too_much = MealDeductions::builder()
+            .breakfast(dec!(40).into())
+            .lunch(dec!(40).into())
+            .dinner(dec!(40).into())
+            .build();
+        assert!(matches!(
+            too_much.validate(),
+            Err(Error::InvalidPerDiem { .. })
+        ));
+    }
+
+    #[test]
+    fn per_diem_cannot_provide_more_meals_than_days() {
+        let per_diem = PerDiem::builder()
+            .destination("DE")
+            .provided_meals(ProvidedMeals::builder().breakfasts(3).build())
+            .meal_deductions(MealDeductions::sample())
+            .build();
+        assert!(per_diem.validate(Quantity::from(dec!(3))).is_ok());
+        assert!(per_diem.validate(Quantity::from(dec!(2))).is_err());
+    }
+
+    #[test]
+    fn distance_unit_from_str() {
+        assert_eq!(
+            DistanceUnit::from_str("KM").unwrap(),
+            DistanceUnit::Kilometers
+        );
+        assert_eq!(DistanceUnit::from_str(" mi").unwrap(), DistanceUnit::Miles);
+        assert!(DistanceUnit::from_str("feet").is_err());
+    }
+
+    #[test]
+    fn per_diem_trip_from_str_with_and_without_meals() {
+        let trip = PerDiemTrip::from_str("Berlin workshop, DE, 3, 2025-05-14, 2, 1, 0").unwrap();
+        assert_eq!(trip.destination(), "DE");
+        assert_eq!(
+            *trip.provided_meals(),
+            ProvidedMeals::builder().breakfasts(2).lunches(1).build()
+        );
+        let trip = PerDiemTrip::from_str("Berlin workshop, DE, 2.5, 2025-05-14").unwrap();
+        assert_eq!(*trip.days(), Quantity::from(dec!(2.5)));
+        assert_eq!(*trip.provided_meals(), ProvidedMeals::default());
+        assert!(PerDiemTrip::from_str("Berlin workshop, DE, 3, 2025-05-14, 2").is_err());
+    }
+
+    #[test]
+    fn per_diem_trip_into_item_uses_rate_of_destination() {
+        let item = PerDiemTrip::from_str("Stockholm, se, 2, 2025-05-14")
+            .unwrap()
+            .into_item(&PerDiemRates::sample_other())
+            .unwrap();
+        assert_eq!(*item.unit_price(), UnitPrice::from(dec!(290)));
+        assert_eq!(*item.currency(), Currency::SEK);
+        assert_eq!(
+            item.kind().as_ref().map(ExpenseKind::unit),
+            Some(QuantityUnit::Days)
+        );
+    }
+}
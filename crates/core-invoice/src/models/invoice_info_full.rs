@@ -1,4 +1,7 @@
-use crate::{Date, FooterText, HasSample, HexColor, InvoiceNumber, PurchaseOrder};
+use crate::{
+    Date, DaysOff, FooterText, HasSample, HexColor, InvoiceNumber, InvoiceReceipt, InvoiceTerms,
+    PurchaseOrder, TimesheetEntry,
+};
 use bon::Builder;
 use getset::Getters;
 use serde::Deserialize;
@@ -13,6 +16,12 @@ pub struct InvoiceInfoFull {
     #[getset(get = "pub")]
     number: InvoiceNumber,
 
+    /// The number as printed on the invoice and used in its file name, e.g.
+    /// `"ACME-0042"`, see [`crate::InvoiceNumberFormat`].
+    #[builder(into)]
+    #[getset(get = "pub")]
+    formatted_number: String,
+
     /// When the payment is due, calculated from the invoice date and payment terms.
     #[getset(get = "pub")]
     invoice_date: Date,
@@ -21,6 +30,11 @@ pub struct InvoiceInfoFull {
     #[getset(get = "pub")]
     due_date: Date,
 
+    /// The payment terms, rendered as localized text, calculated from the
+    /// invoice date and payment terms.
+    #[getset(get = "pub")]
+    terms: InvoiceTerms,
+
     /// A purchase order number associated with this invoice, e.g. `"PO-12345"`
     /// Typically agreed upon between the vendor and client before the
     /// invoice is issued.
@@ -35,14 +49,72 @@ pub struct InvoiceInfoFull {
     #[builder(default)]
     #[getset(get = "pub")]
     emphasize_color_hex: HexColor,
+
+    /// The formatted number of the invoice this credit note reverses, `None`
+    /// for regular invoices.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    credited_invoice: Option<String>,
+
+    /// The original due date of the overdue invoice a payment reminder is
+    /// for, `None` unless this is a payment reminder.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reminded_due_date: Option<Date>,
+
+    /// The dates off during the period, listed on the invoice if time off
+    /// was given as dates to be listed, else empty.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "DaysOff::is_empty")]
+    days_off: DaysOff,
+
+    /// The rows of the timesheet billed on this invoice, itemized on an
+    /// appendix page if the timesheet was to be itemized, else empty.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    timesheet: Vec<TimesheetEntry>,
+
+    /// The receipts of the expenses invoiced, appended as pages or embedded
+    /// as files, see [`crate::ReceiptPlacement`], and attached to the email
+    /// of the invoice.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    receipts: Vec<InvoiceReceipt>,
+}
+
+impl InvoiceInfoFull {
+    /// Turns this into the information of a credit note with `number`,
+    /// printed as `formatted_number`, issued at `date` and reversing the
+    /// invoice this information belongs to.
+    ///
+    /// The due date of a credit note is its issue date, so it is due on
+    /// receipt, without any early payment discount.
+    /// The receipts are not repeated on the credit note.
+    pub fn credit_note(self, number: InvoiceNumber, formatted_number: String, date: Date) -> Self {
+        Self {
+            credited_invoice: Some(self.formatted_number),
+            number,
+            formatted_number,
+            invoice_date: date,
+            due_date: date,
+            terms: InvoiceTerms::due_on_receipt(),
+            receipts: Vec::new(),
+            ..self
+        }
+    }
 }
 
 impl HasSample for InvoiceInfoFull {
     fn sample() -> Self {
         Self::builder()
             .number(InvoiceNumber::sample())
+            .formatted_number(InvoiceNumber::sample().to_string())
             .invoice_date(Date::sample())
             .due_date(Date::sample())
+            .terms(InvoiceTerms::sample())
             .purchase_order(PurchaseOrder::sample())
             .footer_text(FooterText::sample())
             .emphasize_color_hex(HexColor::sample())
@@ -52,8 +124,10 @@ impl HasSample for InvoiceInfoFull {
     fn sample_other() -> Self {
         Self::builder()
             .number(InvoiceNumber::sample_other())
+            .formatted_number(InvoiceNumber::sample_other().to_string())
             .invoice_date(Date::sample_other())
             .due_date(Date::sample_other())
+            .terms(InvoiceTerms::sample_other())
             .purchase_order(PurchaseOrder::sample_other())
             .footer_text(FooterText::sample_other())
             .emphasize_color_hex(HexColor::sample_other())
@@ -80,6 +154,25 @@ mod tests {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
 
+    #[test]
+    fn credit_note_references_credited_invoice() {
+        let sut = Sut::sample().credit_note(
+            InvoiceNumber::from(1),
+            "CN-1".to_owned(),
+            Date::sample_other(),
+        );
+        assert_eq!(*sut.number(), InvoiceNumber::from(1));
+        assert_eq!(sut.formatted_number(), "CN-1");
+        assert_eq!(
+            *sut.credited_invoice(),
+            Some(InvoiceNumber::sample().to_string())
+        );
+        assert_eq!(*sut.invoice_date(), Date::sample_other());
+        assert_eq!(*sut.due_date(), Date::sample_other());
+        assert_eq!(*sut.terms(), InvoiceTerms::due_on_receipt());
+        assert_eq!(sut.purchase_order(), Sut::sample().purchase_order());
+    }
+
     #[test]
     fn test_invoice_info_full_sample() {
         let sample = Sut::sample();
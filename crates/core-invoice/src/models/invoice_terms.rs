@@ -0,0 +1,166 @@
+use crate::{Date, Day, Decimal, HasSample, PaymentTerms, round_amount};
+use bon::Builder;
+use getset::Getters;
+use rust_decimal::dec;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The kind of payment terms of an invoice, which picks the localized text
+/// of the terms, see [`crate::L10nPaymentTerms`].
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentTermsKind {
+    /// See [`PaymentTerms::Net`].
+    Net,
+    /// See [`PaymentTerms::NetEndOfMonth`].
+    NetEndOfMonth,
+    /// See [`PaymentTerms::DueOnReceipt`].
+    DueOnReceipt,
+    /// See [`PaymentTerms::DayOfNextMonth`].
+    DayOfNextMonth,
+    /// See [`PaymentTerms::Discount`].
+    Discount,
+}
+
+/// A discount granted if an invoice is paid early, see
+/// [`crate::DiscountTerms`].
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters)]
+pub struct EarlyPaymentDiscount {
+    /// The discount in percent of the grand total, e.g. `2` for 2%.
+    #[getset(get = "pub")]
+    percent: Decimal,
+
+    /// The number of days after the invoice date the discount is granted
+    /// within, e.g. `10`.
+    #[getset(get = "pub")]
+    days: u16,
+
+    /// The last date the discount is granted.
+    #[getset(get = "pub")]
+    deadline: Date,
+}
+
+impl EarlyPaymentDiscount {
+    /// The discount of `grand_total`, rounded to cents.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let discount = DiscountTerms::sample().early_payment_discount(Date::sample());
+    /// assert_eq!(*discount.amount_of(Decimal::from(dec!(1234.50))), dec!(24.69));
+    /// ```
+    pub fn amount_of(&self, grand_total: Decimal) -> Decimal {
+        round_amount(*grand_total * *self.percent / dec!(100))
+    }
+}
+
+/// The payment terms of an invoice broken down into the parts of its
+/// localized text, derived from [`PaymentTerms`] and the invoice date.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Builder, Getters)]
+pub struct InvoiceTerms {
+    /// Picks the localized text of the terms.
+    #[getset(get = "pub")]
+    kind: PaymentTermsKind,
+
+    /// The net days of the terms, e.g. `30` for `Net 30`, `None` for terms
+    /// without net days.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    days: Option<u16>,
+
+    /// The day of the next month payment is due, `None` for other terms.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    day: Option<Day>,
+
+    /// The discount granted if paid early, `None` for terms without one.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    early_payment_discount: Option<EarlyPaymentDiscount>,
+}
+
+impl InvoiceTerms {
+    /// The terms of `terms` for an invoice issued at `invoice_date`.
+    pub fn new(terms: &PaymentTerms, invoice_date: Date) -> Self {
+        match terms {
+            PaymentTerms::Net(net) => Self::builder()
+                .kind(PaymentTermsKind::Net)
+                .days(**net.due_in())
+                .build(),
+            PaymentTerms::NetEndOfMonth(net) => Self::builder()
+                .kind(PaymentTermsKind::NetEndOfMonth)
+                .days(**net.due_in())
+                .build(),
+            PaymentTerms::DueOnReceipt => Self::due_on_receipt(),
+            PaymentTerms::DayOfNextMonth(day) => Self::builder()
+                .kind(PaymentTermsKind::DayOfNextMonth)
+                .day(*day)
+                .build(),
+            PaymentTerms::Discount(discount) => Self::builder()
+                .kind(PaymentTermsKind::Discount)
+                .days(**discount.net().due_in())
+                .early_payment_discount(discount.early_payment_discount(invoice_date))
+                .build(),
+        }
+    }
+
+    /// Payment due at the invoice date, e.g. of credit notes.
+    pub fn due_on_receipt() -> Self {
+        Self::builder().kind(PaymentTermsKind::DueOnReceipt).build()
+    }
+}
+
+impl HasSample for InvoiceTerms {
+    fn sample() -> Self {
+        Self::new(&PaymentTerms::sample(), Date::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            &PaymentTerms::Discount(crate::DiscountTerms::sample()),
+            Date::sample(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = InvoiceTerms;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn discount_terms_have_early_payment_discount() {
+        let sut = Sut::sample_other();
+        assert_eq!(*sut.kind(), PaymentTermsKind::Discount);
+        assert_eq!(*sut.days(), Some(30));
+        let discount = sut.early_payment_discount().unwrap();
+        assert_eq!(*discount.days(), 10);
+        assert_eq!(
+            *discount.deadline(),
+            Date::sample().advance_days(&crate::DueDays::try_from(10u16).unwrap())
+        );
+    }
+
+    #[test]
+    fn amount_of_rounds_to_cents() {
+        let discount = Sut::sample_other().early_payment_discount().unwrap();
+        assert_eq!(*discount.amount_of(Decimal::from(dec!(1000))), dec!(20));
+        assert_eq!(*discount.amount_of(Decimal::from(dec!(0.25))), dec!(0.01));
+    }
+}
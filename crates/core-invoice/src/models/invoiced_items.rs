@@ -4,11 +4,12 @@ use derive_more::IsVariant;
 use serde::Deserialize;
 use serde::Serialize;
 
-/// The items being invoiced this month, either services or expenses.
+/// The items being invoiced this month, either services, expenses or both
+/// combined into a single invoice.
 #[derive(Clone, Debug, Display, Serialize, Deserialize, IsVariant, PartialEq)]
 pub enum InvoicedItems {
     /// Service invoice mode.
-    #[display("Service {{ time_off: {} }} ", time_off.map(|d| *d).unwrap_or(Quantity::ZERO))]
+    #[display("Service {{ time_off: {} }} ", display_time_off(time_off))]
     Service {
         /// Optional time off deducted from the invoiced service period.
         time_off: Option<TimeOff>,
@@ -16,6 +17,31 @@ pub enum InvoicedItems {
     /// Expenses invoice mode.
     #[display("Expenses")]
     Expenses,
+    /// Services and the expenses of the same period on a single invoice,
+    /// numbered like a service invoice.
+    #[display("Combined {{ time_off: {} }} ", display_time_off(time_off))]
+    Combined {
+        /// Optional time off deducted from the invoiced service period.
+        time_off: Option<TimeOff>,
+    },
+}
+
+fn display_time_off(time_off: &Option<TimeOff>) -> String {
+    time_off
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| Quantity::ZERO.to_string())
+}
+
+impl InvoicedItems {
+    /// The time off to deduct from the services, if any, `None` for
+    /// expenses.
+    pub fn time_off(&self) -> Option<&TimeOff> {
+        match self {
+            Self::Service { time_off } | Self::Combined { time_off } => time_off.as_ref(),
+            Self::Expenses => None,
+        }
+    }
 }
 impl MaybeIsExpenses for InvoicedItems {
     fn is_expenses(&self) -> bool {
@@ -65,5 +91,21 @@ mod tests {
         assert!(!MaybeIsExpenses::is_expenses(&Sut::Service {
             time_off: None
         }));
+        assert!(!MaybeIsExpenses::is_expenses(&Sut::Combined {
+            time_off: None
+        }));
+    }
+
+    #[test]
+    fn time_off() {
+        assert_eq!(Sut::sample().time_off(), Some(&TimeOff::sample()));
+        assert_eq!(
+            Sut::Combined {
+                time_off: Some(TimeOff::sample())
+            }
+            .time_off(),
+            Some(&TimeOff::sample())
+        );
+        assert_eq!(Sut::Expenses.time_off(), None);
     }
 }
@@ -1,8 +1,9 @@
 use std::str::FromStr;
 
 use crate::{
-    Cost, Currency, Date, Day, Decimal, Error, ExchangeRates, HasSample,
-    ItemConvertedIntoTargetCurrency, Month, Quantity, Result, UnitPrice,
+    Cost, Currency, Date, Day, Decimal, DistanceUnit, Error, ExchangeRates, ExpenseKind, HasSample,
+    ItemConvertedIntoTargetCurrency, Month, PerDiem, PerDiemRates, ProvidedMeals, Quantity,
+    QuantityUnit, Receipt, Result, UnitPrice, Vat,
 };
 use bon::Builder;
 use derive_more::Display;
@@ -49,17 +50,58 @@ macro_rules! define_item_struct {
             #[getset(get = "pub")]
             currency: Currency,
             /// The quantity of the expense, e.g. `2.0` for two items
-            #[getset(get = "pub", set)]
+            #[getset(get = "pub", set = "pub(crate)")]
             quantity: $quantity_ty,
             /// The date of the expense, e.g. `2025-05-31`
             #[getset(get = "pub")]
             transaction_date: Date,
+            /// The VAT rate of this item, e.g. a reduced rate for some
+            /// expenses, `None` for the VAT rate of the invoice.
+            #[getset(get = "pub")]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            vat: Option<Vat>,
+            /// How the expense is priced if it follows a statutory formula,
+            /// e.g. mileage or per diem, `None` for a purchase.
+            #[getset(get = "pub")]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            kind: Option<ExpenseKind>,
+            /// The receipts of the expense, PDFs or images stored in the
+            /// data directory, included in the invoice and its email.
+            #[builder(default)]
+            #[getset(get = "pub", set = "pub(crate)")]
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            receipts: Vec<Receipt>,
+            /// The markup of the expense in percent, e.g. `10` for 10%, added
+            /// to its unit price when invoiced, `None` to pass it through at cost.
+            #[getset(get = "pub")]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            markup: Option<Decimal>,
+            /// Whether the expense is invoiced, `false` for an expense recorded
+            /// only for bookkeeping, which is kept but left out of invoices.
+            #[builder(default = true)]
+            #[getset(get = "pub")]
+            #[serde(
+                default = "crate::billable_by_default",
+                skip_serializing_if = "crate::is_billable_by_default"
+            )]
+            billable: bool,
         }
     };
 }
 
 define_item_struct!(pub, Item, Quantity);
 
+/// The default of the billable flag of an [`Item`], expenses are invoiced
+/// unless recorded as not billable.
+pub(crate) fn billable_by_default() -> bool {
+    true
+}
+
+/// Whether `billable` is the default, i.e. need not be serialized.
+pub(crate) fn is_billable_by_default(billable: &bool) -> bool {
+    *billable
+}
+
 impl HasSample for Item {
     fn sample() -> Self {
         Self::sample_expense_coffee()
@@ -167,46 +209,108 @@ impl Item {
             .unit_price(converted_unit_price)
             .quantity(self.quantity)
             .currency(*exchange_rates.target_currency())
+            .maybe_vat(self.vat)
+            .maybe_kind(self.kind)
+            .receipts(self.receipts)
+            .maybe_markup(self.markup)
+            .billable(self.billable)
             .build())
     }
+
+    /// Returns the item with `markup` in percent, see [`Item::markup`].
+    pub fn with_markup(self, markup: Option<Decimal>) -> Self {
+        Self { markup, ..self }
+    }
+
+    /// Returns the item with `billable` as its billable flag, see
+    /// [`Item::billable`].
+    pub fn with_billable(self, billable: bool) -> Self {
+        Self { billable, ..self }
+    }
+
+    /// Returns the item as invoiced, with its markup added to its unit price,
+    /// an item without markup is returned as is.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let item = Item::from_str("Hotel, 90, EUR, 2, 2025-05-31")
+    ///     .unwrap()
+    ///     .with_markup(Some(Decimal::from(dec!(10))));
+    /// let invoiced = item.with_markup_applied();
+    /// assert_eq!(**invoiced.unit_price(), dec!(99));
+    /// assert_eq!(*invoiced.markup(), None);
+    /// ```
+    pub fn with_markup_applied(self) -> Self {
+        match self.markup {
+            Some(markup) => Self {
+                unit_price: UnitPrice::from(*self.unit_price * (dec!(1) + *markup / dec!(100))),
+                markup: None,
+                ..self
+            },
+            None => self,
+        }
+    }
 }
 
 impl FromStr for Item {
     type Err = crate::Error;
 
     /// Parses a string in the format: "name, unit_price, currency, quantity, transaction_date", or
-    /// without spaces after commas, even mixed, e.g. "Coffee, 2.5,EUR, 3.0,2025-05-31".
+    /// without spaces after commas, even mixed, e.g. "Coffee, 2.5,EUR, 3.0,2025-05-31". An
+    /// optional sixth value is the VAT rate of the item in percent, e.g. "Hotel, 90, EUR, 2, 2025-05-31, 12".
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts: Vec<&str> = s.split(',').map(str::trim).collect();
-        if parts.len() != 5 {
+        if !(5..=6).contains(&parts.len()) {
             return Err(Error::InvalidExpenseItem {
                 invalid_string: s.to_string(),
-                reason: "Expected 5 comma-separated values, on format: \"Coffee, 2.5, EUR, 3.0, 2025-05-31\"".to_string(),
+                reason: "Expected 5 or 6 comma-separated values, on format: \"Coffee, 2.5, EUR, 3.0, 2025-05-31\", optionally followed by a VAT rate in percent, e.g. \", 12\"".to_string(),
             });
         }
 
-        let name = parts[0].to_string();
-        let unit_price: UnitPrice = parts[1]
+        Self::from_fields(
+            s,
+            parts[..5].try_into().expect("checked length"),
+            parts.get(5).copied(),
+        )
+    }
+}
+
+impl Item {
+    /// Parses an item from the raw values of its fields, `[name, unit_price,
+    /// currency, quantity, transaction_date]` and an optional VAT rate in
+    /// percent, reporting errors against `source`, e.g. the parsed line.
+    pub(crate) fn from_fields(source: &str, fields: [&str; 5], vat: Option<&str>) -> Result<Self> {
+        let [name, unit_price, currency, quantity, transaction_date] = fields;
+        let name = name.to_string();
+        let unit_price: UnitPrice = unit_price
             .parse::<Decimal>()
-            .map_err(Error::invalid_expense_item(s, "unit_price"))?
+            .map_err(Error::invalid_expense_item(source, "unit_price"))?
             .into();
 
-        let currency =
-            Currency::from_str(parts[2]).map_err(Error::invalid_expense_item(s, "currency"))?;
+        let currency = Currency::from_str(currency)
+            .map_err(Error::invalid_expense_item(source, "currency"))?;
 
-        let quantity: Quantity = parts[3]
+        let quantity: Quantity = quantity
             .parse::<Decimal>()
-            .map_err(Error::invalid_expense_item(s, "quantity"))?
+            .map_err(Error::invalid_expense_item(source, "quantity"))?
             .into();
         if quantity < Quantity::ZERO {
             return Err(Error::InvalidExpenseItem {
-                invalid_string: s.to_string(),
+                invalid_string: source.to_string(),
                 reason: "Quantity cannot be negative".to_string(),
             });
         }
 
-        let transaction_date =
-            Date::from_str(parts[4]).map_err(Error::invalid_expense_item(s, "transaction_date"))?;
+        let transaction_date = Date::from_str(transaction_date)
+            .map_err(Error::invalid_expense_item(source, "transaction_date"))?;
+
+        let vat = vat
+            .map(|vat| Vat::from_str(vat).map_err(Error::invalid_expense_item(source, "vat")))
+            .transpose()?;
 
         Ok(Item::builder()
             .name(name)
@@ -214,8 +318,133 @@ impl FromStr for Item {
             .currency(currency)
             .quantity(quantity)
             .transaction_date(transaction_date)
+            .maybe_vat(vat)
+            .build())
+    }
+}
+
+impl Item {
+    /// Parses a mileage expense in the format: "name, distance, unit, rate,
+    /// currency, transaction_date", where the unit is `km` or `mi` and the
+    /// rate is per unit, e.g. "Client visit, 120, km, 0.25, EUR, 2025-05-12".
+    /// An optional seventh value is the VAT rate of the item in percent.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let item = Item::mileage_from_str("Client visit, 120, km, 0.25, EUR, 2025-05-12").unwrap();
+    /// assert_eq!(**item.quantity(), dec!(120));
+    /// assert_eq!(**item.unit_price(), dec!(0.25));
+    /// assert_eq!(*item.kind(), Some(ExpenseKind::Mileage(DistanceUnit::Kilometers)));
+    /// ```
+    pub fn mileage_from_str(s: &str) -> Result<Self> {
+        let parts: Vec<&str> = s.split(',').map(str::trim).collect();
+        if !(6..=7).contains(&parts.len()) {
+            return Err(Error::InvalidExpenseItem {
+                invalid_string: s.to_string(),
+                reason: "Expected 6 or 7 comma-separated values, on format: \"Client visit, 120, km, 0.25, EUR, 2025-05-12\", optionally followed by a VAT rate in percent, e.g. \", 25\"".to_string(),
+            });
+        }
+        let unit =
+            DistanceUnit::from_str(parts[2]).map_err(Error::invalid_expense_item(s, "unit"))?;
+        let item = Self::from_fields(
+            s,
+            [parts[0], parts[3], parts[4], parts[1], parts[5]],
+            parts.get(6).copied(),
+        )?;
+        Ok(Self {
+            kind: Some(ExpenseKind::Mileage(unit)),
+            ..item
+        })
+    }
+
+    /// Creates a per diem expense of `days` at `destination`, with the
+    /// full-day allowance of the destination in `rates` as unit price and its
+    /// deductions for the `provided_meals`.
+    ///
+    /// # Errors
+    /// Returns [`Error::PerDiemRateNotFound`] if `rates` has no rate for
+    /// `destination`, or [`Error::InvalidPerDiem`] if more meals are provided
+    /// than there are days.
+    pub fn per_diem(
+        name: impl Into<String>,
+        destination: &str,
+        days: Quantity,
+        transaction_date: Date,
+        provided_meals: ProvidedMeals,
+        rates: &PerDiemRates,
+    ) -> Result<Self> {
+        let rate = rates.rate_for(destination)?;
+        let per_diem = PerDiem::builder()
+            .destination(rate.destination().clone())
+            .provided_meals(provided_meals)
+            .meal_deductions(rate.meal_deductions().clone())
+            .build();
+        per_diem.validate(days)?;
+        Ok(Self::builder()
+            .name(name.into())
+            .unit_price(*rate.full_day())
+            .currency(*rate.currency())
+            .quantity(days)
+            .transaction_date(transaction_date)
+            .kind(ExpenseKind::PerDiem(per_diem))
             .build())
     }
+
+    /// The rows this item is billed as, each with the unit of its quantity
+    /// if it has one. A per diem is billed as a row of the full-day allowance
+    /// followed by a row per kind of provided meal, with the deduction as a
+    /// negative unit price, any other item as a single row.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let item = Item::per_diem(
+    ///     "Berlin",
+    ///     "DE",
+    ///     Quantity::from(dec!(3)),
+    ///     "2025-05-14".parse().unwrap(),
+    ///     ProvidedMeals::builder().breakfasts(2).build(),
+    ///     &PerDiemRates::sample(),
+    /// )
+    /// .unwrap();
+    /// let rows = item.priced_rows();
+    ///
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows[0].1, Some(QuantityUnit::Days));
+    /// assert_eq!(**rows[1].0.unit_price(), dec!(-5.6));
+    /// assert_eq!(rows[1].1, Some(QuantityUnit::Breakfasts));
+    /// ```
+    pub fn priced_rows(self) -> Vec<(Self, Option<QuantityUnit>)> {
+        let deductions = match &self.kind {
+            Some(ExpenseKind::PerDiem(per_diem)) => per_diem.deductions(),
+            _ => Vec::new(),
+        };
+        let deduction_rows = deductions
+            .into_iter()
+            .map(|(unit, meals, percent)| {
+                let deduction = Self::builder()
+                    .name(self.name.clone())
+                    .unit_price(UnitPrice::from(-(*self.unit_price * *percent / dec!(100))))
+                    .currency(self.currency)
+                    .quantity(Quantity::from(rust_decimal::Decimal::from(meals)))
+                    .transaction_date(self.transaction_date)
+                    .maybe_vat(self.vat)
+                    .build();
+                (deduction, Some(unit))
+            })
+            .collect::<Vec<_>>();
+        let unit = self.kind.as_ref().map(ExpenseKind::unit);
+        std::iter::once((self, unit))
+            .chain(deduction_rows)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -240,6 +469,19 @@ mod tests {
         assert_ne!(Sut::sample(), Sut::sample_other());
     }
 
+    #[test]
+    fn billable_defaults_to_true_and_is_only_serialized_if_false() {
+        let ron = ron::to_string(&Sut::sample()).unwrap();
+        assert!(!ron.contains("billable"));
+        let deserialized: Sut = ron::from_str(&ron).unwrap();
+        assert!(*deserialized.billable());
+
+        let not_billable = Sut::sample().with_billable(false);
+        let ron = ron::to_string(&not_billable).unwrap();
+        assert!(ron.contains("billable:false"));
+        assert_eq!(ron::from_str::<Sut>(&ron).unwrap(), not_billable);
+    }
+
     #[test]
     fn test_from_str() {
         // N.B. sometimes space after comma, sometimes not.
@@ -254,12 +496,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_str_with_vat() {
+        let sut = Sut::from_str("Hotel, 90, EUR, 2, 2025-05-31, 12").expect("Failed to parse Item");
+        assert_eq!(sut.vat(), &Some(Vat::from_percent(dec!(12)).unwrap()));
+        assert_eq!(
+            Sut::from_str("Hotel, 90, EUR, 2, 2025-05-31")
+                .unwrap()
+                .vat(),
+            &None
+        );
+    }
+
     #[test]
     fn from_str_invalid() {
         let invalid_strings = [
             "Coffee,2.5, EUR,3.0",                          // Missing transaction_date
             "Coffee,2.5, EUR,3.0, invalid_date",            // Invalid transaction_date
-            "Coffee,2.5, EUR,3.0, 2025-05-31, extra",       // Too many parts
+            "Coffee,2.5, EUR,3.0, 2025-05-31, extra",       // Invalid VAT
+            "Coffee,2.5, EUR,3.0, 2025-05-31, 12, extra",   // Too many parts
             "Coffee,invalid_price, EUR,3.0, 2025-05-31",    // Invalid unit_price
             "Coffee,2.5, invalid_currency,3.0, 2025-05-31", // Invalid currency
             "Coffee,2.5, EUR,-3.0, 2025-05-31",             // Negative quantity
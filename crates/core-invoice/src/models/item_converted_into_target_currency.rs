@@ -1,4 +1,4 @@
-use crate::{Cost, HasSample, Item};
+use crate::{Cost, HasSample, Item, Quantity, QuantityUnit};
 use bon::Builder;
 use derive_more::Deref;
 use derive_more::From;
@@ -19,6 +19,32 @@ pub struct ItemConvertedIntoTargetCurrency {
     /// The total cost of the item, calculated as `unit_price * quantity`
     #[getset(get = "pub")]
     total_cost: Cost,
+
+    /// The unit of the quantity, rendered after it, e.g. `km` for mileage,
+    /// `None` for a plain count.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unit: Option<QuantityUnit>,
+}
+
+impl ItemConvertedIntoTargetCurrency {
+    /// Returns this item with negated quantity and total cost, as credited on
+    /// a credit note. The unit price is kept as is.
+    pub fn negated(self) -> Self {
+        let mut in_source_currency = self.in_source_currency;
+        let quantity = Quantity::from(-**in_source_currency.quantity());
+        in_source_currency.set_quantity(quantity);
+        Self {
+            in_source_currency,
+            total_cost: Cost::from(-*self.total_cost),
+            unit: self.unit,
+        }
+    }
+
+    /// Returns this item with `unit` as the unit of its quantity.
+    pub(crate) fn with_unit(self, unit: Option<QuantityUnit>) -> Self {
+        Self { unit, ..self }
+    }
 }
 
 impl HasSample for ItemConvertedIntoTargetCurrency {
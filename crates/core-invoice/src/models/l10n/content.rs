@@ -1,6 +1,6 @@
 use klirr_foundation::ToTypst;
 
-use crate::{L10nClientInfo, L10nInvoiceInfo, L10nLineItems, L10nVendorInfo};
+use crate::{L10nClientInfo, L10nInvoiceInfo, L10nLineItems, L10nPaymentTerms, L10nVendorInfo};
 use bon::Builder;
 use getset::Getters;
 use serde::Deserialize;
@@ -23,6 +23,9 @@ pub struct L10nContent {
     #[getset(get = "pub")]
     line_items: L10nLineItems,
 
+    #[getset(get = "pub")]
+    payment_terms: L10nPaymentTerms,
+
     #[getset(get = "pub")]
     month_names: [String; 12],
 }
@@ -34,6 +37,7 @@ impl L10nContent {
             .invoice_info(L10nInvoiceInfo::english())
             .vendor_info(L10nVendorInfo::english())
             .line_items(L10nLineItems::english())
+            .payment_terms(L10nPaymentTerms::english())
             .month_names([
                 "January".to_string(),
                 "February".to_string(),
@@ -34,6 +34,48 @@ pub struct L10nInvoiceInfo {
     /// EN: "Terms"
     #[getset(get = "pub")]
     terms: String,
+
+    /// EN: "Credit note" — title of credit notes.
+    #[getset(get = "pub")]
+    credit_note: String,
+
+    /// EN: "Credit note no:" — used instead of `invoice_identifier` on
+    /// credit notes.
+    #[getset(get = "pub")]
+    credit_note_identifier: String,
+
+    /// EN: "Credits invoice no:" — refers to the credited invoice.
+    #[getset(get = "pub")]
+    credited_invoice: String,
+
+    /// EN: "Payment reminder" — title of payment reminders.
+    #[getset(get = "pub")]
+    reminder: String,
+
+    /// EN: "Originally due:" — precedes the due date of the overdue invoice
+    /// on payment reminders.
+    #[getset(get = "pub")]
+    reminded_due_date: String,
+
+    /// EN: "Days off:" — precedes the dates off listed on the invoice.
+    #[getset(get = "pub")]
+    days_off: String,
+
+    /// EN: "half day" — follows a listed date of which half the day was off.
+    #[getset(get = "pub")]
+    half_day: String,
+
+    /// EN: "Timesheet" — title of the appendix itemizing the hours worked.
+    #[getset(get = "pub")]
+    timesheet: String,
+
+    /// EN: "Date" — column of the dates worked in the timesheet appendix.
+    #[getset(get = "pub")]
+    timesheet_date: String,
+
+    /// EN: "Hours" — column of the hours worked in the timesheet appendix.
+    #[getset(get = "pub")]
+    timesheet_hours: String,
 }
 
 impl L10nInvoiceInfo {
@@ -46,6 +88,16 @@ impl L10nInvoiceInfo {
             .client_contact("For the attention of:".to_string())
             .vendor_contact("Our reference:".to_string())
             .terms("Terms:".to_string())
+            .credit_note("Credit note".to_string())
+            .credit_note_identifier("Credit note no:".to_string())
+            .credited_invoice("Credits invoice no:".to_string())
+            .reminder("Payment reminder".to_string())
+            .reminded_due_date("Originally due:".to_string())
+            .days_off("Days off:".to_string())
+            .half_day("half day".to_string())
+            .timesheet("Timesheet".to_string())
+            .timesheet_date("Date".to_string())
+            .timesheet_hours("Hours".to_string())
             .build()
     }
 }
@@ -1,3 +1,4 @@
+use crate::L10nQuantityUnits;
 use bon::Builder;
 use getset::Getters;
 use serde::Deserialize;
@@ -35,9 +36,68 @@ pub struct L10nLineItems {
     #[getset(get = "pub")]
     vat: String,
 
+    /// EN: "VAT rate" — column of the VAT summary, shown when the line items
+    /// have different VAT rates.
+    #[getset(get = "pub")]
+    vat_rate: String,
+
+    /// EN: "Net amount" — column of the VAT summary with the amount, excluding
+    /// VAT, of the line items with each rate.
+    #[getset(get = "pub")]
+    vat_base: String,
+
     /// EN: "Grand Total:"
     #[getset(get = "pub")]
     grand_total: String,
+
+    /// Notice on reverse charged invoices, stating that the client accounts
+    /// for the VAT.
+    #[getset(get = "pub")]
+    reverse_charge: String,
+
+    /// EN: "Seller VAT No.:" — printed with the reverse charge notice.
+    #[getset(get = "pub")]
+    seller_vat_number: String,
+
+    /// EN: "Buyer VAT No.:" — printed with the reverse charge notice.
+    #[getset(get = "pub")]
+    buyer_vat_number: String,
+
+    /// EN: "Expenses" — heading of the expenses section on invoices combining
+    /// services and expenses.
+    #[getset(get = "pub")]
+    expenses: String,
+
+    /// EN: "Outstanding amount of invoice {number}" — the line restating the
+    /// outstanding amount on payment reminders, `{number}` is replaced by the
+    /// invoice number.
+    #[getset(get = "pub")]
+    reminded_invoice: String,
+
+    /// EN: "Interest {rate}% p.a. for {days} days" — the line of the
+    /// late-payment interest on payment reminders, `{rate}` is replaced by
+    /// the annual interest rate and `{days}` by the days overdue.
+    #[getset(get = "pub")]
+    late_payment_interest: String,
+
+    /// EN: "Reminder fee" — the line of the reminder fee on payment
+    /// reminders.
+    #[getset(get = "pub")]
+    reminder_fee: String,
+
+    /// EN: "Amounts in {currency} at 1 {invoice_currency} = {rate} {currency}
+    /// on {date}, source: {source}" — the line introducing the totals in the
+    /// reporting currency, `{currency}` is replaced by the reporting currency,
+    /// `{invoice_currency}` by the currency of the invoice, `{rate}` and
+    /// `{date}` by the exchange rate and its date and `{source}` by where the
+    /// rate comes from.
+    #[getset(get = "pub")]
+    reporting_totals: String,
+
+    /// The units rendered after the quantity of line items with a unit, e.g.
+    /// mileage in `km`.
+    #[getset(get = "pub")]
+    units: L10nQuantityUnits,
 }
 
 impl L10nLineItems {
@@ -50,7 +110,24 @@ impl L10nLineItems {
             .total_cost("Total cost".to_string())
             .subtotal("Subtotal:".to_string())
             .vat("VAT".to_string())
+            .vat_rate("VAT rate".to_string())
+            .vat_base("Net amount".to_string())
             .grand_total("Grand Total:".to_string())
+            .reverse_charge(
+                "Reverse charge: VAT to be accounted for by the recipient, Art. 196 Council Directive 2006/112/EC."
+                    .to_string(),
+            )
+            .seller_vat_number("Seller VAT No.:".to_string())
+            .buyer_vat_number("Buyer VAT No.:".to_string())
+            .expenses("Expenses".to_string())
+            .reminded_invoice("Outstanding amount of invoice {number}".to_string())
+            .late_payment_interest("Interest {rate}% p.a. for {days} days".to_string())
+            .reminder_fee("Reminder fee".to_string())
+            .reporting_totals(
+                "Amounts in {currency} at 1 {invoice_currency} = {rate} {currency} on {date}, source: {source}"
+                    .to_string(),
+            )
+            .units(L10nQuantityUnits::english())
             .build()
     }
 }
@@ -83,4 +160,30 @@ mod tests {
         assert_eq!(sut.total_cost(), "Total cost");
         assert_eq!(sut.grand_total(), "Grand Total:");
     }
+
+    #[test]
+    fn reverse_charge_labels() {
+        assert!(
+            L10nLineItems::english()
+                .reverse_charge()
+                .starts_with("Reverse charge")
+        );
+        assert!(
+            L10nLineItems::swedish()
+                .reverse_charge()
+                .starts_with("Omvänd betalningsskyldighet")
+        );
+    }
+
+    #[test]
+    fn expenses_section_label() {
+        assert_eq!(L10nLineItems::english().expenses(), "Expenses");
+        assert_eq!(L10nLineItems::swedish().expenses(), "Utlägg");
+    }
+
+    #[test]
+    fn quantity_unit_labels() {
+        assert_eq!(L10nLineItems::english().units().kilometers(), "km");
+        assert_eq!(L10nLineItems::swedish().units().days(), "dagar");
+    }
 }
@@ -4,6 +4,8 @@ mod invoice_info;
 mod language;
 mod line_items;
 mod localization;
+mod payment_terms;
+mod quantity_units;
 mod swedish;
 mod vendor_info;
 
@@ -13,4 +15,6 @@ pub use invoice_info::*;
 pub use language::*;
 pub use line_items::*;
 pub use localization::*;
+pub use payment_terms::*;
+pub use quantity_units::*;
 pub use vendor_info::*;
@@ -0,0 +1,51 @@
+use bon::Builder;
+use getset::Getters;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Localization for the payment terms, one text per kind of terms, see
+/// [`crate::PaymentTermsKind`]. The placeholders `{days}`, `{day}`,
+/// `{percent}`, `{discount_days}`, `{amount}` and `{deadline}` are filled in
+/// when rendered.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, Builder)]
+pub struct L10nPaymentTerms {
+    /// EN: "Net {days}"
+    #[getset(get = "pub")]
+    net: String,
+
+    /// EN: "Net {days} end of month"
+    #[getset(get = "pub")]
+    net_end_of_month: String,
+
+    /// EN: "Due on receipt"
+    #[getset(get = "pub")]
+    due_on_receipt: String,
+
+    /// EN: "Day {day} of next month"
+    #[getset(get = "pub")]
+    day_of_next_month: String,
+
+    /// EN: "{percent}% {discount_days} days, net {days}"
+    #[getset(get = "pub")]
+    discount: String,
+
+    /// EN: "Deduct {amount} ({percent}%) if paid by {deadline}." — states
+    /// the discount of discount terms below the totals.
+    #[getset(get = "pub")]
+    early_payment_discount: String,
+}
+
+impl L10nPaymentTerms {
+    pub fn english() -> Self {
+        Self::builder()
+            .net("Net {days}".to_string())
+            .net_end_of_month("Net {days} end of month".to_string())
+            .due_on_receipt("Due on receipt".to_string())
+            .day_of_next_month("Day {day} of next month".to_string())
+            .discount("{percent}% {discount_days} days, net {days}".to_string())
+            .early_payment_discount(
+                "Deduct {amount} ({percent}%) if paid by {deadline}.".to_string(),
+            )
+            .build()
+    }
+}
@@ -0,0 +1,46 @@
+use bon::Builder;
+use getset::Getters;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Localization for the units of quantities rendered after the quantity of
+/// a line item, one text per [`crate::QuantityUnit`].
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, Builder)]
+pub struct L10nQuantityUnits {
+    /// EN: "km"
+    #[getset(get = "pub")]
+    kilometers: String,
+
+    /// EN: "mi"
+    #[getset(get = "pub")]
+    miles: String,
+
+    /// EN: "days"
+    #[getset(get = "pub")]
+    days: String,
+
+    /// EN: "breakfasts"
+    #[getset(get = "pub")]
+    breakfasts: String,
+
+    /// EN: "lunches"
+    #[getset(get = "pub")]
+    lunches: String,
+
+    /// EN: "dinners"
+    #[getset(get = "pub")]
+    dinners: String,
+}
+
+impl L10nQuantityUnits {
+    pub fn english() -> Self {
+        Self::builder()
+            .kilometers("km".to_string())
+            .miles("mi".to_string())
+            .days("days".to_string())
+            .breakfasts("breakfasts".to_string())
+            .lunches("lunches".to_string())
+            .dinners("dinners".to_string())
+            .build()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    L10n, L10nClientInfo, L10nContent, L10nInvoiceInfo, L10nLineItems, L10nVendorInfo, Language,
+    L10n, L10nClientInfo, L10nContent, L10nInvoiceInfo, L10nLineItems, L10nPaymentTerms,
+    L10nQuantityUnits, L10nVendorInfo, Language,
 };
 
 impl L10n {
@@ -30,6 +31,16 @@ impl L10nInvoiceInfo {
             .client_contact("Er referens:".to_string())
             .vendor_contact("Vår referens:".to_string())
             .terms("Villkor".to_string())
+            .credit_note("Kreditfaktura".to_string())
+            .credit_note_identifier("Kreditfakturanr:".to_string())
+            .credited_invoice("Avser faktura nr:".to_string())
+            .reminder("Betalningspåminnelse".to_string())
+            .reminded_due_date("Ursprungligt förfallodatum:".to_string())
+            .days_off("Lediga dagar:".to_string())
+            .half_day("halvdag".to_string())
+            .timesheet("Tidrapport".to_string())
+            .timesheet_date("Datum".to_string())
+            .timesheet_hours("Timmar".to_string())
             .build()
     }
 }
@@ -47,6 +58,23 @@ impl L10nVendorInfo {
     }
 }
 
+impl L10nPaymentTerms {
+    pub fn swedish() -> Self {
+        Self::builder()
+            .net("{days} dagar netto".to_string())
+            .net_end_of_month("{days} dagar netto efter månadens slut".to_string())
+            .due_on_receipt("Betalas vid mottagandet".to_string())
+            .day_of_next_month("Den {day} i nästa månad".to_string())
+            .discount(
+                "{percent}% rabatt inom {discount_days} dagar, {days} dagar netto".to_string(),
+            )
+            .early_payment_discount(
+                "Dra av {amount} ({percent}%) vid betalning senast {deadline}.".to_string(),
+            )
+            .build()
+    }
+}
+
 impl L10nLineItems {
     pub fn swedish() -> Self {
         Self::builder()
@@ -57,7 +85,37 @@ impl L10nLineItems {
             .total_cost("Kostnad".to_string())
             .subtotal("Delsumma:".to_string())
             .vat("Moms".to_string())
+            .vat_rate("Momssats".to_string())
+            .vat_base("Underlag".to_string())
             .grand_total("Totalt:".to_string())
+            .reverse_charge(
+                "Omvänd betalningsskyldighet: köparen redovisar momsen, art. 196 rådets direktiv 2006/112/EG."
+                    .to_string(),
+            )
+            .seller_vat_number("Säljarens momsreg.nr:".to_string())
+            .buyer_vat_number("Köparens momsreg.nr:".to_string())
+            .expenses("Utlägg".to_string())
+            .reminded_invoice("Utestående belopp för faktura {number}".to_string())
+            .late_payment_interest("Dröjsmålsränta {rate}% per år i {days} dagar".to_string())
+            .reminder_fee("Påminnelseavgift".to_string())
+            .reporting_totals(
+                "Belopp i {currency} till kursen 1 {invoice_currency} = {rate} {currency} den {date}, källa: {source}"
+                    .to_string(),
+            )
+            .units(L10nQuantityUnits::swedish())
+            .build()
+    }
+}
+
+impl L10nQuantityUnits {
+    pub fn swedish() -> Self {
+        Self::builder()
+            .kilometers("km".to_string())
+            .miles("miles".to_string())
+            .days("dagar".to_string())
+            .breakfasts("frukostar".to_string())
+            .lunches("luncher".to_string())
+            .dinners("middagar".to_string())
             .build()
     }
 }
@@ -69,6 +127,7 @@ impl L10nContent {
             .invoice_info(L10nInvoiceInfo::swedish())
             .vendor_info(L10nVendorInfo::swedish())
             .line_items(L10nLineItems::swedish())
+            .payment_terms(L10nPaymentTerms::swedish())
             .month_names([
                 "Januari".to_string(),
                 "Februari".to_string(),
@@ -0,0 +1,328 @@
+use crate::{
+    ClientId, Currency, Date, Error, HasSample, InvoiceNumber, InvoicedItems, NamedPdf, PathBuf,
+    Result, Totals,
+};
+use bon::Builder;
+use derive_more::{Deref, Display};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// The kind of items billed on an invoice.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InvoiceKind {
+    /// Services, possibly with time off.
+    #[display("services")]
+    Services,
+
+    /// Expenses.
+    #[display("expenses")]
+    Expenses,
+
+    /// Services and expenses on one invoice.
+    #[display("combined")]
+    Combined,
+
+    /// A credit note, numbered in a series of its own, with negated amounts.
+    #[display("credit note")]
+    CreditNote,
+}
+
+impl From<&InvoicedItems> for InvoiceKind {
+    fn from(items: &InvoicedItems) -> Self {
+        match items {
+            InvoicedItems::Service { .. } => Self::Services,
+            InvoicedItems::Expenses => Self::Expenses,
+            InvoicedItems::Combined { .. } => Self::Combined,
+        }
+    }
+}
+
+/// A record of an issued invoice or credit note, appended to `ledger.ron`
+/// each time one is generated.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Builder, Getters)]
+pub struct LedgerEntry {
+    /// The invoice number, e.g. `90`.
+    #[getset(get = "pub")]
+    number: InvoiceNumber,
+
+    /// The number as printed on the invoice, e.g. `"ACME-0090"`, `None` for
+    /// entries recorded before number formats existed.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    formatted_number: Option<String>,
+
+    /// The last day of the invoiced period.
+    #[getset(get = "pub")]
+    period: Date,
+
+    /// The date the invoice is due.
+    #[getset(get = "pub")]
+    due_date: Date,
+
+    /// What the invoice bills, services, expenses or both.
+    #[getset(get = "pub")]
+    kind: InvoiceKind,
+
+    /// The invoiced client, `None` for the default client.
+    #[getset(get = "pub")]
+    #[serde(default)]
+    client: Option<ClientId>,
+
+    /// The amounts of the invoice, negative for credit notes.
+    #[getset(get = "pub")]
+    totals: Totals,
+
+    /// The currency of the amounts.
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// Where the PDF was saved.
+    #[getset(get = "pub")]
+    output_path: PathBuf,
+
+    /// Hex encoded SHA-256 digest of the PDF.
+    #[getset(get = "pub")]
+    pdf_sha256: String,
+}
+
+impl LedgerEntry {
+    /// Creates the ledger entry of the invoice `named_pdf`, generated for
+    /// `items` of `client`.
+    pub fn new(named_pdf: &NamedPdf, items: &InvoicedItems, client: Option<ClientId>) -> Self {
+        Self::with_kind(named_pdf, InvoiceKind::from(items), client)
+    }
+
+    /// Creates the ledger entry of the credit note `named_pdf`, issued to
+    /// `client`.
+    pub fn credit_note(named_pdf: &NamedPdf, client: Option<ClientId>) -> Self {
+        Self::with_kind(named_pdf, InvoiceKind::CreditNote, client)
+    }
+
+    fn with_kind(named_pdf: &NamedPdf, kind: InvoiceKind, client: Option<ClientId>) -> Self {
+        let prepared = named_pdf.prepared_data();
+        Self::builder()
+            .number(prepared.information().number().clone())
+            .formatted_number(prepared.information().formatted_number().clone())
+            .period(*prepared.information().invoice_date())
+            .due_date(*prepared.information().due_date())
+            .kind(kind)
+            .maybe_client(client)
+            .totals(prepared.totals().clone())
+            .currency(*prepared.payment_info().currency())
+            .output_path(named_pdf.saved_at().clone())
+            .pdf_sha256(named_pdf.pdf().sha256_hex())
+            .build()
+    }
+
+    /// Whether this records a credit note, which is not paid.
+    pub fn is_credit_note(&self) -> bool {
+        self.kind == InvoiceKind::CreditNote
+    }
+
+    /// Whether `self` and `other` record the same invoice, i.e. the same
+    /// number and kind for the same client.
+    fn is_same_invoice_as(&self, other: &Self) -> bool {
+        self.number == other.number && self.kind == other.kind && self.client == other.client
+    }
+}
+
+impl HasSample for LedgerEntry {
+    fn sample() -> Self {
+        Self::new(
+            &NamedPdf::sample(),
+            &InvoicedItems::Service { time_off: None },
+            None,
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            &NamedPdf::sample_other(),
+            &InvoicedItems::Expenses,
+            Some(ClientId::sample()),
+        )
+    }
+}
+
+/// All issued invoices, oldest first, persisted in `ledger.ron`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Deref)]
+pub struct Ledger(Vec<LedgerEntry>);
+
+impl Ledger {
+    /// Creates a new `Ledger` from `entries`.
+    pub fn new(entries: impl IntoIterator<Item = LedgerEntry>) -> Self {
+        Self(Vec::from_iter(entries))
+    }
+
+    /// Appends `entry` to the ledger. A regenerated invoice replaces the entry
+    /// of the earlier generated one, keeping its position in the ledger.
+    pub fn record(&mut self, entry: LedgerEntry) {
+        match self
+            .0
+            .iter_mut()
+            .find(|recorded| recorded.is_same_invoice_as(&entry))
+        {
+            Some(recorded) => *recorded = entry,
+            None => self.0.push(entry),
+        }
+    }
+
+    /// Returns the entry of the invoice with `number` of `client`, `None` for
+    /// the default client. The `kind` is only needed if several invoices of
+    /// the client share the number, credit notes are only found with `kind`
+    /// [`InvoiceKind::CreditNote`] since they are numbered in a series of
+    /// their own.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvoiceNotInLedger`] if no such invoice is recorded and
+    /// [`Error::AmbiguousInvoiceInLedger`] if `kind` is needed but missing.
+    pub fn find(
+        &self,
+        number: &InvoiceNumber,
+        kind: Option<InvoiceKind>,
+        client: Option<&ClientId>,
+    ) -> Result<&LedgerEntry> {
+        let matches = self
+            .0
+            .iter()
+            .filter(|entry| entry.number() == number && entry.client().as_ref() == client)
+            .filter(|entry| match kind {
+                Some(kind) => *entry.kind() == kind,
+                None => !entry.is_credit_note(),
+            })
+            .collect::<Vec<_>>();
+        match matches.as_slice() {
+            [] => Err(Error::InvoiceNotInLedger { number: **number }),
+            [entry] => Ok(entry),
+            entries => Err(Error::AmbiguousInvoiceInLedger {
+                number: **number,
+                kinds: entries
+                    .iter()
+                    .map(|entry| entry.kind().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            }),
+        }
+    }
+
+    /// Returns the entries of invoices with `number`, of all kinds and
+    /// clients.
+    pub fn entries_with_number(&self, number: &InvoiceNumber) -> Vec<&LedgerEntry> {
+        self.0
+            .iter()
+            .filter(|entry| entry.number() == number)
+            .collect()
+    }
+}
+
+impl HasSample for Ledger {
+    fn sample() -> Self {
+        Self::new([LedgerEntry::sample()])
+    }
+
+    fn sample_other() -> Self {
+        Self::new([LedgerEntry::sample_other()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = Ledger;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn entry_from_named_pdf() {
+        let named_pdf = NamedPdf::sample();
+        let sut = LedgerEntry::sample();
+        let information = named_pdf.prepared_data().information();
+        assert_eq!(sut.number(), information.number());
+        assert_eq!(sut.period(), information.invoice_date());
+        assert_eq!(*sut.kind(), InvoiceKind::Services);
+        assert_eq!(sut.totals(), named_pdf.prepared_data().totals());
+        assert_eq!(sut.output_path(), named_pdf.saved_at());
+        assert_eq!(*sut.pdf_sha256(), named_pdf.pdf().sha256_hex());
+    }
+
+    #[test]
+    fn record_appends_other_invoices() {
+        let mut sut = Sut::sample();
+        sut.record(LedgerEntry::sample_other());
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut[1], LedgerEntry::sample_other());
+    }
+
+    #[test]
+    fn record_replaces_regenerated_invoice() {
+        let mut sut = Sut::new([LedgerEntry::sample(), LedgerEntry::sample_other()]);
+        let regenerated = LedgerEntry::builder()
+            .number(LedgerEntry::sample().number().clone())
+            .period(*LedgerEntry::sample().period())
+            .due_date(*LedgerEntry::sample().due_date())
+            .kind(InvoiceKind::Services)
+            .totals(Totals::sample_other())
+            .currency(Currency::SEK)
+            .output_path(PathBuf::from("/tmp/regenerated.pdf"))
+            .pdf_sha256("00".to_owned())
+            .build();
+        sut.record(regenerated.clone());
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut[0], regenerated);
+    }
+
+    #[test]
+    fn find_by_number_kind_and_client() {
+        let services = LedgerEntry::sample();
+        let expenses = LedgerEntry::builder()
+            .number(services.number().clone())
+            .period(*services.period())
+            .due_date(*services.due_date())
+            .kind(InvoiceKind::Expenses)
+            .totals(Totals::sample())
+            .currency(Currency::EUR)
+            .output_path(PathBuf::from("/tmp/expenses.pdf"))
+            .pdf_sha256("00".to_owned())
+            .build();
+        let sut = Sut::new([services.clone(), expenses.clone()]);
+        let number = services.number();
+
+        assert_eq!(
+            sut.find(number, None, None),
+            Err(Error::AmbiguousInvoiceInLedger {
+                number: **number,
+                kinds: "services, expenses".to_owned()
+            })
+        );
+        assert_eq!(
+            sut.find(number, Some(InvoiceKind::Expenses), None),
+            Ok(&expenses)
+        );
+        assert_eq!(
+            sut.find(number, None, Some(&ClientId::sample())),
+            Err(Error::InvoiceNotInLedger { number: **number })
+        );
+    }
+
+    #[test]
+    fn entries_with_number_include_all_kinds() {
+        let sut = Sut::sample();
+        assert_eq!(
+            sut.entries_with_number(LedgerEntry::sample().number())
+                .len(),
+            1
+        );
+        assert!(sut.entries_with_number(&InvoiceNumber::from(1)).is_empty());
+    }
+}
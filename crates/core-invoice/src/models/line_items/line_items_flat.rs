@@ -1,6 +1,6 @@
 use crate::{
-    ExchangeRates, HasSample, ItemConvertedIntoTargetCurrency, LineItemsPricedInSourceCurrency,
-    MaybeIsExpenses, Result,
+    ExchangeRates, HasSample, Item, ItemConvertedIntoTargetCurrency,
+    LineItemsPricedInSourceCurrency, MaybeIsExpenses, Result,
 };
 use bon::Builder;
 use getset::Getters;
@@ -12,9 +12,60 @@ pub struct LineItemsFlat {
     #[getset(get = "pub")]
     is_expenses: bool,
 
-    /// Either a single item (Service) or one or more expenses
+    /// One item per service, or one or more expenses
     #[getset(get = "pub")]
     items: Vec<ItemConvertedIntoTargetCurrency>,
+
+    /// Expenses rendered in a section of their own below the services, only
+    /// used by combined invoices, i.e. empty unless both services and
+    /// expenses are billed on the same invoice.
+    #[getset(get = "pub")]
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    expenses: Vec<ItemConvertedIntoTargetCurrency>,
+}
+
+impl LineItemsFlat {
+    /// Returns the items of a credit note for `lines` of this invoice, with
+    /// negated quantities and costs. `lines` are 1-based line numbers in the
+    /// order the items are rendered, i.e. `items` followed by `expenses`, an
+    /// empty `lines` credits every line.
+    ///
+    /// # Errors
+    /// Returns [`crate::Error::InvalidCreditNoteLine`] if a line does not exist.
+    pub fn credited(self, lines: &[usize]) -> Result<Self> {
+        let line_count = self.items.len() + self.expenses.len();
+        if let Some(line) = lines.iter().find(|line| **line == 0 || **line > line_count) {
+            return Err(crate::Error::InvalidCreditNoteLine {
+                line: *line,
+                line_count,
+            });
+        }
+        let is_credited = |line: usize| lines.is_empty() || lines.contains(&line);
+        let credit = |(line, item): (usize, ItemConvertedIntoTargetCurrency)| {
+            is_credited(line).then(|| item.negated())
+        };
+        let service_count = self.items.len();
+        let items = self
+            .items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| (index + 1, item))
+            .filter_map(credit)
+            .collect();
+        let expenses = self
+            .expenses
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| (service_count + index + 1, item))
+            .filter_map(credit)
+            .collect();
+        Ok(Self {
+            is_expenses: self.is_expenses,
+            items,
+            expenses,
+        })
+    }
 }
 
 impl MaybeIsExpenses for LineItemsFlat {
@@ -27,30 +78,48 @@ impl TryFrom<(LineItemsPricedInSourceCurrency, ExchangeRates)> for LineItemsFlat
     type Error = crate::Error;
 
     /// Converts the line items priced in source currency into a flat list of items
-    /// priced in the target currency, using the provided exchange rates.
+    /// priced in the target currency, using the provided exchange rates. Each
+    /// item is billed with its markup as its priced rows, see
+    /// [`Item::with_markup_applied`] and [`Item::priced_rows`], and items
+    /// which are not billable are left out.
     fn try_from(
         (line_items, exchange_rates): (LineItemsPricedInSourceCurrency, ExchangeRates),
     ) -> Result<Self> {
+        let convert = |items: Vec<Item>| {
+            items
+                .into_iter()
+                .filter(|item| *item.billable())
+                .map(Item::with_markup_applied)
+                .flat_map(Item::priced_rows)
+                .map(|(row, unit)| {
+                    row.total_cost_in_target_currency(&exchange_rates)
+                        .map(|row| row.with_unit(unit))
+                })
+                .collect::<Result<Vec<_>>>()
+        };
         match line_items {
-            LineItemsPricedInSourceCurrency::Service(item) => {
-                let service = item.total_cost_in_target_currency(&exchange_rates)?;
+            LineItemsPricedInSourceCurrency::Service(services) => {
                 let flat = LineItemsFlat::builder()
-                    .items(vec![service])
+                    .items(convert(services)?)
                     .is_expenses(false)
                     .build();
                 Ok(flat)
             }
             LineItemsPricedInSourceCurrency::Expenses(expenses) => {
-                let expenses = expenses
-                    .into_iter()
-                    .map(|expense| expense.total_cost_in_target_currency(&exchange_rates))
-                    .collect::<Result<Vec<_>>>()?;
                 let flat = LineItemsFlat::builder()
-                    .items(expenses)
+                    .items(convert(expenses)?)
                     .is_expenses(true)
                     .build();
                 Ok(flat)
             }
+            LineItemsPricedInSourceCurrency::Combined { services, expenses } => {
+                let flat = LineItemsFlat::builder()
+                    .items(convert(services)?)
+                    .expenses(convert(expenses)?)
+                    .is_expenses(false)
+                    .build();
+                Ok(flat)
+            }
         }
     }
 }
@@ -75,8 +144,12 @@ impl HasSample for LineItemsFlat {
 mod tests {
     use super::*;
     use crate::HasSample;
-    use crate::{Currency, ExchangeRatesMap, UnitPrice};
+    use crate::{
+        Currency, Decimal, ExchangeRatesMap, PerDiemRates, ProvidedMeals, Quantity, QuantityUnit,
+        UnitPrice,
+    };
     use rust_decimal::dec;
+    use std::str::FromStr;
     use test_log::test;
 
     type Sut = LineItemsFlat;
@@ -110,6 +183,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn combined_conversion_keeps_expenses_separate() {
+        let line_items = LineItemsPricedInSourceCurrency::Combined {
+            services: vec![Item::sample_consulting_service()],
+            expenses: vec![Item::sample_expense_coffee()],
+        };
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::from_iter([(
+                Currency::GBP,
+                UnitPrice::from(dec!(10.0)),
+            )]))
+            .target_currency(Currency::EUR)
+            .build();
+        let sut = Sut::try_from((line_items, exchange_rates)).unwrap();
+        assert!(!sut.is_expenses());
+        assert_eq!(sut.items().len(), 1);
+        assert_eq!(sut.expenses().len(), 1);
+        assert_eq!(*sut.expenses()[0].name(), "Coffee");
+    }
+
+    #[test]
+    fn mileage_and_per_diem_convert_into_rows_with_units() {
+        let mileage =
+            Item::mileage_from_str("Client visit, 120, km, 0.25, EUR, 2025-05-12").unwrap();
+        let per_diem = Item::per_diem(
+            "Berlin",
+            "DE",
+            Quantity::from(dec!(3)),
+            "2025-05-14".parse().unwrap(),
+            ProvidedMeals::builder().breakfasts(2).build(),
+            &PerDiemRates::sample(),
+        )
+        .unwrap();
+        let line_items = LineItemsPricedInSourceCurrency::Expenses(vec![mileage, per_diem]);
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::default())
+            .target_currency(Currency::EUR)
+            .build();
+        let sut = Sut::try_from((line_items, exchange_rates)).unwrap();
+        let rows = sut
+            .items()
+            .iter()
+            .map(|row| (*row.unit(), **row.total_cost()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (Some(QuantityUnit::Kilometers), dec!(30)),
+                (Some(QuantityUnit::Days), dec!(84)),
+                (Some(QuantityUnit::Breakfasts), dec!(-11.2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn markup_is_applied_and_non_billable_items_are_left_out() {
+        let hotel = Item::from_str("Hotel, 90, EUR, 2, 2025-05-31")
+            .unwrap()
+            .with_markup(Some(Decimal::from(dec!(10))));
+        let own_lunch = Item::from_str("Lunch, 11, EUR, 1, 2025-05-31")
+            .unwrap()
+            .with_billable(false);
+        let line_items = LineItemsPricedInSourceCurrency::Expenses(vec![
+            hotel,
+            own_lunch,
+            Item::from_str("Taxi, 25, EUR, 1, 2025-05-31").unwrap(),
+        ]);
+        let exchange_rates = ExchangeRates::builder()
+            .rates(ExchangeRatesMap::default())
+            .target_currency(Currency::EUR)
+            .build();
+        let sut = Sut::try_from((line_items, exchange_rates)).unwrap();
+        let rows = sut
+            .items()
+            .iter()
+            .map(|row| (row.name().as_str(), **row.total_cost()))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec![("Hotel", dec!(198)), ("Taxi", dec!(25))]);
+    }
+
+    #[test]
+    fn credited_negates_all_lines_by_default() {
+        let sut = Sut::sample().credited(&[]).unwrap();
+        let item = &sut.items()[0];
+        let original = Sut::sample().items()[0].clone();
+        assert_eq!(**item.quantity(), -**original.quantity());
+        assert_eq!(**item.total_cost(), -**original.total_cost());
+        assert_eq!(item.unit_price(), original.unit_price());
+    }
+
+    #[test]
+    fn credited_keeps_only_selected_lines_across_sections() {
+        let sut = Sut::builder()
+            .is_expenses(false)
+            .items(vec![ItemConvertedIntoTargetCurrency::sample()])
+            .expenses(vec![
+                ItemConvertedIntoTargetCurrency::sample(),
+                ItemConvertedIntoTargetCurrency::sample_other(),
+            ])
+            .build()
+            .credited(&[3])
+            .unwrap();
+        assert!(sut.items().is_empty());
+        assert_eq!(sut.expenses().len(), 1);
+        assert_eq!(
+            sut.expenses()[0].name(),
+            ItemConvertedIntoTargetCurrency::sample_other().name()
+        );
+    }
+
+    #[test]
+    fn credited_rejects_line_out_of_range() {
+        assert_eq!(
+            Sut::sample().credited(&[2]),
+            Err(crate::Error::InvalidCreditNoteLine {
+                line: 2,
+                line_count: 1
+            })
+        );
+        assert!(Sut::sample().credited(&[0]).is_err());
+    }
+
     #[test]
     fn test_is_expenses() {
         assert!(!MaybeIsExpenses::is_expenses(&Sut::sample()));
@@ -1,7 +1,5 @@
-use crate::{HasSample, Item};
-use derive_more::From;
+use crate::{Currency, HasSample, Item};
 use derive_more::IsVariant;
-use derive_more::TryUnwrap;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -18,19 +16,53 @@ impl MaybeIsExpenses for LineItemsPricedInSourceCurrency {
 }
 
 /// Services or expenses included in this invoice to be paid by the client.
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, From, TryUnwrap, IsVariant)]
-#[from(Vec<Item>, Item)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, IsVariant)]
 pub enum LineItemsPricedInSourceCurrency {
-    /// Service sold by the vendor to the client, e.g. `"Agreed Consulting Fees"`
-    Service(Item),
+    /// Services sold by the vendor to the client, one item per configured
+    /// service, e.g. `"Agreed Consulting Fees"`
+    Service(Vec<Item>),
     /// Expense incurred by the vendor, travel expenses for a conference/summit/
     /// retreat
     Expenses(Vec<Item>),
+    /// Services together with the expenses of the same period, on a single
+    /// invoice.
+    Combined {
+        /// One item per configured service.
+        services: Vec<Item>,
+        /// The expenses recorded for the period, possibly empty.
+        expenses: Vec<Item>,
+    },
+}
+
+impl LineItemsPricedInSourceCurrency {
+    /// The expenses of this invoice, empty for services only invoices.
+    pub fn expenses(&self) -> Vec<Item> {
+        match self {
+            Self::Service(_) => Vec::new(),
+            Self::Expenses(expenses) | Self::Combined { expenses, .. } => expenses.clone(),
+        }
+    }
+
+    /// The items which need exchange rates to be priced in
+    /// `target_currency`: the expenses together with the services priced in
+    /// another currency, e.g. recurring items, see [`crate::RecurringItem`].
+    pub fn items_to_convert(&self, target_currency: Currency) -> Vec<Item> {
+        let services = match self {
+            Self::Service(services) | Self::Combined { services, .. } => services.as_slice(),
+            Self::Expenses(_) => &[],
+        };
+        services
+            .iter()
+            .filter(|service| *service.currency() != target_currency)
+            .cloned()
+            .chain(self.expenses())
+            .collect()
+    }
 }
 
 impl HasSample for LineItemsPricedInSourceCurrency {
     fn sample() -> Self {
-        Self::Service(Item::sample())
+        Self::Service(vec![Item::sample()])
     }
 
     fn sample_other() -> Self {
@@ -62,5 +94,39 @@ mod tests {
         assert!(!MaybeIsExpenses::is_expenses(
             &LineItemsPricedInSourceCurrency::sample()
         ));
+        assert!(!MaybeIsExpenses::is_expenses(&Sut::Combined {
+            services: vec![Item::sample_consulting_service()],
+            expenses: vec![Item::sample_expense_coffee()],
+        }));
+    }
+
+    #[test]
+    fn expenses() {
+        assert!(Sut::sample().expenses().is_empty());
+        assert_eq!(Sut::sample_other().expenses(), vec![Item::sample_other()]);
+        let combined = Sut::Combined {
+            services: vec![Item::sample_consulting_service()],
+            expenses: vec![Item::sample_expense_coffee()],
+        };
+        assert_eq!(combined.expenses(), vec![Item::sample_expense_coffee()]);
+    }
+
+    #[test]
+    fn items_to_convert_includes_services_in_other_currency() {
+        let combined = Sut::Combined {
+            services: vec![Item::sample_consulting_service()],
+            expenses: vec![Item::sample_expense_coffee()],
+        };
+        assert_eq!(
+            combined.items_to_convert(Currency::EUR),
+            vec![Item::sample_expense_coffee()]
+        );
+        assert_eq!(
+            combined.items_to_convert(Currency::SEK),
+            vec![
+                Item::sample_consulting_service(),
+                Item::sample_expense_coffee()
+            ]
+        );
     }
 }
@@ -2,35 +2,56 @@ mod data;
 mod deserialize_contents_of_ron;
 mod error;
 mod exchange_rates;
+mod expense_import;
+mod expense_kind;
 mod invoice_info_full;
 mod invoice_number;
+mod invoice_terms;
 mod invoiced_items;
 mod item;
 mod item_converted_into_target_currency;
 mod l10n;
 mod layout;
+mod ledger;
 mod line_items;
 mod named_pdf;
+mod payments;
+mod receipt;
+mod timesheet;
+mod totals;
+mod valid_credit_note_input;
 mod valid_input;
+mod valid_reminder_input;
 
 pub use data::*;
 pub use deserialize_contents_of_ron::*;
 pub use error::*;
 pub use exchange_rates::*;
+pub use expense_import::*;
+pub use expense_kind::*;
 pub use invoice_info_full::*;
 pub use invoice_number::*;
+pub use invoice_terms::*;
 pub use invoiced_items::*;
 pub use item::*;
 pub use item_converted_into_target_currency::*;
+pub use klirr_foundation::ExchangeRatesProviderKind;
 pub use klirr_foundation::HasSample;
 pub use klirr_foundation::OutputPath;
 pub use klirr_foundation::{
-    BankHolidays, CompanyInformation, Cost, CountryCode, Date, Day, Decimal, DueDays, HexColor,
-    Month, MonthHalf, PostalAddress, Quantity, Rate, RelativeTime, StreetAddress, UnitPrice, Vat,
-    Year,
+    BankHolidays, CompanyInformation, Cost, CountryCode, Date, Day, DayOff, DaysOff, Decimal,
+    DueDays, HexColor, Month, MonthHalf, PostalAddress, Quantity, Rate, RelativeTime,
+    StreetAddress, UnitPrice, Vat, WorkSchedule, Year,
 };
 pub use l10n::*;
 pub use layout::*;
+pub use ledger::*;
 pub use line_items::*;
 pub use named_pdf::*;
+pub use payments::*;
+pub use receipt::*;
+pub use timesheet::*;
+pub use totals::*;
+pub use valid_credit_note_input::*;
 pub use valid_input::*;
+pub use valid_reminder_input::*;
@@ -0,0 +1,324 @@
+use crate::{ClientId, Date, Decimal, HasSample, InvoiceKind, InvoiceNumber, LedgerEntry};
+use bon::Builder;
+use derive_more::{Deref, Display};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// How a payment settles an invoice.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Settlement {
+    /// The invoice is paid in full.
+    #[display("paid")]
+    Paid,
+
+    /// Part of the invoice is paid, the rest is still outstanding.
+    #[display("partially paid")]
+    PartiallyPaid,
+
+    /// The outstanding amount will never be paid.
+    #[display("written off")]
+    WrittenOff,
+}
+
+/// A payment, or write-off, of an issued invoice.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Builder, Getters)]
+pub struct PaymentRecord {
+    /// How this payment settles the invoice.
+    #[getset(get = "pub")]
+    settlement: Settlement,
+
+    /// When the payment was received, or the invoice written off.
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// The amount received, in the currency of the invoice, `0` for
+    /// write-offs.
+    #[getset(get = "pub")]
+    amount: Decimal,
+}
+
+impl HasSample for PaymentRecord {
+    fn sample() -> Self {
+        Self::builder()
+            .settlement(Settlement::Paid)
+            .date(Date::sample())
+            .amount(Decimal::from(rust_decimal::dec!(1000)))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .settlement(Settlement::PartiallyPaid)
+            .date(Date::sample_other())
+            .amount(Decimal::from(rust_decimal::dec!(200)))
+            .build()
+    }
+}
+
+/// The payments of one issued invoice, identified like in the ledger by
+/// number, kind and client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Getters)]
+pub struct InvoicePayments {
+    /// The invoice number.
+    #[getset(get = "pub")]
+    number: InvoiceNumber,
+
+    /// The kind of the invoice.
+    #[getset(get = "pub")]
+    kind: InvoiceKind,
+
+    /// The invoiced client, `None` for the default client.
+    #[getset(get = "pub")]
+    #[serde(default)]
+    client: Option<ClientId>,
+
+    /// The payments, in the order they were recorded.
+    #[getset(get = "pub")]
+    records: Vec<PaymentRecord>,
+}
+
+impl InvoicePayments {
+    fn is_for(&self, entry: &LedgerEntry) -> bool {
+        self.number == *entry.number()
+            && self.kind == *entry.kind()
+            && self.client == *entry.client()
+    }
+}
+
+/// Payments of issued invoices, persisted in `payments.ron`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Deref)]
+pub struct Payments(Vec<InvoicePayments>);
+
+impl Payments {
+    /// Records `record` for the invoice of the ledger `entry`.
+    pub fn record(&mut self, entry: &LedgerEntry, record: PaymentRecord) {
+        match self.0.iter_mut().find(|payments| payments.is_for(entry)) {
+            Some(payments) => payments.records.push(record),
+            None => self.0.push(InvoicePayments {
+                number: entry.number().clone(),
+                kind: *entry.kind(),
+                client: entry.client().clone(),
+                records: vec![record],
+            }),
+        }
+    }
+
+    /// The payments recorded for the invoice of the ledger `entry`.
+    pub fn records_for(&self, entry: &LedgerEntry) -> &[PaymentRecord] {
+        self.0
+            .iter()
+            .find(|payments| payments.is_for(entry))
+            .map(|payments| payments.records.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl HasSample for Payments {
+    fn sample() -> Self {
+        let mut payments = Self::default();
+        payments.record(&LedgerEntry::sample(), PaymentRecord::sample());
+        payments
+    }
+
+    fn sample_other() -> Self {
+        let mut payments = Self::default();
+        payments.record(&LedgerEntry::sample_other(), PaymentRecord::sample_other());
+        payments
+    }
+}
+
+/// Whether an issued invoice is paid.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+pub enum PaymentStatus {
+    /// Nothing has been paid.
+    #[display("unpaid")]
+    Unpaid,
+
+    /// Part of the invoice has been paid.
+    #[display("partially paid")]
+    PartiallyPaid,
+
+    /// The invoice has been paid in full.
+    #[display("paid")]
+    Paid,
+
+    /// The invoice has been written off.
+    #[display("written off")]
+    WrittenOff,
+}
+
+/// The payment status of an issued invoice at a date.
+#[derive(Clone, Debug, PartialEq, Getters)]
+pub struct InvoiceStatus {
+    /// The issued invoice.
+    #[getset(get = "pub")]
+    entry: LedgerEntry,
+
+    /// Whether the invoice is paid.
+    #[getset(get = "pub")]
+    status: PaymentStatus,
+
+    /// The sum of all payments.
+    #[getset(get = "pub")]
+    paid: Decimal,
+
+    /// The amount still to be paid, `0` once paid or written off.
+    #[getset(get = "pub")]
+    outstanding: Decimal,
+
+    /// Number of days past the due date, `None` unless the invoice has an
+    /// outstanding amount and is past due.
+    #[getset(get = "pub")]
+    days_overdue: Option<i64>,
+}
+
+impl InvoiceStatus {
+    /// The status at `today` of the ledger `entry` with the payments `records`.
+    /// The latest settlement decides the status, so an invoice marked as paid
+    /// is paid even if less than its grand total was received, e.g. due to
+    /// bank fees.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    ///
+    /// let entry = LedgerEntry::sample();
+    /// let past_due = entry.due_date().advance_days(&DueDays::try_from(3u16).unwrap());
+    /// let status = InvoiceStatus::new(entry, &[], past_due);
+    ///
+    /// assert_eq!(*status.status(), PaymentStatus::Unpaid);
+    /// assert_eq!(*status.days_overdue(), Some(3));
+    /// ```
+    pub fn new(entry: LedgerEntry, records: &[PaymentRecord], today: Date) -> Self {
+        let paid = records
+            .iter()
+            .map(|record| **record.amount())
+            .sum::<rust_decimal::Decimal>();
+        let status = match records.last().map(PaymentRecord::settlement) {
+            None => PaymentStatus::Unpaid,
+            Some(Settlement::Paid) => PaymentStatus::Paid,
+            Some(Settlement::PartiallyPaid) => PaymentStatus::PartiallyPaid,
+            Some(Settlement::WrittenOff) => PaymentStatus::WrittenOff,
+        };
+        let outstanding = match status {
+            PaymentStatus::Paid | PaymentStatus::WrittenOff => rust_decimal::Decimal::ZERO,
+            PaymentStatus::Unpaid | PaymentStatus::PartiallyPaid => {
+                (**entry.totals().grand_total() - paid).max(rust_decimal::Decimal::ZERO)
+            }
+        };
+        let days_past_due = entry.due_date().days_until(&today);
+        let days_overdue = (!outstanding.is_zero() && days_past_due > 0).then_some(days_past_due);
+        Self {
+            entry,
+            status,
+            paid: Decimal::from(paid),
+            outstanding: Decimal::from(outstanding),
+            days_overdue,
+        }
+    }
+
+    /// Whether the invoice has an outstanding amount past its due date.
+    pub fn is_overdue(&self) -> bool {
+        self.days_overdue.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DueDays;
+    use rust_decimal::dec;
+    use test_log::test;
+
+    type Sut = Payments;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn records_are_kept_per_invoice() {
+        let mut sut = Sut::sample();
+        sut.record(&LedgerEntry::sample(), PaymentRecord::sample_other());
+        sut.record(&LedgerEntry::sample_other(), PaymentRecord::sample());
+        assert_eq!(sut.len(), 2);
+        assert_eq!(
+            sut.records_for(&LedgerEntry::sample()),
+            [PaymentRecord::sample(), PaymentRecord::sample_other()]
+        );
+        assert_eq!(
+            sut.records_for(&LedgerEntry::sample_other()),
+            [PaymentRecord::sample()]
+        );
+    }
+
+    fn partial(amount: rust_decimal::Decimal) -> PaymentRecord {
+        PaymentRecord::builder()
+            .settlement(Settlement::PartiallyPaid)
+            .date(Date::sample())
+            .amount(Decimal::from(amount))
+            .build()
+    }
+
+    fn days_after_due(entry: &LedgerEntry, days: u16) -> Date {
+        entry
+            .due_date()
+            .advance_days(&DueDays::try_from(days).unwrap())
+    }
+
+    #[test]
+    fn partially_paid_invoice_has_outstanding_amount() {
+        let entry = LedgerEntry::sample();
+        let grand_total = **entry.totals().grand_total();
+        let status = InvoiceStatus::new(entry.clone(), &[partial(dec!(100))], *entry.due_date());
+        assert_eq!(*status.status(), PaymentStatus::PartiallyPaid);
+        assert_eq!(**status.paid(), dec!(100));
+        assert_eq!(**status.outstanding(), grand_total - dec!(100));
+        assert!(!status.is_overdue(), "not overdue on the due date");
+    }
+
+    #[test]
+    fn paid_invoice_is_never_overdue() {
+        let entry = LedgerEntry::sample();
+        let today = days_after_due(&entry, 30);
+        let paid = PaymentRecord::builder()
+            .settlement(Settlement::Paid)
+            .date(today)
+            .amount(Decimal::from(dec!(1)))
+            .build();
+        let status = InvoiceStatus::new(entry, &[partial(dec!(100)), paid], today);
+        assert_eq!(*status.status(), PaymentStatus::Paid);
+        assert_eq!(**status.outstanding(), dec!(0));
+        assert_eq!(*status.days_overdue(), None);
+    }
+
+    #[test]
+    fn written_off_invoice_is_never_overdue() {
+        let entry = LedgerEntry::sample();
+        let today = days_after_due(&entry, 30);
+        let written_off = PaymentRecord::builder()
+            .settlement(Settlement::WrittenOff)
+            .date(today)
+            .amount(Decimal::ZERO)
+            .build();
+        let status = InvoiceStatus::new(entry, &[written_off], today);
+        assert_eq!(*status.status(), PaymentStatus::WrittenOff);
+        assert!(!status.is_overdue());
+    }
+
+    #[test]
+    fn partially_paid_invoice_past_due_is_overdue() {
+        let entry = LedgerEntry::sample();
+        let today = days_after_due(&entry, 10);
+        let status = InvoiceStatus::new(entry, &[partial(dec!(100))], today);
+        assert_eq!(*status.days_overdue(), Some(10));
+    }
+}
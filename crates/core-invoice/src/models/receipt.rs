@@ -0,0 +1,230 @@
+use crate::{Error, Item, Result};
+use bon::Builder;
+use derive_more::{Deref, Display};
+use getset::Getters;
+use serde::Deserialize;
+use serde::Serialize;
+use std::path::Path;
+
+/// The name of the directory in the data directory the receipts of the
+/// expenses are stored in.
+pub const RECEIPTS_DIR_NAME: &str = "receipts";
+
+/// The file format of a receipt, derived from its file extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReceiptFormat {
+    /// A PDF document, e.g. an emailed receipt.
+    Pdf,
+    /// A PNG image, e.g. a screenshot.
+    Png,
+    /// A JPEG image, e.g. a photo of a paper receipt.
+    Jpeg,
+    /// A GIF image.
+    Gif,
+    /// An SVG image.
+    Svg,
+}
+
+impl ReceiptFormat {
+    /// The format of the file at `path`, by its extension, compared
+    /// case-insensitively.
+    ///
+    /// # Errors
+    /// Returns [`Error::UnsupportedReceiptFormat`] if the extension is not that
+    /// of a PDF or a supported image.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("pdf") => Ok(Self::Pdf),
+            Some("png") => Ok(Self::Png),
+            Some("jpg" | "jpeg") => Ok(Self::Jpeg),
+            Some("gif") => Ok(Self::Gif),
+            Some("svg") => Ok(Self::Svg),
+            _ => Err(Error::UnsupportedReceiptFormat {
+                path: path.display().to_string(),
+            }),
+        }
+    }
+
+    /// The MIME type of the format, e.g. `"application/pdf"`.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Pdf => "application/pdf",
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+
+    /// Whether the format is an image, which can be shown on a page of the
+    /// invoice, rather than only embedded in it.
+    pub fn is_image(&self) -> bool {
+        !matches!(self, Self::Pdf)
+    }
+}
+
+/// A receipt of an expense, a PDF or an image file, by its path relative to
+/// the data directory, e.g. `"receipts/2025-05-31/hotel.pdf"`.
+#[derive(Clone, Debug, Display, PartialEq, Eq, Hash, Serialize, Deserialize, Deref)]
+#[serde(transparent)]
+pub struct Receipt(String);
+
+impl Receipt {
+    /// Creates a receipt stored at `path`, relative to the data directory.
+    ///
+    /// # Errors
+    /// Returns [`Error::UnsupportedReceiptFormat`] if `path` is neither a PDF
+    /// nor a supported image, see [`ReceiptFormat::from_path`].
+    pub fn new(path: impl Into<String>) -> Result<Self> {
+        let path = path.into();
+        ReceiptFormat::from_path(&path)?;
+        Ok(Self(path))
+    }
+
+    /// The format of the receipt, see [`ReceiptFormat::from_path`].
+    pub fn format(&self) -> Result<ReceiptFormat> {
+        ReceiptFormat::from_path(&self.0)
+    }
+
+    /// The file name of the receipt, e.g. `"hotel.pdf"`.
+    pub fn file_name(&self) -> &str {
+        self.0.rsplit('/').next().unwrap_or(&self.0)
+    }
+}
+
+/// How the receipts of the expenses are included in an invoice.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReceiptPlacement {
+    /// Image receipts are appended as a page each, PDF receipts, which cannot
+    /// be shown on a page, are embedded as files.
+    #[default]
+    Pages,
+    /// All receipts are embedded as files, listed as attachments by PDF
+    /// readers.
+    Embedded,
+}
+
+/// A receipt of an expense on an invoice, appended as a page of its own or
+/// embedded as a file, see [`ReceiptPlacement`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Getters, Builder)]
+pub struct InvoiceReceipt {
+    /// The name of the expense the receipt is for, e.g. `"Hotel"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    expense: String,
+
+    /// The receipt, the file of which is read from the data directory.
+    #[getset(get = "pub")]
+    receipt: Receipt,
+
+    /// The MIME type of the receipt, e.g. `"application/pdf"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    mime_type: String,
+
+    /// Whether the receipt is appended as a page, else it is embedded.
+    #[getset(get = "pub")]
+    page: bool,
+}
+
+impl InvoiceReceipt {
+    /// The receipts of the billable `expenses`, in order, each included as
+    /// `placement` says, skipping receipts of an unsupported format.
+    pub fn for_expenses<'a>(
+        expenses: impl IntoIterator<Item = &'a Item>,
+        placement: ReceiptPlacement,
+    ) -> Vec<Self> {
+        expenses
+            .into_iter()
+            .filter(|expense| *expense.billable())
+            .flat_map(|expense| {
+                expense.receipts().iter().filter_map(move |receipt| {
+                    let format = receipt.format().ok()?;
+                    Some(
+                        Self::builder()
+                            .expense(expense.name().clone())
+                            .receipt(receipt.clone())
+                            .mime_type(format.mime_type())
+                            .page(placement == ReceiptPlacement::Pages && format.is_image())
+                            .build(),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// The content of a receipt read from the data directory, e.g. to attach it
+/// to an email.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Getters, Builder)]
+pub struct ReceiptFile {
+    /// The receipt the content is read from.
+    #[getset(get = "pub")]
+    receipt: Receipt,
+
+    /// The MIME type of the receipt, e.g. `"image/png"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    mime_type: String,
+
+    /// The content of the file.
+    #[getset(get = "pub")]
+    content: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn format_from_extension_ignores_case() {
+        assert_eq!(
+            ReceiptFormat::from_path("receipts/2025-05-31/hotel.PDF").unwrap(),
+            ReceiptFormat::Pdf
+        );
+        assert_eq!(
+            ReceiptFormat::from_path("lunch.jpg").unwrap(),
+            ReceiptFormat::Jpeg
+        );
+        assert!(matches!(
+            ReceiptFormat::from_path("notes.txt"),
+            Err(Error::UnsupportedReceiptFormat { .. })
+        ));
+        assert!(ReceiptFormat::from_path("receipt").is_err());
+    }
+
+    #[test]
+    fn file_name_is_last_path_component() {
+        let receipt = Receipt::new("receipts/2025-05-31/hotel.pdf").unwrap();
+        assert_eq!(receipt.file_name(), "hotel.pdf");
+    }
+
+    #[test]
+    fn for_expenses_appends_images_as_pages_and_embeds_pdfs() {
+        let hotel = Item::builder()
+            .name("Hotel".to_owned())
+            .unit_price(crate::UnitPrice::from(rust_decimal::dec!(90)))
+            .currency(crate::Currency::EUR)
+            .quantity(crate::Quantity::ONE)
+            .transaction_date("2025-05-31".parse().unwrap())
+            .receipts(vec![
+                Receipt::new("receipts/2025-05-31/hotel.pdf").unwrap(),
+                Receipt::new("receipts/2025-05-31/hotel.png").unwrap(),
+            ])
+            .build();
+        let pages = |placement| {
+            InvoiceReceipt::for_expenses([&hotel, &Item::sample_expense_coffee()], placement)
+                .iter()
+                .map(|receipt| *receipt.page())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(pages(ReceiptPlacement::Pages), vec![false, true]);
+        assert_eq!(pages(ReceiptPlacement::Embedded), vec![false, false]);
+    }
+}
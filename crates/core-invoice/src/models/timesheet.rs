@@ -0,0 +1,212 @@
+use crate::{Date, Error, HasSample, Quantity, Result};
+use bon::Builder;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+/// A row of a timesheet, the hours worked at a date.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Builder, Getters)]
+pub struct TimesheetEntry {
+    /// The date worked, e.g. `2025-05-12`.
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// The hours worked at `date`, e.g. `7.5`.
+    #[getset(get = "pub")]
+    hours: Quantity,
+
+    /// What was worked on, e.g. `"Code review"`.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+/// The hours actually worked during a period, imported from a CSV file,
+/// billed instead of the working days of the period.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Builder, Getters)]
+pub struct Timesheet {
+    /// The rows of the timesheet, in the order of the file.
+    #[getset(get = "pub")]
+    entries: Vec<TimesheetEntry>,
+
+    /// Whether each row is itemized on an appendix page of the invoice.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    itemized: bool,
+}
+
+impl Timesheet {
+    /// Parses a timesheet from CSV with a `date,hours,description` header,
+    /// where the description is optional, e.g.:
+    ///
+    /// ```text
+    /// date,hours,description
+    /// 2025-05-12,7.5,Code review
+    /// 2025-05-13,8
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidTimesheet`] if a row cannot be parsed or has
+    /// negative hours.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let csv = "date,hours,description\n2025-05-12,7.5,Code review\n2025-05-13,8\n";
+    /// let timesheet = Timesheet::from_csv(csv.as_bytes(), false).unwrap();
+    ///
+    /// assert_eq!(timesheet.entries().len(), 2);
+    /// assert_eq!(*timesheet.hours(), dec!(15.5));
+    /// ```
+    pub fn from_csv(reader: impl std::io::Read, itemized: bool) -> Result<Self> {
+        let entries = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader)
+            .deserialize::<TimesheetEntry>()
+            .map(|entry| {
+                entry.map_err(|e| Error::InvalidTimesheet {
+                    reason: e.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(entry) = entries.iter().find(|entry| entry.hours < Quantity::ZERO) {
+            return Err(Error::InvalidTimesheet {
+                reason: format!("negative hours worked at {}", entry.date),
+            });
+        }
+        Ok(Self { entries, itemized })
+    }
+
+    /// The sum of the hours of all rows.
+    pub fn hours(&self) -> Quantity {
+        self.entries
+            .iter()
+            .fold(Quantity::ZERO, |acc, entry| acc + entry.hours)
+    }
+
+    /// Fails if any row is dated outside of the inclusive range
+    /// `[start, end]`.
+    ///
+    /// # Errors
+    /// Returns [`Error::TimesheetEntryOutsidePeriod`] for the first row dated
+    /// before `start` or after `end`.
+    pub fn validate_within(&self, start: Date, end: Date) -> Result<()> {
+        match self
+            .entries
+            .iter()
+            .find(|entry| !(start..=end).contains(&entry.date))
+        {
+            Some(entry) => Err(Error::TimesheetEntryOutsidePeriod {
+                date: entry.date,
+                start,
+                end,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl HasSample for Timesheet {
+    fn sample() -> Self {
+        Self::from_csv(
+            "date,hours,description\n2025-05-12,7.5,Code review\n2025-05-13,8,\n".as_bytes(),
+            false,
+        )
+        .expect("valid sample timesheet")
+    }
+
+    fn sample_other() -> Self {
+        Self::from_csv("date,hours\n2025-05-20,4\n".as_bytes(), true)
+            .expect("valid sample timesheet")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::dec;
+    use test_log::test;
+
+    type Sut = Timesheet;
+
+    fn d(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn from_csv_with_optional_description() {
+        let sut = Sut::sample();
+        assert_eq!(
+            *sut.entries(),
+            vec![
+                TimesheetEntry::builder()
+                    .date(d("2025-05-12"))
+                    .hours(Quantity::from(dec!(7.5)))
+                    .description("Code review")
+                    .build(),
+                TimesheetEntry::builder()
+                    .date(d("2025-05-13"))
+                    .hours(Quantity::EIGHT)
+                    .build(),
+            ]
+        );
+        assert_eq!(sut.hours(), Quantity::from(dec!(15.5)));
+    }
+
+    #[test]
+    fn from_csv_without_description_column() {
+        let sut = Sut::sample_other();
+        assert_eq!(sut.hours(), Quantity::from(dec!(4)));
+        assert!(sut.itemized());
+    }
+
+    #[test]
+    fn from_csv_invalid() {
+        for invalid in [
+            "date,hours\n2025-05-32,8\n",
+            "date,hours\n2025-05-12,eight\n",
+            "date,hours\n2025-05-12\n",
+            "date,hours\n2025-05-12,-1\n",
+        ] {
+            assert!(
+                matches!(
+                    Sut::from_csv(invalid.as_bytes(), false),
+                    Err(Error::InvalidTimesheet { .. })
+                ),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_within() {
+        let sut = Sut::sample();
+        assert!(
+            sut.validate_within(d("2025-05-01"), d("2025-05-31"))
+                .is_ok()
+        );
+        assert_eq!(
+            sut.validate_within(d("2025-05-13"), d("2025-05-31")),
+            Err(Error::TimesheetEntryOutsidePeriod {
+                date: d("2025-05-12"),
+                start: d("2025-05-13"),
+                end: d("2025-05-31"),
+            })
+        );
+    }
+}
@@ -0,0 +1,446 @@
+use crate::{
+    Currency, Date, Decimal, EarlyPaymentDiscount, HasSample, LineItemsFlat,
+    LineItemsPricedInSourceCurrency, UnitPrice, Vat,
+};
+use getset::Getters;
+use indexmap::IndexMap;
+use rust_decimal::RoundingStrategy;
+use serde::{Deserialize, Serialize};
+
+/// Number of decimals amounts are rounded to, i.e. cents.
+const AMOUNT_DECIMALS: u32 = 2;
+
+/// Rounds `amount` to cents, midpoints away from zero.
+pub(crate) fn round_amount(amount: rust_decimal::Decimal) -> Decimal {
+    Decimal::from(
+        amount.round_dp_with_strategy(AMOUNT_DECIMALS, RoundingStrategy::MidpointAwayFromZero),
+    )
+}
+
+/// The VAT of all line items with the same VAT rate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Getters)]
+pub struct VatAmount {
+    /// The VAT rate, e.g. `25` for 25%.
+    #[getset(get = "pub")]
+    rate: Vat,
+
+    /// Sum of the total cost of the line items with this rate, **excluding
+    /// VAT**, rounded to cents.
+    #[getset(get = "pub")]
+    base: Decimal,
+
+    /// VAT of the line items with this rate, rounded to cents.
+    #[getset(get = "pub")]
+    amount: Decimal,
+}
+
+/// The amounts of an invoice, computed from its line items and VAT rates.
+///
+/// All amounts are rounded to cents, so that the grand total is exactly the
+/// subtotal plus the VAT as rendered on the invoice.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Getters)]
+pub struct Totals {
+    /// Sum of the total cost of all line items, **excluding VAT**.
+    #[getset(get = "pub")]
+    subtotal: Decimal,
+
+    /// VAT added on top of the subtotal, the sum of `vat_per_rate`.
+    #[getset(get = "pub")]
+    vat: Decimal,
+
+    /// The VAT of the line items grouped by rate, in order of first use.
+    #[getset(get = "pub")]
+    #[serde(default)]
+    vat_per_rate: Vec<VatAmount>,
+
+    /// The amount to pay, the subtotal plus VAT.
+    #[getset(get = "pub")]
+    grand_total: Decimal,
+
+    /// The discount of the grand total if paid early, `None` unless the
+    /// payment terms grant one, see [`EarlyPaymentDiscount`].
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    early_payment_discount: Option<Decimal>,
+}
+
+impl Totals {
+    /// Sums the line items, services and expenses alike, and adds VAT on top,
+    /// at the rate of each item or else at `vat`. If `reverse_charge` no VAT
+    /// is added, the client accounts for it.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let vat = Vat::from_percent(dec!(25)).unwrap();
+    /// let totals = Totals::new(&LineItemsFlat::sample(), vat, false);
+    /// assert_eq!(*totals.grand_total(), *totals.subtotal() + *totals.vat());
+    ///
+    /// let reverse_charged = Totals::new(&LineItemsFlat::sample(), vat, true);
+    /// assert_eq!(reverse_charged.grand_total(), reverse_charged.subtotal());
+    /// ```
+    pub fn new(line_items: &LineItemsFlat, vat: Vat, reverse_charge: bool) -> Self {
+        Self::from_costs_per_rate(line_items.items().iter().chain(line_items.expenses()).map(
+            |item| {
+                let rate = if reverse_charge {
+                    Vat::ZERO
+                } else {
+                    item.vat().unwrap_or(vat)
+                };
+                (rate, **item.total_cost())
+            },
+        ))
+    }
+
+    /// Sets the discount of the grand total if paid early, see
+    /// [`EarlyPaymentDiscount::amount_of`].
+    pub fn with_early_payment_discount(self, discount: Option<&EarlyPaymentDiscount>) -> Self {
+        Self {
+            early_payment_discount: discount.map(|discount| discount.amount_of(self.grand_total)),
+            ..self
+        }
+    }
+
+    /// Groups the VAT-exclusive `costs` by VAT rate and sums them.
+    fn from_costs_per_rate(costs: impl IntoIterator<Item = (Vat, rust_decimal::Decimal)>) -> Self {
+        let mut bases = IndexMap::<Vat, rust_decimal::Decimal>::new();
+        for (rate, cost) in costs {
+            *bases.entry(rate).or_default() += cost;
+        }
+        let vat_per_rate = bases
+            .into_iter()
+            .map(|(rate, base)| VatAmount {
+                rate,
+                base: round_amount(base),
+                amount: round_amount(base * *rate.percent() / rust_decimal::Decimal::ONE_HUNDRED),
+            })
+            .collect::<Vec<_>>();
+        let subtotal = vat_per_rate
+            .iter()
+            .map(|vat| **vat.base())
+            .sum::<rust_decimal::Decimal>();
+        let vat = vat_per_rate
+            .iter()
+            .map(|vat| **vat.amount())
+            .sum::<rust_decimal::Decimal>();
+        Self {
+            subtotal: Decimal::from(subtotal),
+            vat: Decimal::from(vat),
+            vat_per_rate,
+            grand_total: Decimal::from(subtotal + vat),
+            early_payment_discount: None,
+        }
+    }
+}
+
+/// The subtotal, VAT and grand total of an invoice converted into another
+/// currency than the one it is paid in, e.g. for reporting VAT in `SEK` on an
+/// invoice in `EUR`, at the rate of a given date.
+///
+/// The subtotal and VAT are converted and rounded to cents separately, so
+/// that the converted grand total is exactly their sum.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Getters)]
+pub struct ReportingTotals {
+    /// The currency the totals are converted into, e.g. `SEK`.
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// The amount in `currency` of one unit of the currency of the invoice.
+    #[getset(get = "pub")]
+    rate: UnitPrice,
+
+    /// The date of `rate`, the invoice date.
+    #[getset(get = "pub")]
+    rate_date: Date,
+
+    /// Where `rate` comes from, e.g. `"European Central Bank"`.
+    #[getset(get = "pub")]
+    source: String,
+
+    /// The subtotal, **excluding VAT**, in `currency`.
+    #[getset(get = "pub")]
+    subtotal: Decimal,
+
+    /// The VAT in `currency`.
+    #[getset(get = "pub")]
+    vat: Decimal,
+
+    /// The grand total in `currency`, the subtotal plus VAT.
+    #[getset(get = "pub")]
+    grand_total: Decimal,
+}
+
+impl ReportingTotals {
+    /// Converts `totals` into `currency` at `rate`, the amount in `currency`
+    /// of one unit of the currency of the invoice on `rate_date`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core_invoice;
+    /// use klirr_core_invoice::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let vat = Vat::from_percent(dec!(25)).unwrap();
+    /// let totals = Totals::new(&LineItemsFlat::sample(), vat, false);
+    /// let reporting = ReportingTotals::new(
+    ///     &totals,
+    ///     Currency::SEK,
+    ///     UnitPrice::from(dec!(11.05)),
+    ///     Date::sample(),
+    ///     "European Central Bank",
+    /// );
+    /// assert_eq!(*reporting.grand_total(), *reporting.subtotal() + *reporting.vat());
+    /// ```
+    pub fn new(
+        totals: &Totals,
+        currency: Currency,
+        rate: UnitPrice,
+        rate_date: Date,
+        source: impl Into<String>,
+    ) -> Self {
+        let subtotal = round_amount(**totals.subtotal() * *rate);
+        let vat = round_amount(**totals.vat() * *rate);
+        Self {
+            currency,
+            rate,
+            rate_date,
+            source: source.into(),
+            grand_total: Decimal::from(*subtotal + *vat),
+            subtotal,
+            vat,
+        }
+    }
+
+    /// Converts other `totals` at the same rate, e.g. those of a credit note
+    /// for the invoice these are the totals of.
+    pub fn for_totals(&self, totals: &Totals) -> Self {
+        Self::new(
+            totals,
+            self.currency,
+            self.rate,
+            self.rate_date,
+            self.source.clone(),
+        )
+    }
+}
+
+pub trait SumTotals {
+    /// [`Totals`] for line items priced in the currency of the invoice, `()`
+    /// for line items not yet converted into it, which cannot be summed.
+    type Totals: Clone + std::fmt::Debug + PartialEq + Eq + std::hash::Hash + Serialize;
+
+    /// Sums the line items, see [`Totals::new`], and the discount if paid
+    /// early, see [`Totals::with_early_payment_discount`].
+    fn sum_totals(
+        &self,
+        vat: Vat,
+        reverse_charge: bool,
+        early_payment_discount: Option<&EarlyPaymentDiscount>,
+    ) -> Self::Totals;
+}
+
+impl SumTotals for LineItemsFlat {
+    type Totals = Totals;
+
+    fn sum_totals(
+        &self,
+        vat: Vat,
+        reverse_charge: bool,
+        early_payment_discount: Option<&EarlyPaymentDiscount>,
+    ) -> Self::Totals {
+        Totals::new(self, vat, reverse_charge).with_early_payment_discount(early_payment_discount)
+    }
+}
+
+impl SumTotals for LineItemsPricedInSourceCurrency {
+    type Totals = ();
+
+    fn sum_totals(
+        &self,
+        _vat: Vat,
+        _reverse_charge: bool,
+        _early_payment_discount: Option<&EarlyPaymentDiscount>,
+    ) -> Self::Totals {
+    }
+}
+
+impl HasSample for ReportingTotals {
+    fn sample() -> Self {
+        Self::new(
+            &Totals::sample(),
+            Currency::SEK,
+            UnitPrice::from(rust_decimal::dec!(11.05)),
+            Date::sample(),
+            "European Central Bank",
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            &Totals::sample_other(),
+            Currency::GBP,
+            UnitPrice::from(rust_decimal::dec!(0.85)),
+            Date::sample_other(),
+            "Bank of England",
+        )
+    }
+}
+
+impl HasSample for Totals {
+    fn sample() -> Self {
+        Self::new(&LineItemsFlat::sample(), Vat::ZERO, false)
+    }
+
+    fn sample_other() -> Self {
+        Self::new(&LineItemsFlat::sample_other(), Vat::sample(), false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Item, ItemConvertedIntoTargetCurrency};
+    use rust_decimal::dec;
+    use std::str::FromStr;
+    use test_log::test;
+
+    type Sut = Totals;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn sums_services_and_expenses_and_adds_vat() {
+        let line_items = LineItemsFlat::builder()
+            .is_expenses(false)
+            .items(vec![ItemConvertedIntoTargetCurrency::sample()])
+            .expenses(vec![ItemConvertedIntoTargetCurrency::sample_other()])
+            .build();
+        let subtotal = **ItemConvertedIntoTargetCurrency::sample().total_cost()
+            + **ItemConvertedIntoTargetCurrency::sample_other().total_cost();
+        let sut = Sut::new(&line_items, Vat::from_percent(dec!(25)).unwrap(), false);
+        assert_eq!(**sut.subtotal(), subtotal);
+        assert_eq!(**sut.vat(), subtotal * dec!(0.25));
+        assert_eq!(**sut.grand_total(), subtotal * dec!(1.25));
+    }
+
+    #[test]
+    fn item_vat_overrides_invoice_vat() {
+        let reduced = Vat::from_percent(dec!(12)).unwrap();
+        let hotel = Item::from_str("Hotel, 90, EUR, 2, 2025-05-31, 12").unwrap();
+        let line_items = LineItemsFlat::builder()
+            .is_expenses(true)
+            .items(vec![
+                hotel.with_total_cost(),
+                ItemConvertedIntoTargetCurrency::sample_other(),
+            ])
+            .build();
+        let vat = Vat::from_percent(dec!(25)).unwrap();
+        let sut = Sut::new(&line_items, vat, false);
+        assert_eq!(
+            sut.vat_per_rate()
+                .iter()
+                .map(|vat| *vat.rate())
+                .collect::<Vec<_>>(),
+            vec![reduced, vat]
+        );
+
+        let reverse_charged = Sut::new(&line_items, vat, true);
+        assert_eq!(
+            reverse_charged
+                .vat_per_rate()
+                .iter()
+                .map(|vat| *vat.rate())
+                .collect::<Vec<_>>(),
+            vec![Vat::ZERO]
+        );
+        assert_eq!(**reverse_charged.vat(), dec!(0));
+        assert_eq!(reverse_charged.subtotal(), sut.subtotal());
+    }
+
+    #[test]
+    fn zero_vat_grand_total_equals_subtotal() {
+        let sut = Sut::sample();
+        assert_eq!(**sut.vat(), dec!(0));
+        assert_eq!(sut.grand_total(), sut.subtotal());
+    }
+
+    #[test]
+    fn vat_is_grouped_by_rate() {
+        let low = Vat::from_percent(dec!(6)).unwrap();
+        let high = Vat::from_percent(dec!(25)).unwrap();
+        let sut = Sut::from_costs_per_rate([(high, dec!(100)), (low, dec!(50)), (high, dec!(300))]);
+        assert_eq!(
+            sut.vat_per_rate()
+                .iter()
+                .map(|vat| (*vat.rate(), **vat.base(), **vat.amount()))
+                .collect::<Vec<_>>(),
+            vec![(high, dec!(400), dec!(100)), (low, dec!(50), dec!(3))]
+        );
+        assert_eq!(**sut.subtotal(), dec!(450));
+        assert_eq!(**sut.vat(), dec!(103));
+        assert_eq!(**sut.grand_total(), dec!(553));
+    }
+
+    #[test]
+    fn amounts_are_rounded_to_cents() {
+        let vat = Vat::from_percent(dec!(25)).unwrap();
+        let sut = Sut::from_costs_per_rate([(vat, dec!(10.005)), (vat, dec!(0.017))]);
+        assert_eq!(**sut.subtotal(), dec!(10.02));
+        assert_eq!(**sut.vat(), dec!(2.51));
+        assert_eq!(**sut.grand_total(), dec!(12.53));
+    }
+
+    #[test]
+    fn early_payment_discount_of_grand_total() {
+        let vat = Vat::from_percent(dec!(25)).unwrap();
+        let discount = crate::DiscountTerms::sample().early_payment_discount(crate::Date::sample());
+        let sut = Sut::from_costs_per_rate([(vat, dec!(1000))])
+            .with_early_payment_discount(Some(&discount));
+        assert_eq!(**sut.grand_total(), dec!(1250));
+        assert_eq!(sut.early_payment_discount().map(|d| *d), Some(dec!(25)));
+        assert_eq!(*Sut::sample().early_payment_discount(), None);
+    }
+
+    #[test]
+    fn reporting_totals_equality() {
+        assert_eq!(ReportingTotals::sample(), ReportingTotals::sample());
+        assert_eq!(
+            ReportingTotals::sample_other(),
+            ReportingTotals::sample_other()
+        );
+    }
+
+    #[test]
+    fn reporting_totals_inequality() {
+        assert_ne!(ReportingTotals::sample(), ReportingTotals::sample_other());
+    }
+
+    #[test]
+    fn reporting_totals_convert_subtotal_and_vat_rounded_to_cents() {
+        let vat = Vat::from_percent(dec!(25)).unwrap();
+        let totals = Sut::from_costs_per_rate([(vat, dec!(1000.01))]);
+        let sut = ReportingTotals::new(
+            &totals,
+            Currency::SEK,
+            UnitPrice::from(dec!(11.0523)),
+            crate::Date::sample(),
+            "European Central Bank",
+        );
+        assert_eq!(**sut.subtotal(), dec!(11052.41));
+        assert_eq!(**sut.vat(), dec!(2763.08));
+        assert_eq!(**sut.grand_total(), dec!(13815.49));
+        assert_eq!(sut.for_totals(&totals), sut);
+    }
+}
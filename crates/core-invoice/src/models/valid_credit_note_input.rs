@@ -0,0 +1,76 @@
+use crate::{
+    ClientId, Date, HasSample, InvoiceNumber, InvoicedItems, Language, Layout, PathBuf, Timesheet,
+};
+use bon::Builder;
+use derive_more::Display;
+use getset::Getters;
+
+/// Input validated and ready for credit note generation.
+#[derive(Debug, Clone, Display, Builder, Getters)]
+#[display(
+    "Invoice: {}, lines: {:?}, items: {}, date: {}, language: {}",
+    invoice,
+    lines,
+    items,
+    date,
+    language
+)]
+pub struct ValidCreditNoteInput {
+    /// Number of the invoice to credit.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    invoice: InvoiceNumber,
+
+    /// The 1-based lines of the invoice to credit, empty credits all lines.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    lines: Vec<usize>,
+
+    /// The kind of the credited invoice, used to find its period.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    items: InvoicedItems,
+
+    /// Client of the credited invoice, `None` for the default client.
+    #[getset(get = "pub")]
+    client: Option<ClientId>,
+
+    /// Hours of the timesheet the credited invoice was billed from, needed to
+    /// rebuild it with the billed hours instead of the working days.
+    #[getset(get = "pub")]
+    timesheet: Option<Timesheet>,
+
+    #[builder(default)]
+    #[getset(get = "pub")]
+    language: Language,
+
+    #[builder(default)]
+    #[getset(get = "pub")]
+    layout: Layout,
+
+    /// Issue date of the credit note, unless it was issued before.
+    #[getset(get = "pub")]
+    date: Date,
+
+    #[getset(get = "pub")]
+    maybe_output_path: Option<PathBuf>,
+}
+
+impl HasSample for ValidCreditNoteInput {
+    fn sample() -> Self {
+        Self::builder()
+            .invoice(9876)
+            .date(Date::sample())
+            .maybe_output_path(PathBuf::from("credit_note.pdf"))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .invoice(9877)
+            .lines(vec![1])
+            .items(InvoicedItems::Expenses)
+            .date(Date::sample_other())
+            .build()
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Date, DecryptedEmailSettings, HasSample, InvoicedItems, Language, Layout, PathBuf};
+use crate::{
+    ClientId, Date, DecryptedEmailSettings, HasSample, InvoicedItems, Language, Layout, PathBuf,
+    ReceiptPlacement, Timesheet,
+};
 use bon::Builder;
 use derive_more::Display;
 use getset::{Getters, WithSetters};
@@ -36,6 +39,22 @@ pub struct ValidInput {
     #[getset(get = "pub")]
     refresh_holidays: bool,
 
+    /// Hours actually worked during the period, billed instead of the working
+    /// days of the period for services billed per day or per hour.
+    #[getset(get = "pub")]
+    timesheet: Option<Timesheet>,
+
+    /// Client in the client registry to invoice, `None` invoices the
+    /// default client.
+    #[getset(get = "pub")]
+    client: Option<ClientId>,
+
+    /// How the receipts of the invoiced expenses are included in the
+    /// invoice, see [`ReceiptPlacement`].
+    #[builder(default)]
+    #[getset(get = "pub")]
+    receipts: ReceiptPlacement,
+
     #[getset(get = "pub")]
     maybe_output_path: Option<PathBuf>,
 
@@ -0,0 +1,64 @@
+use crate::{
+    ClientId, Date, DecryptedEmailSettings, HasSample, InvoiceKind, InvoiceNumber, Language,
+    Layout, PathBuf,
+};
+use bon::Builder;
+use derive_more::Display;
+use getset::Getters;
+
+/// Input validated and ready for payment reminder generation.
+#[derive(Debug, Clone, Display, Builder, Getters)]
+#[display("Invoice: {}, date: {}, language: {}", invoice, date, language)]
+pub struct ValidReminderInput {
+    /// Number of the overdue invoice to remind of.
+    #[builder(into)]
+    #[getset(get = "pub")]
+    invoice: InvoiceNumber,
+
+    /// The kind of the overdue invoice, only needed if several invoices of
+    /// the client share the number, see [`crate::Ledger::find`].
+    #[getset(get = "pub")]
+    kind: Option<InvoiceKind>,
+
+    /// Client of the overdue invoice, `None` for the default client.
+    #[getset(get = "pub")]
+    client: Option<ClientId>,
+
+    #[builder(default)]
+    #[getset(get = "pub")]
+    language: Language,
+
+    #[builder(default)]
+    #[getset(get = "pub")]
+    layout: Layout,
+
+    /// Date of the reminder, up to which interest is accrued.
+    #[getset(get = "pub")]
+    date: Date,
+
+    #[getset(get = "pub")]
+    maybe_output_path: Option<PathBuf>,
+
+    /// Email settings used to send the reminder, `None` if not emailed.
+    #[getset(get = "pub")]
+    email: Option<DecryptedEmailSettings>,
+}
+
+impl HasSample for ValidReminderInput {
+    fn sample() -> Self {
+        Self::builder()
+            .invoice(9876)
+            .date(Date::sample())
+            .maybe_output_path(PathBuf::from("reminder.pdf"))
+            .build()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .invoice(9877)
+            .kind(InvoiceKind::Expenses)
+            .client(ClientId::sample())
+            .date(Date::sample_other())
+            .build()
+    }
+}
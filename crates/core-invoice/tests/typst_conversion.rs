@@ -1,7 +1,8 @@
 use insta::assert_snapshot;
 use klirr_core_invoice::{
-    BankHolidays, Currency, Data, Date, ExchangeRates, ExchangeRatesMap, HasSample, InvoicedItems,
-    L10n, LabeledField, Language, PreparedData, UnitPrice, ValidInput, Vat,
+    BankHolidays, Currency, Data, Date, DaysOff, ExchangeRates, ExchangeRatesMap, HasSample,
+    InvoiceNumber, InvoicedItems, L10n, LabeledField, Language, OutputPath, PreparedData, Service,
+    ServiceFees, TimeOff, Timesheet, UnitPrice, ValidInput, Vat,
 };
 use klirr_foundation::ToTypstFn;
 use rust_decimal::dec;
@@ -46,6 +47,43 @@ fn data_services_to_typst() {
     assert_snapshot!("data_services_to_typst", typst);
 }
 
+#[test]
+fn data_services_with_listed_days_off_to_typst() {
+    let days_off = ["2025-05-12..2025-05-16", "2025-05-20:half"]
+        .into_iter()
+        .map(|days| days.parse::<DaysOff>().unwrap())
+        .collect::<DaysOff>();
+    let input = ValidInput::builder()
+        .items(InvoicedItems::Service {
+            time_off: Some(TimeOff::Dates {
+                days_off,
+                listed: true,
+            }),
+        })
+        .date("2025-05-31".parse::<Date>().unwrap())
+        .language(Language::EN)
+        .build();
+    let typst = prepared_data_from(input).to_typst_fn();
+    assert_snapshot!("data_services_with_listed_days_off_to_typst", typst);
+}
+
+#[test]
+fn data_services_with_itemized_timesheet_to_typst() {
+    let timesheet = Timesheet::from_csv(
+        "date,hours,description\n2025-05-12,7.5,Code review\n2025-05-13,8\n".as_bytes(),
+        true,
+    )
+    .unwrap();
+    let input = ValidInput::builder()
+        .items(InvoicedItems::Service { time_off: None })
+        .date("2025-05-31".parse::<Date>().unwrap())
+        .language(Language::EN)
+        .timesheet(timesheet)
+        .build();
+    let typst = prepared_data_from(input).to_typst_fn();
+    assert_snapshot!("data_services_with_itemized_timesheet_to_typst", typst);
+}
+
 #[test]
 fn l10n_english_to_typst() {
     let typst = L10n::new(Language::EN).unwrap().content().to_typst_fn();
@@ -116,3 +154,113 @@ fn data_services_with_vat_to_typst() {
         .to_typst_fn();
     assert_snapshot!("data_services_with_vat_to_typst", typst);
 }
+
+#[test]
+fn data_services_reverse_charge_to_typst() {
+    let input = ValidInput::builder()
+        .items(InvoicedItems::Service { time_off: None })
+        .date("2025-05-31".parse::<Date>().unwrap())
+        .language(Language::EN)
+        .build();
+    let data = Data::sample();
+    let payment_info_with_vat = data
+        .payment_info()
+        .clone()
+        .with_vat(Vat::from_percent(dec!(25)).expect("25% is valid"));
+    let data = Data::builder()
+        .information(data.information().clone())
+        .vendor(data.vendor().clone())
+        .client(data.client().clone().with_reverse_charge(true))
+        .payment_info(payment_info_with_vat)
+        .service_fees(data.service_fees().clone())
+        .expensed_periods(data.expensed_periods().clone())
+        .build();
+    let typst = data
+        .to_partial(input, &BankHolidays::default())
+        .unwrap()
+        .to_typst(sample_exchange_rates())
+        .unwrap()
+        .to_typst_fn();
+    assert_snapshot!("data_services_reverse_charge_to_typst", typst);
+}
+
+#[test]
+fn data_multiple_services_to_typst() {
+    let input = ValidInput::builder()
+        .items(InvoicedItems::Service { time_off: None })
+        .date("2025-05-31".parse::<Date>().unwrap())
+        .language(Language::EN)
+        .build();
+    let data = Data::sample();
+    let service_fees = ServiceFees::builder()
+        .name(data.service_fees().name())
+        .rate(*data.service_fees().rate())
+        .cadence(*data.service_fees().cadence())
+        .additional_services(vec![Service::sample(), Service::sample_other()])
+        .build()
+        .unwrap();
+    let data = Data::builder()
+        .information(data.information().clone())
+        .vendor(data.vendor().clone())
+        .client(data.client().clone())
+        .payment_info(data.payment_info().clone())
+        .service_fees(service_fees)
+        .expensed_periods(data.expensed_periods().clone())
+        .build();
+    let typst = data
+        .to_partial(input, &BankHolidays::default())
+        .unwrap()
+        .to_typst(sample_exchange_rates())
+        .unwrap()
+        .to_typst_fn();
+    assert_snapshot!("data_multiple_services_to_typst", typst);
+}
+
+#[test]
+fn data_combined_to_typst() {
+    let input = ValidInput::builder()
+        .items(InvoicedItems::Combined { time_off: None })
+        .date("2025-05-31".parse::<Date>().unwrap())
+        .language(Language::EN)
+        .build();
+    let typst = prepared_data_from(input).to_typst_fn();
+    assert_snapshot!("data_combined_to_typst", typst);
+}
+
+#[test]
+fn data_services_with_reporting_totals_to_typst() {
+    let input = ValidInput::builder()
+        .items(InvoicedItems::Service { time_off: None })
+        .date("2025-05-31".parse::<Date>().unwrap())
+        .language(Language::EN)
+        .build();
+    let typst = prepared_data_from(input)
+        .with_reporting_totals(
+            Currency::SEK,
+            UnitPrice::from(dec!(11.05)),
+            "2025-05-31".parse::<Date>().unwrap(),
+            "European Central Bank",
+        )
+        .to_typst_fn();
+    assert_snapshot!("data_services_with_reporting_totals_to_typst", typst);
+}
+
+#[test]
+fn credit_note_to_typst() {
+    let input = ValidInput::builder()
+        .items(InvoicedItems::Combined { time_off: None })
+        .date("2025-05-31".parse::<Date>().unwrap())
+        .language(Language::EN)
+        .build();
+    let typst = prepared_data_from(input)
+        .credit_note(
+            InvoiceNumber::from(1),
+            "1".to_owned(),
+            "2025-06-15".parse::<Date>().unwrap(),
+            &[2],
+            OutputPath::Name("credit_note.pdf".into()),
+        )
+        .unwrap()
+        .to_typst_fn();
+    assert_snapshot!("credit_note_to_typst", typst);
+}
@@ -32,6 +32,15 @@
   }
 }
 
+#let format_quantity(l10n, row) = {
+  if "unit" in row {
+    // E.g. mileage and per diem rows, formatted as "120 km"
+    str(row.quantity) + " " + l10n.line_items.units.at(row.unit)
+  } else {
+    str(row.quantity)
+  }
+}
+
 // Function to format numbers to two decimals
 #let format_amount(amount, currency) = {
   let amt = amount * 1.0
@@ -2,7 +2,8 @@ use std::str::FromStr;
 use std::{cmp::Ordering, ops::Mul};
 
 use crate::{
-    BankHolidays, Cadence, Date, Day, Granularity, ModelError, Month, Quantity, RelativeTime, Year,
+    BankHolidays, Cadence, Date, Day, DaysOff, Granularity, ModelError, Month, Quantity,
+    RelativeTime, WorkSchedule, Year,
 };
 use chrono::{Datelike, NaiveDate, Weekday};
 use indexmap::IndexSet;
@@ -40,6 +41,18 @@ pub enum CalendarError {
 
     #[error("Invalid date, underlying: {underlying}")]
     InvalidDate { underlying: String },
+
+    #[error("Number {number} precedes the offset number {offset}")]
+    NumberPrecedesOffset { number: u16, offset: u16 },
+
+    #[error("Cannot invoice per {granularity} when cadence is {cadence}")]
+    GranularityDoesNotFitCadence {
+        granularity: Granularity,
+        cadence: Cadence,
+    },
+
+    #[error("Period '{label}' does not match cadence {cadence}")]
+    PeriodLabelDoesNotMatchCadence { label: String, cadence: Cadence },
 }
 
 fn month_end_date(year: Year, month: Month) -> Date {
@@ -59,6 +72,30 @@ fn first_half_end_date(year: Year, month: Month) -> Date {
         .build()
 }
 
+fn quarter_end_date(year: Year, quarter: i32) -> CalendarResult<Date> {
+    Ok(month_end_date(year, Month::try_from(quarter * 3)?))
+}
+
+fn naive_date(date: Date) -> NaiveDate {
+    date.to_datetime().date()
+}
+
+fn date_from_days_from_ce(days: i32) -> CalendarResult<Date> {
+    NaiveDate::from_num_days_from_ce_opt(days)
+        .map(Date::from)
+        .ok_or(CalendarError::InvalidDate {
+            underlying: "negative period serial".to_owned(),
+        })
+}
+
+/// Days from the common era of Monday 2024-01-01, the first day of ISO week 1
+/// of 2024, which periods of [`Granularity::TwoWeeks`] are aligned to.
+fn two_weeks_anchor() -> i32 {
+    NaiveDate::from_ymd_opt(2024, 1, 1)
+        .expect("valid date")
+        .num_days_from_ce()
+}
+
 fn period_end_for_unit(date: Date, unit: Granularity) -> CalendarResult<Date> {
     match unit {
         Granularity::Month => Ok(date.end_of_month()),
@@ -70,9 +107,12 @@ fn period_end_for_unit(date: Date, unit: Granularity) -> CalendarResult<Date> {
                 Ok(date.end_of_month())
             }
         }
+        Granularity::Week => week_end_date(week_serial(date)),
+        Granularity::TwoWeeks => two_weeks_end_date(two_weeks_serial(date)),
+        Granularity::Quarter => quarter_end_date(*date.year(), (*date.month() as i32 - 1) / 3 + 1),
         unsupported => Err(CalendarError::InvalidPeriod {
             bad_value: format!(
-                "Unsupported relative period unit '{unsupported:?}', expected Month or Fortnight"
+                "Unsupported relative period unit '{unsupported:?}', expected Month, Fortnight, Week, TwoWeeks or Quarter"
             ),
         }),
     }
@@ -142,6 +182,32 @@ fn fortnight_serial(period_end: Date) -> CalendarResult<i32> {
     Ok(year * 24 + month_zero_based * 2 + half)
 }
 
+/// Serial number of the ISO week containing `date`, counting from the week of
+/// Monday 0001-01-01.
+fn week_serial(date: Date) -> i32 {
+    (naive_date(date).num_days_from_ce() - 1).div_euclid(7)
+}
+
+fn week_end_date(serial: i32) -> CalendarResult<Date> {
+    date_from_days_from_ce(serial * 7 + 7)
+}
+
+/// Serial number of the two ISO weeks period containing `date`, counting
+/// from the period starting at [`two_weeks_anchor`].
+fn two_weeks_serial(date: Date) -> i32 {
+    (naive_date(date).num_days_from_ce() - two_weeks_anchor()).div_euclid(14)
+}
+
+fn two_weeks_end_date(serial: i32) -> CalendarResult<Date> {
+    date_from_days_from_ce(two_weeks_anchor() + serial * 14 + 13)
+}
+
+fn quarter_serial(period_end: Date) -> i32 {
+    let year = **period_end.year() as i32;
+    let quarter_zero_based = (*period_end.month() as i32 - 1) / 3;
+    year * 4 + quarter_zero_based
+}
+
 fn shift_period_end(period_end: Date, unit: Granularity, amount: i16) -> CalendarResult<Date> {
     let offset = amount as i32;
     match unit {
@@ -175,9 +241,20 @@ fn shift_period_end(period_end: Date, unit: Granularity, amount: i16) -> Calenda
                 Ok(month_end_date(year, month))
             }
         }
+        Granularity::Week => week_end_date(week_serial(period_end) + offset),
+        Granularity::TwoWeeks => two_weeks_end_date(two_weeks_serial(period_end) + offset),
+        Granularity::Quarter => {
+            let serial = quarter_serial(period_end) + offset;
+            if serial < 0 {
+                return Err(CalendarError::InvalidDate {
+                    underlying: "negative period serial".to_owned(),
+                });
+            }
+            quarter_end_date(Year::from(serial / 4), serial % 4 + 1)
+        }
         unsupported => Err(CalendarError::InvalidPeriod {
             bad_value: format!(
-                "Unsupported relative period unit '{unsupported:?}', expected Month or Fortnight"
+                "Unsupported relative period unit '{unsupported:?}', expected Month, Fortnight, Week, TwoWeeks or Quarter"
             ),
         }),
     }
@@ -196,6 +273,9 @@ fn elapsed_periods_since(start: Date, end: Date, cadence: Cadence) -> CalendarRe
     let elapsed = match cadence {
         Cadence::Monthly => month_serial(end) - month_serial(start),
         Cadence::BiWeekly => fortnight_serial(end)? - fortnight_serial(start)?,
+        Cadence::Weekly => week_serial(end) - week_serial(start),
+        Cadence::IsoBiWeekly => two_weeks_serial(end) - two_weeks_serial(start),
+        Cadence::Quarterly => quarter_serial(end) - quarter_serial(start),
     };
 
     Ok(elapsed as u16)
@@ -228,6 +308,30 @@ fn parse_legacy_period_label(value: &str) -> CalendarResult<(Date, Granularity)>
     };
 
     let year = Year::from_str(year_part)?;
+
+    if let Some(quarter) = month_part.strip_prefix('Q') {
+        let quarter = quarter
+            .parse::<i32>()
+            .ok()
+            .filter(|quarter| (1..=4).contains(quarter) && parts.next().is_none())
+            .ok_or(CalendarError::InvalidPeriod {
+                bad_value: value.to_owned(),
+            })?;
+        return Ok((quarter_end_date(year, quarter)?, Granularity::Quarter));
+    }
+
+    if let Some(week) = month_part.strip_prefix('W') {
+        let sunday = week
+            .parse::<u32>()
+            .ok()
+            .filter(|_| parts.next().is_none())
+            .and_then(|week| NaiveDate::from_isoywd_opt(*year as i32, week, Weekday::Sun))
+            .ok_or(CalendarError::InvalidPeriod {
+                bad_value: value.to_owned(),
+            })?;
+        return Ok((Date::from(sunday), Granularity::Week));
+    }
+
     let month = Month::from_str(month_part)?;
 
     if let Some(rest) = parts.next() {
@@ -248,7 +352,10 @@ fn parse_legacy_period_label(value: &str) -> CalendarResult<(Date, Granularity)>
 
 /// Parses a user-facing period label into a period-end date using cadence rules.
 ///
-/// Supports legacy labels (`YYYY-MM`, `YYYY-MM-first-half`) and full dates (`YYYY-MM-DD`).
+/// Supports legacy labels (`YYYY-MM`, `YYYY-MM-first-half`), quarters
+/// (`YYYY-Q2`), ISO weeks (`YYYY-W20`) and full dates (`YYYY-MM-DD`). A label
+/// is accepted if a whole number of its periods fits in the cadence period,
+/// e.g. a month label for quarterly cadence, see [`Cadence::validate`].
 ///
 /// # Examples
 /// ```
@@ -260,6 +367,12 @@ fn parse_legacy_period_label(value: &str) -> CalendarResult<(Date, Granularity)>
 ///
 /// let fortnight = parse_period_label_for_cadence("2025-02-first-half", Cadence::BiWeekly).unwrap();
 /// assert_eq!(fortnight.to_string(), "2025-02-14");
+///
+/// let quarter = parse_period_label_for_cadence("2025-Q2", Cadence::Quarterly).unwrap();
+/// assert_eq!(quarter.to_string(), "2025-06-30");
+///
+/// let week = parse_period_label_for_cadence("2025-W20", Cadence::Weekly).unwrap();
+/// assert_eq!(week.to_string(), "2025-05-18");
 /// ```
 pub fn parse_period_label_for_cadence(value: &str, cadence: Cadence) -> CalendarResult<Date> {
     if let Ok((date, unit)) = parse_legacy_period_label(value) {
@@ -270,7 +383,11 @@ pub fn parse_period_label_for_cadence(value: &str, cadence: Cadence) -> Calendar
             (Cadence::BiWeekly, Granularity::Month) => {
                 Err(CalendarError::CannotExpenseForMonthWhenCadenceIsBiWeekly)
             }
-            _ => period_end_for_cadence(date, cadence),
+            _ if cadence.validate(unit) => period_end_for_cadence(date, cadence),
+            _ => Err(CalendarError::PeriodLabelDoesNotMatchCadence {
+                label: value.to_owned(),
+                cadence,
+            }),
         };
     }
 
@@ -344,7 +461,91 @@ pub fn calculate_period_number(
     Ok(number)
 }
 
-fn period_bounds(period_end: Date, cadence: Cadence) -> CalendarResult<(Date, Date)> {
+/// Finds the period-end date of the period numbered `number`, the inverse of
+/// [`calculate_period_number`].
+///
+/// Periods in `record_of_periods_off` are skipped, since they are never
+/// numbered.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_foundation;
+/// use indexmap::IndexSet;
+/// use klirr_foundation::*;
+///
+/// let offset_date = "2024-01-31".parse::<Date>().unwrap();
+/// let periods_off = ["2024-03-31".parse::<Date>().unwrap()]
+///     .into_iter()
+///     .collect::<IndexSet<_>>();
+///
+/// let period_end = period_end_for_period_number(
+///     100,
+///     &offset_date,
+///     103,
+///     Cadence::Monthly,
+///     true,
+///     &periods_off,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(period_end.to_string(), "2024-04-30");
+/// ```
+pub fn period_end_for_period_number(
+    offset_number: u16,
+    offset_date: &Date,
+    number: u16,
+    cadence: Cadence,
+    is_expenses: bool,
+    record_of_periods_off: &IndexSet<Date>,
+) -> CalendarResult<Date> {
+    let offset_date = period_end_for_cadence(*offset_date, cadence)?;
+    if record_of_periods_off.contains(&offset_date) {
+        return Err(CalendarError::RecordsOffMustNotContainOffsetPeriod {
+            offset_period: offset_date.to_string(),
+        });
+    }
+
+    let target_number = if is_expenses {
+        number.checked_sub(1)
+    } else {
+        Some(number)
+    }
+    .filter(|target_number| *target_number >= offset_number)
+    .ok_or(CalendarError::NumberPrecedesOffset {
+        number,
+        offset: offset_number,
+    })?;
+
+    let periods_off = record_of_periods_off
+        .iter()
+        .map(|period_off| period_end_for_cadence(*period_off, cadence))
+        .collect::<CalendarResult<IndexSet<_>>>()?;
+
+    let mut period_end = offset_date;
+    let mut period_number = offset_number;
+    while period_number < target_number {
+        period_end = shift_period_end(period_end, cadence.max_granularity(), 1)?;
+        if !periods_off.contains(&period_end) {
+            period_number += 1;
+        }
+    }
+    Ok(period_end)
+}
+
+/// Returns the first and last date, both inclusive, of the period of
+/// `cadence` that `period_end` falls in.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_foundation;
+/// use klirr_foundation::*;
+///
+/// let (start, end) = period_bounds("2025-05-20".parse().unwrap(), Cadence::BiWeekly).unwrap();
+///
+/// assert_eq!(start.to_string(), "2025-05-16");
+/// assert_eq!(end.to_string(), "2025-05-31");
+/// ```
+pub fn period_bounds(period_end: Date, cadence: Cadence) -> CalendarResult<(Date, Date)> {
     let period_end = period_end_for_cadence(period_end, cadence)?;
     let start = match cadence {
         Cadence::Monthly => Date::builder()
@@ -367,18 +568,28 @@ fn period_bounds(period_end: Date, cadence: Cadence) -> CalendarResult<(Date, Da
                     .build(),
             }
         }
+        Cadence::Weekly => date_from_days_from_ce(naive_date(period_end).num_days_from_ce() - 6)?,
+        Cadence::IsoBiWeekly => {
+            date_from_days_from_ce(naive_date(period_end).num_days_from_ce() - 13)?
+        }
+        Cadence::Quarterly => Date::builder()
+            .year(*period_end.year())
+            .month(Month::try_from(*period_end.month() as i32 - 2)?)
+            .day(Day::try_from(1).expect("1 is a valid day"))
+            .build(),
     };
 
     Ok((start, period_end))
 }
 
-/// Counts weekdays (Mon–Fri) in the inclusive range `[start, end]`, excluding
-/// any date in `bank_holidays`. A holiday that already falls on a weekend is a
-/// no-op, so it is never double-counted.
+/// Counts the working days of `work_schedule` in the inclusive range
+/// `[start, end]`, excluding any date in `bank_holidays`. A holiday that
+/// already falls on a day off is a no-op, so it is never double-counted.
 fn working_days_between(
     start: Date,
     end: Date,
     bank_holidays: &BankHolidays,
+    work_schedule: &WorkSchedule,
 ) -> CalendarResult<Quantity> {
     let start = NaiveDate::from_ymd_opt(
         **start.year() as i32,
@@ -402,11 +613,7 @@ fn working_days_between(
     let mut current = start;
     let mut working_days = 0;
     while current <= end {
-        let is_weekday = matches!(
-            current.weekday(),
-            Weekday::Mon | Weekday::Tue | Weekday::Wed | Weekday::Thu | Weekday::Fri
-        );
-        if is_weekday && !holidays.contains(&current) {
+        if work_schedule.is_working_day(current.weekday()) && !holidays.contains(&current) {
             working_days += 1;
         }
         current = current.succ_opt().ok_or(CalendarError::InvalidDate {
@@ -417,20 +624,67 @@ fn working_days_between(
     Ok(Quantity::from(working_days))
 }
 
+/// Counts the `days_off` in the inclusive range `[start, end]`, half days as
+/// half, skipping days off that are not working days of `work_schedule` or
+/// that are in `bank_holidays`, so that they are never double-counted.
+fn days_off_between(
+    start: Date,
+    end: Date,
+    days_off: &DaysOff,
+    bank_holidays: &BankHolidays,
+    work_schedule: &WorkSchedule,
+) -> Quantity {
+    days_off
+        .iter()
+        .filter(|day| (start..=end).contains(day.date()))
+        .filter(|day| work_schedule.is_working_day(naive_date(*day.date()).weekday()))
+        .filter(|day| !bank_holidays.contains(day.date()))
+        .fold(Quantity::ZERO, |acc, day| acc + day.quantity())
+}
+
 fn working_days_in_period(
     period_end: Date,
     cadence: Cadence,
     bank_holidays: &BankHolidays,
+    work_schedule: &WorkSchedule,
+    days_off: &DaysOff,
 ) -> CalendarResult<Quantity> {
     let (start, end) = period_bounds(period_end, cadence)?;
-    working_days_between(start, end, bank_holidays)
+    let working_days = working_days_between(start, end, bank_holidays, work_schedule)?;
+    Ok(working_days - days_off_between(start, end, days_off, bank_holidays, work_schedule))
+}
+
+/// Counts the periods of `unit` in the period of `cadence` ending at
+/// `period_end`, e.g. three months in a quarter.
+fn units_in_period(
+    period_end: Date,
+    unit: Granularity,
+    cadence: Cadence,
+) -> CalendarResult<Quantity> {
+    let (start, end) = period_bounds(period_end, cadence)?;
+    let mut unit_end = period_end_for_unit(start, unit)?;
+    let mut units = 1;
+    while unit_end < end {
+        unit_end = shift_period_end(unit_end, unit, 1)?;
+        units += 1;
+    }
+    Ok(Quantity::from(units))
 }
 
 /// Calculates billable quantity for a period-end date and cadence.
 ///
 /// `bank_holidays` are deducted from billable working days for day- and
 /// hour-granularity rates only; monthly/fortnightly fixed rates are unaffected.
-/// Pass [`BankHolidays::default`] (empty) to count every weekday.
+/// Pass [`BankHolidays::default`] (empty) to count every working day.
+///
+/// Day- and hour-granularity quantities follow `work_schedule`: only its
+/// working days are counted, each with its hours per day, scaled by its part
+/// time factor. Pass [`WorkSchedule::default`] for eight hours Monday to
+/// Friday.
+///
+/// `days_off` are deducted from those working days, half days as half. Days
+/// off outside the period, on days you do not work or on `bank_holidays` are
+/// ignored. Pass [`DaysOff::default`] (empty) to deduct none.
 ///
 /// # Examples
 /// ```
@@ -446,6 +700,8 @@ fn working_days_in_period(
 ///     Cadence::Monthly,
 ///     &IndexSet::default(),
 ///     &BankHolidays::default(),
+///     &WorkSchedule::default(),
+///     &DaysOff::default(),
 /// )
 /// .unwrap();
 ///
@@ -457,6 +713,8 @@ pub fn quantity_in_period(
     cadence: Cadence,
     record_of_periods_off: &IndexSet<Date>,
     bank_holidays: &BankHolidays,
+    work_schedule: &WorkSchedule,
+    days_off: &DaysOff,
 ) -> CalendarResult<Quantity> {
     let target_date = period_end_for_cadence(*target_date, cadence)?;
 
@@ -472,19 +730,34 @@ pub fn quantity_in_period(
     ) {
         return Err(CalendarError::CannotInvoiceForMonthWhenCadenceIsBiWeekly);
     }
+    if !cadence.validate(granularity) {
+        return Err(CalendarError::GranularityDoesNotFitCadence {
+            granularity,
+            cadence,
+        });
+    }
 
     match granularity {
-        Granularity::Month => Ok(Quantity::ONE),
-        Granularity::Fortnight => match cadence {
-            Cadence::Monthly => Ok(Quantity::TWO),
-            Cadence::BiWeekly => Ok(Quantity::ONE),
-        },
-        Granularity::Day => working_days_in_period(target_date, cadence, bank_holidays),
-        Granularity::Hour => Ok(Quantity::EIGHT.mul(*working_days_in_period(
-            target_date,
-            cadence,
-            bank_holidays,
-        )?)),
+        Granularity::Week
+        | Granularity::TwoWeeks
+        | Granularity::Fortnight
+        | Granularity::Month
+        | Granularity::Quarter => units_in_period(target_date, granularity, cadence),
+        Granularity::Day | Granularity::Hour => {
+            let days = working_days_in_period(
+                target_date,
+                cadence,
+                bank_holidays,
+                work_schedule,
+                days_off,
+            )?
+            .mul(*work_schedule.part_time_factor());
+            if granularity == Granularity::Hour {
+                Ok(days.mul(**work_schedule.hours_per_day()))
+            } else {
+                Ok(days)
+            }
+        }
     }
 }
 
@@ -736,6 +1009,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn period_end_for_period_number_is_inverse_of_calculate_period_number() {
+        let offset_date = d("2024-01-31");
+        let periods_off = periods_off([d("2024-03-31"), d("2024-06-30")]);
+        for target in ["2024-01-31", "2024-02-29", "2024-04-30", "2024-07-31"] {
+            for is_expenses in [false, true] {
+                let number = calculate_period_number(
+                    100,
+                    &offset_date,
+                    &d(target),
+                    Cadence::Monthly,
+                    is_expenses,
+                    &periods_off,
+                )
+                .unwrap();
+                let period_end = period_end_for_period_number(
+                    100,
+                    &offset_date,
+                    number,
+                    Cadence::Monthly,
+                    is_expenses,
+                    &periods_off,
+                )
+                .unwrap();
+                assert_eq!(period_end, d(target));
+            }
+        }
+    }
+
+    #[test]
+    fn period_end_for_period_number_biweekly() {
+        let period_end = period_end_for_period_number(
+            10,
+            &d("2025-01-15"),
+            13,
+            Cadence::BiWeekly,
+            false,
+            &IndexSet::default(),
+        )
+        .unwrap();
+        assert_eq!(period_end, d("2025-02-28"));
+    }
+
+    #[test]
+    fn period_end_for_period_number_rejects_number_before_offset() {
+        let result = period_end_for_period_number(
+            100,
+            &d("2024-01-31"),
+            100,
+            Cadence::Monthly,
+            true,
+            &IndexSet::default(),
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            CalendarError::NumberPrecedesOffset {
+                number: 100,
+                offset: 100
+            }
+        );
+    }
+
     #[test]
     fn period_bounds_for_biweekly_first_and_second_half() {
         let (start_first, end_first) = period_bounds(d("2025-05-15"), Cadence::BiWeekly).unwrap();
@@ -753,12 +1088,22 @@ mod tests {
 
     #[test]
     fn working_days_between_counts_weekdays_only() {
-        let weekends =
-            working_days_between(d("2025-05-17"), d("2025-05-18"), &no_holidays()).unwrap();
+        let weekends = working_days_between(
+            d("2025-05-17"),
+            d("2025-05-18"),
+            &no_holidays(),
+            &WorkSchedule::default(),
+        )
+        .unwrap();
         assert_eq!(*weekends, dec!(0));
 
-        let weekday =
-            working_days_between(d("2025-05-19"), d("2025-05-19"), &no_holidays()).unwrap();
+        let weekday = working_days_between(
+            d("2025-05-19"),
+            d("2025-05-19"),
+            &no_holidays(),
+            &WorkSchedule::default(),
+        )
+        .unwrap();
         assert_eq!(*weekday, dec!(1));
     }
 
@@ -766,12 +1111,14 @@ mod tests {
     fn working_days_between_excludes_bank_holiday() {
         // 2025-05-19 .. 2025-05-23 is Mon..Fri => 5 working days.
         let span = (d("2025-05-19"), d("2025-05-23"));
-        let full = working_days_between(span.0, span.1, &no_holidays()).unwrap();
+        let full =
+            working_days_between(span.0, span.1, &no_holidays(), &WorkSchedule::default()).unwrap();
         assert_eq!(*full, dec!(5));
 
         // Marking the Wednesday as a holiday removes exactly one working day.
         let holidays = BankHolidays::new([d("2025-05-21")]);
-        let reduced = working_days_between(span.0, span.1, &holidays).unwrap();
+        let reduced =
+            working_days_between(span.0, span.1, &holidays, &WorkSchedule::default()).unwrap();
         assert_eq!(*reduced, dec!(4));
     }
 
@@ -780,7 +1127,8 @@ mod tests {
         // 2025-05-17 is a Saturday — marking it as a holiday changes nothing.
         let span = (d("2025-05-19"), d("2025-05-23"));
         let holidays = BankHolidays::new([d("2025-05-17")]);
-        let count = working_days_between(span.0, span.1, &holidays).unwrap();
+        let count =
+            working_days_between(span.0, span.1, &holidays, &WorkSchedule::default()).unwrap();
         assert_eq!(*count, dec!(5));
     }
 
@@ -794,6 +1142,8 @@ mod tests {
             Cadence::Monthly,
             &IndexSet::default(),
             &no_holidays(),
+            &WorkSchedule::default(),
+            &DaysOff::default(),
         )
         .unwrap();
         let hours_reduced = quantity_in_period(
@@ -802,6 +1152,8 @@ mod tests {
             Cadence::Monthly,
             &IndexSet::default(),
             &days_off_one,
+            &WorkSchedule::default(),
+            &DaysOff::default(),
         )
         .unwrap();
         // One holiday removes one working day == 8 hours.
@@ -817,6 +1169,8 @@ mod tests {
             Cadence::Monthly,
             &IndexSet::default(),
             &no_holidays(),
+            &WorkSchedule::default(),
+            &DaysOff::default(),
         )
         .unwrap();
         let fortnight = quantity_in_period(
@@ -825,6 +1179,8 @@ mod tests {
             Cadence::BiWeekly,
             &IndexSet::default(),
             &no_holidays(),
+            &WorkSchedule::default(),
+            &DaysOff::default(),
         )
         .unwrap();
         assert!(fortnight < monthly);
@@ -839,6 +1195,8 @@ mod tests {
                 Cadence::Monthly,
                 &IndexSet::default(),
                 &no_holidays(),
+                &WorkSchedule::default(),
+                &DaysOff::default(),
             )
             .unwrap(),
             Quantity::ONE
@@ -850,6 +1208,8 @@ mod tests {
                 Cadence::Monthly,
                 &IndexSet::default(),
                 &no_holidays(),
+                &WorkSchedule::default(),
+                &DaysOff::default(),
             )
             .unwrap(),
             Quantity::TWO
@@ -861,6 +1221,8 @@ mod tests {
                 Cadence::BiWeekly,
                 &IndexSet::default(),
                 &no_holidays(),
+                &WorkSchedule::default(),
+                &DaysOff::default(),
             )
             .unwrap(),
             Quantity::ONE
@@ -876,6 +1238,8 @@ mod tests {
             Cadence::BiWeekly,
             &IndexSet::default(),
             &no_holidays(),
+            &WorkSchedule::default(),
+            &DaysOff::default(),
         )
         .unwrap();
         let hours = quantity_in_period(
@@ -884,11 +1248,98 @@ mod tests {
             Cadence::BiWeekly,
             &IndexSet::default(),
             &no_holidays(),
+            &WorkSchedule::default(),
+            &DaysOff::default(),
         )
         .unwrap();
         assert_eq!(hours, Quantity::EIGHT.mul(*days));
     }
 
+    #[test]
+    fn quantity_in_period_follows_work_schedule() {
+        // May 2025 has 22 weekdays, of which 17 are Monday to Thursday.
+        let target = d("2025-05-31");
+        let quantity = |granularity, work_schedule: &WorkSchedule| {
+            quantity_in_period(
+                &target,
+                granularity,
+                Cadence::Monthly,
+                &IndexSet::default(),
+                &no_holidays(),
+                work_schedule,
+                &DaysOff::default(),
+            )
+            .unwrap()
+        };
+        let short_days = WorkSchedule::builder()
+            .hours_per_day(dec!(7.5))
+            .build()
+            .unwrap();
+        assert_eq!(*quantity(Granularity::Day, &short_days), dec!(22));
+        assert_eq!(*quantity(Granularity::Hour, &short_days), dec!(165));
+
+        let four_day_week = WorkSchedule::builder()
+            .working_days([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu])
+            .build()
+            .unwrap();
+        assert_eq!(*quantity(Granularity::Day, &four_day_week), dec!(17));
+        assert_eq!(*quantity(Granularity::Hour, &four_day_week), dec!(136));
+
+        // 7.5 hours Monday to Thursday at 80%.
+        let part_time = WorkSchedule::sample_other();
+        assert_eq!(*quantity(Granularity::Day, &part_time), dec!(13.6));
+        assert_eq!(*quantity(Granularity::Hour, &part_time), dec!(102));
+    }
+
+    #[test]
+    fn quantity_in_period_deducts_days_off_on_working_days_only() {
+        // May 2025 has 22 weekdays, Ascension Day 2025-05-29 is a holiday.
+        let target = d("2025-05-31");
+        let holidays = BankHolidays::new([d("2025-05-29")]);
+        let days_off = [
+            "2025-05-12..2025-05-18",
+            "2025-05-20:half",
+            "2025-05-29",
+            "2025-06-02",
+        ]
+        .into_iter()
+        .map(|days| days.parse::<DaysOff>().unwrap())
+        .collect::<DaysOff>();
+        let quantity = |granularity| {
+            quantity_in_period(
+                &target,
+                granularity,
+                Cadence::Monthly,
+                &IndexSet::default(),
+                &holidays,
+                &WorkSchedule::default(),
+                &days_off,
+            )
+            .unwrap()
+        };
+        // 21 working days, minus Monday to Friday and half of Tuesday the
+        // week after; the weekend, the holiday and June are not deducted.
+        assert_eq!(*quantity(Granularity::Day), dec!(15.5));
+        assert_eq!(*quantity(Granularity::Hour), dec!(124));
+        assert_eq!(quantity(Granularity::Month), Quantity::ONE);
+    }
+
+    #[test]
+    fn quantity_in_period_work_schedule_does_not_affect_fixed_units() {
+        let target = d("2025-05-31");
+        let month = quantity_in_period(
+            &target,
+            Granularity::Month,
+            Cadence::Monthly,
+            &IndexSet::default(),
+            &no_holidays(),
+            &WorkSchedule::sample_other(),
+            &DaysOff::default(),
+        )
+        .unwrap();
+        assert_eq!(month, Quantity::ONE);
+    }
+
     #[test]
     fn quantity_in_period_fails_when_target_is_in_periods_off() {
         let target = d("2025-05-31");
@@ -899,6 +1350,8 @@ mod tests {
             Cadence::Monthly,
             &periods_off,
             &no_holidays(),
+            &WorkSchedule::default(),
+            &DaysOff::default(),
         );
         assert_eq!(
             result.unwrap_err(),
@@ -917,10 +1370,288 @@ mod tests {
             Cadence::BiWeekly,
             &IndexSet::default(),
             &no_holidays(),
+            &WorkSchedule::default(),
+            &DaysOff::default(),
         );
         assert_eq!(
             result.unwrap_err(),
             CalendarError::CannotInvoiceForMonthWhenCadenceIsBiWeekly
         );
     }
+
+    #[test]
+    fn period_end_for_unit_week_two_weeks_and_quarter() {
+        // 2025-05-14 is a Wednesday in ISO week 20.
+        assert_eq!(
+            period_end_for_unit(d("2025-05-14"), Granularity::Week).unwrap(),
+            d("2025-05-18")
+        );
+        assert_eq!(
+            period_end_for_unit(d("2025-05-18"), Granularity::Week).unwrap(),
+            d("2025-05-18")
+        );
+        // Two weeks periods start every 14 days from Monday 2024-01-01.
+        assert_eq!(
+            period_end_for_unit(d("2025-05-14"), Granularity::TwoWeeks).unwrap(),
+            d("2025-05-18")
+        );
+        assert_eq!(
+            period_end_for_unit(d("2025-05-20"), Granularity::TwoWeeks).unwrap(),
+            d("2025-06-01")
+        );
+        assert_eq!(
+            period_end_for_unit(d("2024-01-01"), Granularity::TwoWeeks).unwrap(),
+            d("2024-01-14")
+        );
+        // The period from 2026-12-28, in ISO week 53 of 2026, runs into ISO
+        // week 1 of 2027.
+        assert_eq!(
+            period_end_for_unit(d("2026-12-27"), Granularity::TwoWeeks).unwrap(),
+            d("2026-12-27")
+        );
+        assert_eq!(
+            period_end_for_unit(d("2026-12-28"), Granularity::TwoWeeks).unwrap(),
+            d("2027-01-10")
+        );
+        assert_eq!(
+            period_end_for_unit(d("2027-01-04"), Granularity::TwoWeeks).unwrap(),
+            d("2027-01-10")
+        );
+        assert_eq!(
+            period_end_for_unit(d("2025-05-14"), Granularity::Quarter).unwrap(),
+            d("2025-06-30")
+        );
+    }
+
+    #[test]
+    fn shift_period_end_week_two_weeks_and_quarter() {
+        assert_eq!(
+            shift_period_end(d("2025-05-18"), Granularity::Week, 1).unwrap(),
+            d("2025-05-25")
+        );
+        assert_eq!(
+            shift_period_end(d("2025-01-05"), Granularity::Week, -1).unwrap(),
+            d("2024-12-29")
+        );
+        assert_eq!(
+            shift_period_end(d("2025-05-18"), Granularity::TwoWeeks, 1).unwrap(),
+            d("2025-06-01")
+        );
+        assert_eq!(
+            shift_period_end(d("2025-12-31"), Granularity::Quarter, 1).unwrap(),
+            d("2026-03-31")
+        );
+        assert_eq!(
+            shift_period_end(d("2025-03-31"), Granularity::Quarter, -1).unwrap(),
+            d("2024-12-31")
+        );
+    }
+
+    #[test]
+    fn elapsed_periods_since_weekly_iso_biweekly_and_quarterly() {
+        assert_eq!(
+            elapsed_periods_since(d("2025-05-18"), d("2025-06-15"), Cadence::Weekly).unwrap(),
+            4
+        );
+        assert_eq!(
+            elapsed_periods_since(d("2024-12-29"), d("2025-01-05"), Cadence::Weekly).unwrap(),
+            1
+        );
+        assert_eq!(
+            elapsed_periods_since(d("2025-05-18"), d("2025-06-15"), Cadence::IsoBiWeekly).unwrap(),
+            2
+        );
+        assert_eq!(
+            elapsed_periods_since(d("2025-03-31"), d("2025-12-31"), Cadence::Quarterly).unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn period_bounds_for_weekly_iso_biweekly_and_quarterly() {
+        assert_eq!(
+            period_bounds(d("2025-05-14"), Cadence::Weekly).unwrap(),
+            (d("2025-05-12"), d("2025-05-18"))
+        );
+        assert_eq!(
+            period_bounds(d("2025-05-14"), Cadence::IsoBiWeekly).unwrap(),
+            (d("2025-05-05"), d("2025-05-18"))
+        );
+        assert_eq!(
+            period_bounds(d("2025-05-14"), Cadence::Quarterly).unwrap(),
+            (d("2025-04-01"), d("2025-06-30"))
+        );
+    }
+
+    #[test]
+    fn parse_quarter_and_week_labels() {
+        assert_eq!(
+            parse_period_label_for_cadence("2025-Q2", Cadence::Quarterly).unwrap(),
+            d("2025-06-30")
+        );
+        assert_eq!(
+            parse_period_label_for_cadence("2025-05", Cadence::Quarterly).unwrap(),
+            d("2025-06-30")
+        );
+        assert_eq!(
+            parse_period_label_for_cadence("2025-W20", Cadence::Weekly).unwrap(),
+            d("2025-05-18")
+        );
+        assert_eq!(
+            parse_period_label_for_cadence("2025-W20", Cadence::IsoBiWeekly).unwrap(),
+            d("2025-05-18")
+        );
+        assert_eq!(
+            parse_period_label_for_cadence("2025-05-14", Cadence::Weekly).unwrap(),
+            d("2025-05-18")
+        );
+    }
+
+    #[test]
+    fn parse_label_rejects_label_not_matching_cadence() {
+        assert_eq!(
+            parse_period_label_for_cadence("2025-W20", Cadence::Monthly).unwrap_err(),
+            CalendarError::PeriodLabelDoesNotMatchCadence {
+                label: "2025-W20".to_owned(),
+                cadence: Cadence::Monthly
+            }
+        );
+        assert_eq!(
+            parse_period_label_for_cadence("2025-05", Cadence::Weekly).unwrap_err(),
+            CalendarError::PeriodLabelDoesNotMatchCadence {
+                label: "2025-05".to_owned(),
+                cadence: Cadence::Weekly
+            }
+        );
+        assert!(parse_period_label_for_cadence("2025-Q5", Cadence::Quarterly).is_err());
+        assert!(parse_period_label_for_cadence("2025-W54", Cadence::Weekly).is_err());
+    }
+
+    #[test]
+    fn quantity_in_period_for_new_cadences() {
+        let quantity = |target: &str, granularity, cadence| {
+            quantity_in_period(
+                &d(target),
+                granularity,
+                cadence,
+                &IndexSet::default(),
+                &no_holidays(),
+                &WorkSchedule::default(),
+                &DaysOff::default(),
+            )
+        };
+        assert_eq!(
+            quantity("2025-06-30", Granularity::Month, Cadence::Quarterly).unwrap(),
+            Quantity::from(3)
+        );
+        assert_eq!(
+            quantity("2025-06-30", Granularity::Fortnight, Cadence::Quarterly).unwrap(),
+            Quantity::from(6)
+        );
+        assert_eq!(
+            quantity("2025-06-30", Granularity::Quarter, Cadence::Quarterly).unwrap(),
+            Quantity::ONE
+        );
+        assert_eq!(
+            quantity("2025-05-18", Granularity::Week, Cadence::IsoBiWeekly).unwrap(),
+            Quantity::TWO
+        );
+        assert_eq!(
+            quantity("2025-05-18", Granularity::TwoWeeks, Cadence::IsoBiWeekly).unwrap(),
+            Quantity::ONE
+        );
+        assert_eq!(
+            quantity("2025-05-18", Granularity::Day, Cadence::Weekly).unwrap(),
+            Quantity::from(5)
+        );
+        assert_eq!(
+            quantity("2025-05-18", Granularity::Day, Cadence::IsoBiWeekly).unwrap(),
+            Quantity::from(10)
+        );
+        assert_eq!(
+            quantity("2025-05-31", Granularity::Week, Cadence::Monthly).unwrap_err(),
+            CalendarError::GranularityDoesNotFitCadence {
+                granularity: Granularity::Week,
+                cadence: Cadence::Monthly
+            }
+        );
+    }
+
+    #[test]
+    fn calculate_period_number_is_idempotent_within_period() {
+        for (cadence, offset_date, dates_of_next_period) in [
+            (
+                Cadence::Weekly,
+                "2025-05-18",
+                ["2025-05-19", "2025-05-22", "2025-05-25"],
+            ),
+            (
+                Cadence::IsoBiWeekly,
+                "2025-05-18",
+                ["2025-05-19", "2025-05-26", "2025-06-01"],
+            ),
+            (
+                Cadence::Quarterly,
+                "2025-03-31",
+                ["2025-04-01", "2025-05-15", "2025-06-30"],
+            ),
+        ] {
+            for date in dates_of_next_period {
+                let number = calculate_period_number(
+                    100,
+                    &d(offset_date),
+                    &d(date),
+                    cadence,
+                    false,
+                    &IndexSet::default(),
+                )
+                .unwrap();
+                assert_eq!(number, 101, "{cadence} {date}");
+            }
+        }
+    }
+
+    #[test]
+    fn period_end_for_period_number_is_inverse_for_new_cadences() {
+        for (cadence, offset_date, targets) in [
+            (
+                Cadence::Weekly,
+                "2024-12-22",
+                ["2024-12-29", "2025-01-05", "2025-02-02"],
+            ),
+            (
+                Cadence::IsoBiWeekly,
+                "2024-12-29",
+                ["2025-01-12", "2025-01-26", "2025-03-09"],
+            ),
+            (
+                Cadence::Quarterly,
+                "2024-09-30",
+                ["2024-12-31", "2025-03-31", "2025-12-31"],
+            ),
+        ] {
+            let periods_off = periods_off([d(targets[1])]);
+            for target in [targets[0], targets[2]] {
+                let number = calculate_period_number(
+                    100,
+                    &d(offset_date),
+                    &d(target),
+                    cadence,
+                    false,
+                    &periods_off,
+                )
+                .unwrap();
+                let period_end = period_end_for_period_number(
+                    100,
+                    &d(offset_date),
+                    number,
+                    cadence,
+                    false,
+                    &periods_off,
+                )
+                .unwrap();
+                assert_eq!(period_end, d(target), "{cadence}");
+            }
+        }
+    }
 }
@@ -0,0 +1,357 @@
+use std::cell::{Ref, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+use chrono::{Local, NaiveDate};
+use log::debug;
+use rust_decimal::Decimal;
+
+use super::{ExchangeRateProvider, ExchangeRatesError, Result, get_with_reqwest};
+
+const ECB_API: &str = "https://www.ecb.europa.eu/stats/eurofxref";
+
+/// The documents of the European Central Bank with the rates of the latest
+/// day, of the last 90 days and of every day since 1999, smallest first.
+const ECB_DOCUMENTS: [&str; 3] = [
+    "eurofxref-daily.xml",
+    "eurofxref-hist-90d.xml",
+    "eurofxref-hist.xml",
+];
+
+/// Dates at most this many days ago are first looked up in the daily
+/// document, which covers a weekend or holiday before today.
+const MAX_DAYS_AGO_IN_DAILY_DOCUMENT: i64 = 4;
+
+/// Dates at most this many days ago are first looked up in the document of
+/// the last 90 days, with a margin since it ends on the latest working day.
+const MAX_DAYS_AGO_IN_90_DAYS_DOCUMENT: i64 = 85;
+
+/// Number of decimals cross rates, between two currencies other than the
+/// euro, are rounded to.
+const CROSS_RATE_DECIMALS: u32 = 6;
+
+/// The euro reference rates of each day in a document of the European
+/// Central Bank, which has format:
+/// ```xml
+/// <gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+///   <Cube>
+///     <Cube time="2025-05-30">
+///       <Cube currency="USD" rate="1.1357"/>
+///       <Cube currency="SEK" rate="10.8985"/>
+///     </Cube>
+///   </Cube>
+/// </gesmes:Envelope>
+/// ```
+/// where each rate is the amount of the currency of one euro.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EcbRates(BTreeMap<NaiveDate, HashMap<String, Decimal>>);
+
+impl EcbRates {
+    /// Parses a daily or historic document of the European Central Bank.
+    ///
+    /// # Errors
+    /// Returns [`ExchangeRatesError::ParseError`] if the document is not
+    /// valid XML or has a malformed day or rate.
+    pub fn parse(xml: &str) -> Result<Self> {
+        let document = roxmltree::Document::parse(xml).map_err(ExchangeRatesError::parse_error)?;
+        let mut days = BTreeMap::new();
+        for day in document
+            .descendants()
+            .filter(|node| node.tag_name().name() == "Cube" && node.has_attribute("time"))
+        {
+            let time = day.attribute("time").unwrap_or_default();
+            let date = NaiveDate::from_str(time)
+                .map_err(|e| ExchangeRatesError::parse_error(format!("day '{time}': {e}")))?;
+            let mut rates = HashMap::new();
+            for cube in day
+                .children()
+                .filter(|child| child.tag_name().name() == "Cube")
+            {
+                let (Some(currency), Some(rate)) =
+                    (cube.attribute("currency"), cube.attribute("rate"))
+                else {
+                    continue;
+                };
+                let rate = Decimal::from_str(rate).map_err(|e| {
+                    ExchangeRatesError::parse_error(format!("rate of {currency} on {date}: {e}"))
+                })?;
+                rates.insert(currency.to_owned(), rate);
+            }
+            days.insert(date, rates);
+        }
+        Ok(Self(days))
+    }
+
+    /// Whether this has the rates of `date` or of an earlier day, i.e.
+    /// whether [`Self::rate`] finds the rates in effect on `date`.
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        self.0.keys().next().is_some_and(|first| *first <= date)
+    }
+
+    /// The amount in `to` of one unit of `from` at the rates of `date`, or of
+    /// the latest day before it without rates, e.g. a weekend or holiday.
+    ///
+    /// # Errors
+    /// Returns [`ExchangeRatesError::MissingRate`] if there are no rates of
+    /// `date` or earlier, or not for both currencies.
+    pub fn rate(&self, date: NaiveDate, from: &str, to: &str) -> Result<Decimal> {
+        let missing = || ExchangeRatesError::MissingRate {
+            target: to.to_owned(),
+            base: from.to_owned(),
+        };
+        let (_, rates) = self.0.range(..=date).next_back().ok_or_else(missing)?;
+        let euro_rate = |currency: &str| match currency {
+            "EUR" => Some(Decimal::ONE),
+            _ => rates.get(currency).copied(),
+        };
+        let from_rate = euro_rate(from).ok_or_else(missing)?;
+        let to_rate = euro_rate(to).ok_or_else(missing)?;
+        if from_rate.is_zero() {
+            return Err(missing());
+        }
+        Ok((to_rate / from_rate)
+            .round_dp(CROSS_RATE_DECIMALS)
+            .normalize())
+    }
+}
+
+/// Fetches the euro reference rates of the [European Central Bank][ecb],
+/// computing cross rates between other currencies from them.
+///
+/// The smallest document covering a date is picked by how many days ago the
+/// date is, falling back to the larger ones, and each document is fetched at
+/// most once.
+///
+/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/html/index.en.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EuropeanCentralBank {
+    /// The URL the documents are fetched from, e.g. of a local stub server in
+    /// tests.
+    base_url: String,
+
+    /// The day the age of looked up dates is counted from.
+    today: NaiveDate,
+
+    /// The fetched documents, in the order of [`ECB_DOCUMENTS`].
+    documents: [RefCell<Option<EcbRates>>; 3],
+}
+
+impl Default for EuropeanCentralBank {
+    fn default() -> Self {
+        Self::new(ECB_API)
+    }
+}
+
+impl EuropeanCentralBank {
+    /// Fetches the documents from `base_url` instead of the public one.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            today: Local::now().date_naive(),
+            documents: Default::default(),
+        }
+    }
+
+    /// Counts the age of looked up dates from `today` instead of the current
+    /// day.
+    pub fn with_today(self, today: NaiveDate) -> Self {
+        Self { today, ..self }
+    }
+
+    /// The document at `index` of [`ECB_DOCUMENTS`], fetched unless it was
+    /// fetched before.
+    fn document(&self, index: usize) -> Result<Ref<'_, EcbRates>> {
+        let document = &self.documents[index];
+        if document.borrow().is_none() {
+            document.replace(Some(self.fetch_document(ECB_DOCUMENTS[index])?));
+        }
+        Ok(Ref::map(document.borrow(), |rates| {
+            rates.as_ref().expect("document was fetched above")
+        }))
+    }
+
+    fn fetch_document(&self, name: &str) -> Result<EcbRates> {
+        let url = format!("{}/{name}", self.base_url);
+        debug!("Fetching ECB rates from {url}.");
+        let xml = get_with_reqwest(&url)?
+            .text()
+            .map_err(ExchangeRatesError::network_error)?;
+        EcbRates::parse(&xml)
+    }
+}
+
+impl ExchangeRateProvider for EuropeanCentralBank {
+    fn rate(&self, date: NaiveDate, from: &str, to: &str) -> Result<Decimal> {
+        if from == to {
+            return Ok(Decimal::ONE);
+        }
+        let days_ago = (self.today - date).num_days();
+        let first = if days_ago <= MAX_DAYS_AGO_IN_DAILY_DOCUMENT {
+            0
+        } else if days_ago <= MAX_DAYS_AGO_IN_90_DAYS_DOCUMENT {
+            1
+        } else {
+            2
+        };
+        for index in first..ECB_DOCUMENTS.len() {
+            let rates = self.document(index)?;
+            if rates.covers(date) {
+                return rates.rate(date, from, to);
+            }
+        }
+        Err(ExchangeRatesError::MissingRate {
+            target: to.to_owned(),
+            base: from.to_owned(),
+        })
+    }
+
+    fn source(&self) -> String {
+        "European Central Bank euro reference rates".to_owned()
+    }
+
+    fn cache_file_name(&self) -> Option<&'static str> {
+        Some("cached_rates_ecb")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+    use rust_decimal::dec;
+
+    fn document(days: &[(&str, &[(&str, &str)])]) -> String {
+        let days = days
+            .iter()
+            .map(|(time, rates)| {
+                let rates = rates
+                    .iter()
+                    .map(|(currency, rate)| format!("<Cube currency='{currency}' rate='{rate}'/>"))
+                    .collect::<String>();
+                format!("<Cube time='{time}'>{rates}</Cube>")
+            })
+            .collect::<String>();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+  <gesmes:subject>Reference rates</gesmes:subject>
+  <gesmes:Sender><gesmes:name>European Central Bank</gesmes:name></gesmes:Sender>
+  <Cube>{days}</Cube>
+</gesmes:Envelope>"#
+        )
+    }
+
+    fn d(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_euro_and_cross_rates() {
+        let sut = EcbRates::parse(&document(&[(
+            "2025-05-30",
+            &[("USD", "1.1357"), ("SEK", "10.8985"), ("GBP", "0.8429")],
+        )]))
+        .unwrap();
+        assert_eq!(
+            sut.rate(d("2025-05-30"), "EUR", "SEK").unwrap(),
+            dec!(10.8985)
+        );
+        assert_eq!(
+            sut.rate(d("2025-05-30"), "SEK", "EUR").unwrap(),
+            dec!(0.091756)
+        );
+        assert_eq!(
+            sut.rate(d("2025-05-30"), "GBP", "USD").unwrap(),
+            dec!(1.347372)
+        );
+    }
+
+    #[test]
+    fn uses_latest_day_on_or_before_date() {
+        let sut = EcbRates::parse(&document(&[
+            ("2025-05-29", &[("SEK", "10.9")]),
+            ("2025-05-30", &[("SEK", "10.8")]),
+        ]))
+        .unwrap();
+        assert_eq!(sut.rate(d("2025-06-01"), "EUR", "SEK").unwrap(), dec!(10.8));
+        assert_eq!(sut.rate(d("2025-05-29"), "EUR", "SEK").unwrap(), dec!(10.9));
+        assert!(!sut.covers(d("2025-05-28")));
+        assert!(matches!(
+            sut.rate(d("2025-05-28"), "EUR", "SEK"),
+            Err(ExchangeRatesError::MissingRate { .. })
+        ));
+        assert!(matches!(
+            sut.rate(d("2025-05-30"), "EUR", "XXX"),
+            Err(ExchangeRatesError::MissingRate { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_xml_is_parse_error() {
+        assert!(matches!(
+            EcbRates::parse("<Cube>"),
+            Err(ExchangeRatesError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn falls_back_to_history_of_90_days_from_stub_server() {
+        let server = MockServer::start();
+        let daily = server.mock(|when, then| {
+            when.method(GET).path("/eurofxref-daily.xml");
+            then.status(200)
+                .body(document(&[("2025-05-30", &[("SEK", "10.8985")])]));
+        });
+        let last_90_days = server.mock(|when, then| {
+            when.method(GET).path("/eurofxref-hist-90d.xml");
+            then.status(200).body(document(&[
+                ("2025-05-27", &[("SEK", "10.91")]),
+                ("2025-05-28", &[("SEK", "10.92")]),
+            ]));
+        });
+        let full_history = server.mock(|when, then| {
+            when.method(GET).path("/eurofxref-hist.xml");
+            then.status(200).body(document(&[]));
+        });
+
+        let sut = EuropeanCentralBank::new(server.base_url()).with_today(d("2025-05-30"));
+        let rate = sut.rate(d("2025-05-28"), "EUR", "SEK").unwrap();
+        let again = sut.rate(d("2025-05-27"), "EUR", "SEK").unwrap();
+
+        assert_eq!(rate, dec!(10.92));
+        assert_eq!(again, dec!(10.91));
+        daily.assert();
+        last_90_days.assert();
+        full_history.assert_hits(0);
+    }
+
+    #[test]
+    fn looks_up_old_dates_in_full_history_only_from_stub_server() {
+        let server = MockServer::start();
+        let daily = server.mock(|when, then| {
+            when.method(GET).path("/eurofxref-daily.xml");
+            then.status(200).body(document(&[]));
+        });
+        let last_90_days = server.mock(|when, then| {
+            when.method(GET).path("/eurofxref-hist-90d.xml");
+            then.status(200).body(document(&[]));
+        });
+        let full_history = server.mock(|when, then| {
+            when.method(GET).path("/eurofxref-hist.xml");
+            then.status(200).body(document(&[
+                ("2024-01-02", &[("SEK", "11.1")]),
+                ("2024-01-03", &[("SEK", "11.2")]),
+            ]));
+        });
+
+        let sut = EuropeanCentralBank::new(server.base_url()).with_today(d("2025-05-30"));
+        let rate = sut.rate(d("2024-01-02"), "EUR", "SEK").unwrap();
+        let again = sut.rate(d("2024-01-03"), "SEK", "EUR").unwrap();
+
+        assert_eq!(rate, dec!(11.1));
+        assert_eq!(again, dec!(0.089286));
+        daily.assert_hits(0);
+        last_90_days.assert_hits(0);
+        full_history.assert();
+    }
+}
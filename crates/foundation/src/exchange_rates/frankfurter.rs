@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use getset::Getters;
+use log::debug;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use super::{ExchangeRateProvider, ExchangeRatesError, Result, get_with_reqwest};
+
+const FRANKFURTER_API: &str = "https://api.frankfurter.app";
+
+/// Where the rates of the [Frankfurter API][api] come from, stated on
+/// invoices showing converted amounts.
+///
+/// [api]: https://frankfurter.dev/
+pub const FRANKFURTER_RATES_SOURCE: &str =
+    "European Central Bank reference rates (frankfurter.app)";
+
+/// Response has format:
+/// ```json
+/// {
+///   "amount": 1.0,
+///   "base": "GBP",
+///   "date": "2025-04-30",
+///   "rates": {
+///     "EUR": 1.174
+///   }
+///  }
+/// ```
+/// as given by `curl -s "https://api.frankfurter.app/2025-05-01?from=GBP&to=EUR"`
+#[derive(Debug, Clone, Deserialize, Getters)]
+struct FrankfurterApiResponse {
+    #[getset(get = "pub")]
+    rates: HashMap<String, Decimal>,
+}
+
+pub trait DeserializableResponse {
+    fn json<T: serde::de::DeserializeOwned>(self) -> Result<T>;
+}
+
+impl DeserializableResponse for reqwest::blocking::Response {
+    fn json<T: serde::de::DeserializeOwned>(self) -> Result<T> {
+        self.json().map_err(ExchangeRatesError::parse_error)
+    }
+}
+
+/// Fetches exchange rates from the [Frankfurter API][api], which publishes
+/// the reference rates of the European Central Bank.
+///
+/// [api]: https://frankfurter.dev/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frankfurter {
+    /// The URL of the API, e.g. of a local stub server in tests.
+    base_url: String,
+}
+
+impl Default for Frankfurter {
+    fn default() -> Self {
+        Self::new(FRANKFURTER_API)
+    }
+}
+
+impl Frankfurter {
+    /// Fetches rates from the API at `base_url` instead of the public one.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Formats a URL for the API to fetch exchange rates
+    fn format_url(&self, date: NaiveDate, from: &str, to: &str) -> String {
+        format!("{}/{date}?from={from}&to={to}", self.base_url)
+    }
+
+    /// Gets the exchange rate from the response of `fetcher` to the URL of
+    /// the API for `date`, `from` and `to`.
+    pub fn rate_with_fetcher<T: DeserializableResponse>(
+        &self,
+        date: NaiveDate,
+        from: &str,
+        to: &str,
+        fetcher: impl Fn(String) -> Result<T>,
+    ) -> Result<Decimal> {
+        if from == to {
+            return Ok(Decimal::ONE);
+        }
+        debug!("Fetching {from}/{to}@{date} rate.");
+        fetcher(self.format_url(date, from, to))?
+            .json::<FrankfurterApiResponse>()
+            .and_then(|response| {
+                response
+                    .rates()
+                    .get(to)
+                    .cloned()
+                    .ok_or(ExchangeRatesError::MissingRate {
+                        target: to.to_owned(),
+                        base: from.to_owned(),
+                    })
+            })
+    }
+}
+
+impl ExchangeRateProvider for Frankfurter {
+    fn rate(&self, date: NaiveDate, from: &str, to: &str) -> Result<Decimal> {
+        self.rate_with_fetcher(date, from, to, |url| get_with_reqwest(&url))
+    }
+
+    fn source(&self) -> String {
+        FRANKFURTER_RATES_SOURCE.to_owned()
+    }
+
+    fn cache_file_name(&self) -> Option<&'static str> {
+        Some("cached_rates")
+    }
+}
+
+/// Makes blocking requests to the [Frankfurter API][api] to get the exchange rate
+///
+/// [api]: https://frankfurter.dev/
+pub fn get_exchange_rate_with_fetcher<T: DeserializableResponse>(
+    date: NaiveDate,
+    from: &str,
+    to: &str,
+    fetcher: impl Fn(String) -> Result<T>,
+) -> Result<Decimal> {
+    Frankfurter::default().rate_with_fetcher(date, from, to, fetcher)
+}
+
+pub fn get_exchange_rate_with_reqwest(date: &NaiveDate, from: &str, to: &str) -> Result<Decimal> {
+    Frankfurter::default().rate(*date, from, to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+
+    #[test]
+    fn test_format_url() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+        let url = Frankfurter::default().format_url(date, "GBP", "EUR");
+        assert_eq!(
+            url,
+            "https://api.frankfurter.app/2025-04-30?from=GBP&to=EUR"
+        );
+    }
+
+    #[test]
+    fn no_fetch_needed_when_same_currency() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+        let rate = get_exchange_rate_with_fetcher::<MockResponse>(date, "EUR", "EUR", |_url| {
+            unreachable!("fetch should not be called for equal currency");
+        })
+        .unwrap();
+        assert_eq!(rate, Decimal::ONE);
+    }
+
+    struct MockResponse(&'static str);
+    impl DeserializableResponse for MockResponse {
+        fn json<T: serde::de::DeserializeOwned>(self) -> Result<T> {
+            serde_json::from_str(self.0).map_err(ExchangeRatesError::parse_error)
+        }
+    }
+
+    #[test]
+    fn gets_rate_from_mocked_fetcher() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+        let rate = get_exchange_rate_with_fetcher(date, "GBP", "EUR", |_url| {
+            Ok::<MockResponse, ExchangeRatesError>(MockResponse(r#"{"rates":{"EUR":"1.174"}}"#))
+        })
+        .unwrap();
+        assert_eq!(rate.to_string(), "1.174");
+    }
+
+    #[test]
+    fn gets_rate_from_stub_server() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/2025-04-30")
+                .query_param("from", "GBP")
+                .query_param("to", "EUR");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"amount":1.0,"base":"GBP","date":"2025-04-30","rates":{"EUR":1.174}}"#);
+        });
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+
+        let rate = Frankfurter::new(server.base_url())
+            .rate(date, "GBP", "EUR")
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(rate.to_string(), "1.174");
+    }
+
+    #[test]
+    fn error_status_of_stub_server_is_network_error() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET);
+            then.status(404).body(r#"{"message":"not found"}"#);
+        });
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+
+        let result = Frankfurter::new(server.base_url()).rate(date, "GBP", "XXX");
+
+        assert!(matches!(
+            result,
+            Err(ExchangeRatesError::NetworkError { .. })
+        ));
+    }
+}
@@ -1,4 +1,21 @@
-use std::{borrow::Borrow, collections::HashMap, path::PathBuf};
+mod ecb;
+mod frankfurter;
+mod provider;
+mod rates_file;
+
+pub use ecb::{EcbRates, EuropeanCentralBank};
+pub use frankfurter::{
+    DeserializableResponse, FRANKFURTER_RATES_SOURCE, Frankfurter, get_exchange_rate_with_fetcher,
+    get_exchange_rate_with_reqwest,
+};
+use provider::get_with_reqwest;
+pub use provider::{ExchangeRateProvider, ExchangeRatesProviderKind};
+pub use rates_file::RatesFile;
+
+use std::{
+    borrow::Borrow,
+    path::{Path, PathBuf},
+};
 
 use bon::Builder;
 use chrono::NaiveDate;
@@ -12,9 +29,6 @@ use crate::{data_dir, deserialize_contents_of_ron, path_to_ron_file_with_base, s
 
 pub type Result<T, E = ExchangeRatesError> = std::result::Result<T, E>;
 
-const FRANKFURTER_API: &str = "https://api.frankfurter.app";
-const CACHED_RATES_FILE_NAME: &str = "cached_rates";
-
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ExchangeRatesError {
     NetworkError { underlying: String },
@@ -69,87 +83,35 @@ pub struct ExchangeRateItem {
     source_currency: String,
 }
 
-/// Response has format:
-/// ```json
-/// {
-///   "amount": 1.0,
-///   "base": "GBP",
-///   "date": "2025-04-30",
-///   "rates": {
-///     "EUR": 1.174
-///   }
-///  }
-/// ```
-/// as given by `curl -s "https://api.frankfurter.app/2025-05-01?from=GBP&to=EUR"`
-#[derive(Debug, Clone, Deserialize, Getters)]
-struct FrankfurterApiResponse {
-    #[getset(get = "pub")]
-    rates: HashMap<String, Decimal>,
-}
-
-pub trait DeserializableResponse {
-    fn json<T: serde::de::DeserializeOwned>(self) -> Result<T>;
-}
-
-impl DeserializableResponse for reqwest::blocking::Response {
-    fn json<T: serde::de::DeserializeOwned>(self) -> Result<T> {
-        self.json().map_err(ExchangeRatesError::parse_error)
-    }
-}
-
-/// Formats a URL for the [Frankfurter API][api] to fetch exchange rates
-///
-/// [api]: https://frankfurter.dev/
-fn format_url(date: NaiveDate, from: &str, to: &str) -> String {
-    format!("{FRANKFURTER_API}/{date}?from={from}&to={to}")
-}
-
-/// Makes blocking requests to the [Frankfurter API][api] to get the exchange rate
-///  
-/// [api]: https://frankfurter.dev/
-pub fn get_exchange_rate_with_fetcher<T: DeserializableResponse>(
-    date: NaiveDate,
-    from: &str,
-    to: &str,
-    fetcher: impl Fn(String) -> Result<T>,
-) -> Result<Decimal> {
-    if from == to {
-        return Ok(Decimal::ONE);
-    }
-    debug!("Fetching {from}/{to}@{date} rate.");
-    fetcher(format_url(date, from, to))?
-        .json::<FrankfurterApiResponse>()
-        .and_then(|response| {
-            response
-                .rates()
-                .get(to)
-                .cloned()
-                .ok_or(ExchangeRatesError::MissingRate {
-                    target: to.to_owned(),
-                    base: from.to_owned(),
-                })
-        })
-}
-
-pub fn get_exchange_rate_with_reqwest(date: &NaiveDate, from: &str, to: &str) -> Result<Decimal> {
-    get_exchange_rate_with_fetcher(*date, from, to, |url| {
-        reqwest::blocking::get(&url).map_err(ExchangeRatesError::network_error)
-    })
-}
-
 pub type ExchangeRatesMap = IndexMap<String, Decimal>;
 
+/// Fetches exchange rates with an [`ExchangeRateProvider`], caching fetched
+/// rates on disk, in a file of their own for each provider.
 #[derive(Builder)]
 pub struct ExchangeRatesFetcher<T = ()> {
     path_to_cache: PathBuf,
+    /// Where the rates come from, [`Frankfurter`] by default.
+    #[builder(default = Box::new(Frankfurter::default()))]
+    provider: Box<dyn ExchangeRateProvider>,
     #[allow(dead_code)]
     extra: T,
 }
 
 impl Default for ExchangeRatesFetcher {
     fn default() -> Self {
+        Self::for_provider(&ExchangeRatesProviderKind::default(), data_dir())
+    }
+}
+
+impl ExchangeRatesFetcher {
+    /// Fetches rates with the provider of `kind`, caching them in the data
+    /// directory at `base_path`, against which the path of a rates file is
+    /// resolved.
+    pub fn for_provider(kind: &ExchangeRatesProviderKind, base_path: impl AsRef<Path>) -> Self {
+        let path_to_cache = base_path.as_ref().to_path_buf();
         Self {
-            path_to_cache: data_dir(),
+            provider: kind.provider(&path_to_cache),
+            path_to_cache,
             extra: (),
         }
     }
@@ -200,22 +162,37 @@ impl CachedRates {
 }
 
 impl<T> ExchangeRatesFetcher<T> {
-    fn path(&self) -> PathBuf {
-        path_to_ron_file_with_base(&self.path_to_cache, CACHED_RATES_FILE_NAME)
+    /// Where the rates come from, see [`ExchangeRateProvider::source`].
+    pub fn source(&self) -> String {
+        self.provider.source()
+    }
+
+    /// The path of the cache of the provider, `None` if it is not cached.
+    fn path(&self) -> Option<PathBuf> {
+        self.provider
+            .cache_file_name()
+            .map(|name| path_to_ron_file_with_base(&self.path_to_cache, name))
     }
 
     fn load_cache(&self) -> Result<CachedRates> {
-        deserialize_contents_of_ron(self.path())
+        let path = self
+            .path()
+            .ok_or_else(|| ExchangeRatesError::parse_error("rates are not cached"))?;
+        deserialize_contents_of_ron(path)
             .map_err(|error| ExchangeRatesError::parse_error(format!("{error:?}")))
     }
 
     fn save_cache(&self, rates: &CachedRates) -> Result<()> {
-        save_to_disk(rates, self.path())
+        let Some(path) = self.path() else {
+            return Ok(());
+        };
+        save_to_disk(rates, path)
             .map(|_| ())
             .map_err(|error| ExchangeRatesError::parse_error(format!("{error:?}")))
     }
 
     fn do_fetch(
+        &self,
         cache: &mut CachedRates,
         target_currency: &str,
         items: Vec<ExchangeRateItem>,
@@ -226,8 +203,13 @@ impl<T> ExchangeRatesFetcher<T> {
             let date = expense.transaction_date();
             let from = expense.source_currency();
             let to = target_currency;
-            let (rate, is_new) =
-                cache.load_else_fetch(date, from, to, get_exchange_rate_with_reqwest)?;
+            if from == to {
+                rates.insert(from.clone(), Decimal::ONE);
+                continue;
+            }
+            let (rate, is_new) = cache.load_else_fetch(date, from, to, |date, from, to| {
+                self.provider.rate(*date, from, to)
+            })?;
             fetched_new_rates |= is_new;
             rates.insert(from.clone(), rate);
         }
@@ -242,6 +224,10 @@ impl<T> ExchangeRatesFetcher<T> {
     }
 
     fn update_cache_if_needed(&self, rates_by_day: &CachedRates, fetched_new_rates: bool) {
+        if self.path().is_none() {
+            debug!("ℹ️ Rates of {} are not cached.", self.source());
+            return;
+        }
         if !fetched_new_rates {
             debug!("ℹ️ No new rates fetched, used only cached rates.");
             return;
@@ -266,7 +252,7 @@ impl<T> ExchangeRatesFetcher<T> {
         let target_currency = target_currency.into();
         let mut rates_by_day = self.load_cache_else_new();
         let (rates, fetched_new_rates) =
-            Self::do_fetch(&mut rates_by_day, &target_currency, items)?;
+            self.do_fetch(&mut rates_by_day, &target_currency, items)?;
         debug!("✅ Fetched exchanges rates for #{} expenses.", rates.len());
         self.update_cache_if_needed(&rates_by_day, fetched_new_rates);
         Ok(rates)
@@ -276,6 +262,7 @@ impl<T> ExchangeRatesFetcher<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use httpmock::prelude::*;
     use rust_decimal::dec;
     use tempfile::{TempDir, tempdir};
 
@@ -286,48 +273,12 @@ mod tests {
             .build()
     }
 
-    #[test]
-    fn test_format_url() {
-        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
-        let url = format_url(date, "GBP", "EUR");
-        assert_eq!(
-            url,
-            "https://api.frankfurter.app/2025-04-30?from=GBP&to=EUR"
-        );
-    }
-
-    #[test]
-    fn no_fetch_needed_when_same_currency() {
-        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
-        let rate = get_exchange_rate_with_fetcher::<MockResponse>(date, "EUR", "EUR", |_url| {
-            unreachable!("fetch should not be called for equal currency");
-        })
-        .unwrap();
-        assert_eq!(rate, Decimal::ONE);
-    }
-
-    struct MockResponse(&'static str);
-    impl DeserializableResponse for MockResponse {
-        fn json<T: serde::de::DeserializeOwned>(self) -> Result<T> {
-            serde_json::from_str(self.0).map_err(ExchangeRatesError::parse_error)
-        }
-    }
-
-    #[test]
-    fn gets_rate_from_mocked_fetcher() {
-        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
-        let rate = get_exchange_rate_with_fetcher(date, "GBP", "EUR", |_url| {
-            Ok::<MockResponse, ExchangeRatesError>(MockResponse(r#"{"rates":{"EUR":"1.174"}}"#))
-        })
-        .unwrap();
-        assert_eq!(rate.to_string(), "1.174");
-    }
-
     #[test]
     fn fetcher_uses_custom_cache_dir() {
         let tempdir = tempdir().unwrap();
         let fetcher = temp_fetcher(&tempdir);
-        let path = fetcher.path();
+        let path = fetcher.path().unwrap();
+        assert!(path.starts_with(tempdir.path()));
         assert!(path.ends_with("cached_rates.ron"));
     }
 
@@ -339,7 +290,7 @@ mod tests {
         fetcher.update_cache_if_needed(&CachedRates::default(), false);
 
         assert!(
-            !fetcher.path().exists(),
+            !fetcher.path().unwrap().exists(),
             "Cache file should not exist when no new rates were fetched."
         );
     }
@@ -356,7 +307,7 @@ mod tests {
 
         fetcher.update_cache_if_needed(&cache, true);
 
-        let loaded: CachedRates = deserialize_contents_of_ron(fetcher.path()).unwrap();
+        let loaded: CachedRates = deserialize_contents_of_ron(fetcher.path().unwrap()).unwrap();
         assert_eq!(loaded, cache, "Cache should be updated with new rates.");
     }
 
@@ -391,7 +342,7 @@ mod tests {
         let tempdir = tempdir().unwrap();
         let fetcher = temp_fetcher(&tempdir);
 
-        std::fs::write(fetcher.path(), "gibberish").unwrap();
+        std::fs::write(fetcher.path().unwrap(), "gibberish").unwrap();
 
         assert_eq!(
             fetcher.load_cache_else_new(),
@@ -399,4 +350,77 @@ mod tests {
             "Cache should be reset to default when gibberish is found."
         );
     }
+
+    #[test]
+    fn fetch_for_items_fetches_from_provider_once_then_from_cache() {
+        let tempdir = tempdir().unwrap();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/2025-05-30");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"rates":{"EUR":1.186}}"#);
+        });
+        let fetcher = ExchangeRatesFetcher::builder()
+            .path_to_cache(tempdir.path().to_path_buf())
+            .provider(Box::new(Frankfurter::new(server.base_url())))
+            .extra(())
+            .build();
+        let items = || {
+            vec![
+                ExchangeRateItem::builder()
+                    .transaction_date(chrono::NaiveDate::from_ymd_opt(2025, 5, 30).unwrap())
+                    .source_currency("GBP")
+                    .build(),
+                ExchangeRateItem::builder()
+                    .transaction_date(chrono::NaiveDate::from_ymd_opt(2025, 5, 30).unwrap())
+                    .source_currency("EUR")
+                    .build(),
+            ]
+        };
+
+        let rates = fetcher.fetch_for_items("EUR", items()).unwrap();
+        let cached = fetcher.fetch_for_items("EUR", items()).unwrap();
+
+        mock.assert_hits(1);
+        assert_eq!(rates, cached);
+        assert_eq!(rates.get("GBP"), Some(&dec!(1.186)));
+        assert_eq!(rates.get("EUR"), Some(&Decimal::ONE));
+    }
+
+    #[test]
+    fn rates_of_rates_file_are_not_cached() {
+        let tempdir = tempdir().unwrap();
+        std::fs::write(
+            tempdir.path().join("rates.csv"),
+            "date,from,to,rate\n2025-05-30,GBP,EUR,1.186\n",
+        )
+        .unwrap();
+        let fetcher = ExchangeRatesFetcher::for_provider(
+            &ExchangeRatesProviderKind::File("rates.csv".into()),
+            tempdir.path(),
+        );
+        let item = ExchangeRateItem::builder()
+            .transaction_date(chrono::NaiveDate::from_ymd_opt(2025, 6, 1).unwrap())
+            .source_currency("GBP")
+            .build();
+
+        let rates = fetcher.fetch_for_items("EUR", vec![item]).unwrap();
+
+        assert_eq!(rates.get("GBP"), Some(&dec!(1.186)));
+        assert_eq!(fetcher.path(), None);
+        assert_eq!(fetcher.source(), "rates file rates.csv");
+        assert!(!tempdir.path().join("cached_rates.ron").exists());
+    }
+
+    #[test]
+    fn for_provider_caches_rates_at_base_path() {
+        let tempdir = tempdir().unwrap();
+        let fetcher =
+            ExchangeRatesFetcher::for_provider(&ExchangeRatesProviderKind::Ecb, tempdir.path());
+        assert_eq!(
+            fetcher.path(),
+            Some(tempdir.path().join("cached_rates_ecb.ron"))
+        );
+    }
 }
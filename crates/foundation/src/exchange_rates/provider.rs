@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::{EuropeanCentralBank, ExchangeRatesError, Frankfurter, RatesFile, Result};
+
+/// Upper bound on how long a single exchange rate request may take.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A source of exchange rates, e.g. a web API or a local file.
+pub trait ExchangeRateProvider {
+    /// The amount in `to` of one unit of `from` on `date`, e.g. `11.05` for
+    /// `from` being `"EUR"` and `to` being `"SEK"`.
+    fn rate(&self, date: NaiveDate, from: &str, to: &str) -> Result<Decimal>;
+
+    /// Where the rates come from, stated on invoices showing converted
+    /// amounts.
+    fn source(&self) -> String;
+
+    /// Name of the file fetched rates are cached in, `None` if they are not
+    /// cached, e.g. for rates read from a local file.
+    fn cache_file_name(&self) -> Option<&'static str>;
+}
+
+/// Which [`ExchangeRateProvider`] to fetch exchange rates with, as chosen in
+/// the config, e.g. `File("rates.csv")`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExchangeRatesProviderKind {
+    /// The [Frankfurter API](https://frankfurter.dev/), see [`Frankfurter`].
+    #[default]
+    Frankfurter,
+
+    /// The reference rates published by the European Central Bank, see
+    /// [`EuropeanCentralBank`].
+    Ecb,
+
+    /// A local CSV or RON file of rates, for offline or audited use, see
+    /// [`RatesFile`]. A relative path is relative to the data directory.
+    File(PathBuf),
+}
+
+impl ExchangeRatesProviderKind {
+    /// Whether this is the default provider, [`Self::Frankfurter`].
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Creates the provider of this kind, resolving a relative path of a
+    /// rates file against `base_path`.
+    pub fn provider(&self, base_path: impl AsRef<Path>) -> Box<dyn ExchangeRateProvider> {
+        match self {
+            Self::Frankfurter => Box::new(Frankfurter::default()),
+            Self::Ecb => Box::new(EuropeanCentralBank::default()),
+            Self::File(path) => Box::new(RatesFile::new(base_path.as_ref().join(path))),
+        }
+    }
+}
+
+/// Makes a blocking `GET` request to `url` with a bounded timeout, failing on
+/// non-2xx responses.
+pub(super) fn get_with_reqwest(url: &str) -> Result<reqwest::blocking::Response> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(HTTP_TIMEOUT)
+        .build()
+        .map_err(ExchangeRatesError::network_error)?;
+    client
+        .get(url)
+        .send()
+        .and_then(reqwest::blocking::Response::error_for_status)
+        .map_err(ExchangeRatesError::network_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_kind_is_frankfurter() {
+        assert!(ExchangeRatesProviderKind::Frankfurter.is_default());
+        assert!(!ExchangeRatesProviderKind::Ecb.is_default());
+    }
+
+    #[test]
+    fn kind_deserializes_from_ron() {
+        let kinds: Vec<ExchangeRatesProviderKind> =
+            ron::from_str(r#"[Frankfurter, Ecb, File("rates.csv")]"#).unwrap();
+        assert_eq!(
+            kinds,
+            vec![
+                ExchangeRatesProviderKind::Frankfurter,
+                ExchangeRatesProviderKind::Ecb,
+                ExchangeRatesProviderKind::File("rates.csv".into()),
+            ]
+        );
+    }
+}
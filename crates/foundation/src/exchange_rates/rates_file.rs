@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use indexmap::IndexMap;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use super::{ExchangeRateProvider, ExchangeRatesError, Result};
+
+/// Number of decimals inverted rates are rounded to.
+const INVERTED_RATE_DECIMALS: u32 = 6;
+
+/// A row of a CSV rates file.
+#[derive(Debug, Deserialize)]
+struct RateRow {
+    date: NaiveDate,
+    from: String,
+    to: String,
+    rate: Decimal,
+}
+
+/// Reads exchange rates from a local file, for offline or audited use, never
+/// fetching nor caching anything.
+///
+/// The file is either a CSV file, with extension `.csv`, with a header row:
+/// ```text
+/// date,from,to,rate
+/// 2025-05-30,EUR,SEK,10.8985
+/// ```
+/// or a RON file keyed by day, then by the currency converted from, then by
+/// the currency converted into, the format of the cache of fetched rates:
+/// ```text
+/// {
+///     "2025-05-30": {
+///         "EUR": {
+///             "SEK": 10.8985,
+///         },
+///     },
+/// }
+/// ```
+/// where each rate is the amount of the currency converted into of one unit
+/// of the currency converted from.
+///
+/// The rate of the latest day on or before the requested date is used, so a
+/// file with the rates of business days covers weekends and holidays. A rate
+/// only listed in the other direction is inverted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RatesFile {
+    path: PathBuf,
+}
+
+type RatesByDay = BTreeMap<NaiveDate, IndexMap<(String, String), Decimal>>;
+
+impl RatesFile {
+    /// Reads rates from the file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn is_csv(&self) -> bool {
+        self.path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"))
+    }
+
+    fn read(&self) -> Result<RatesByDay> {
+        let invalid = |error: &dyn std::fmt::Display| {
+            ExchangeRatesError::parse_error(format!(
+                "invalid rates file {}: {error}",
+                self.path.display()
+            ))
+        };
+        let contents = std::fs::read_to_string(&self.path).map_err(|e| invalid(&e))?;
+        let mut days = RatesByDay::new();
+        if self.is_csv() {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(contents.as_bytes());
+            for row in reader.deserialize::<RateRow>() {
+                let row = row.map_err(|e| invalid(&e))?;
+                days.entry(row.date)
+                    .or_default()
+                    .insert((row.from, row.to), row.rate);
+            }
+        } else {
+            let by_day: IndexMap<NaiveDate, IndexMap<String, IndexMap<String, Decimal>>> =
+                ron::from_str(&contents).map_err(|e| invalid(&e))?;
+            for (date, by_from) in by_day {
+                let rates = days.entry(date).or_default();
+                for (from, by_to) in by_from {
+                    for (to, rate) in by_to {
+                        rates.insert((from.clone(), to), rate);
+                    }
+                }
+            }
+        }
+        Ok(days)
+    }
+}
+
+impl ExchangeRateProvider for RatesFile {
+    fn rate(&self, date: NaiveDate, from: &str, to: &str) -> Result<Decimal> {
+        if from == to {
+            return Ok(Decimal::ONE);
+        }
+        let pair = (from.to_owned(), to.to_owned());
+        let inverse = (to.to_owned(), from.to_owned());
+        self.read()?
+            .range(..=date)
+            .rev()
+            .find_map(|(_, rates)| {
+                rates.get(&pair).copied().or_else(|| {
+                    rates
+                        .get(&inverse)
+                        .filter(|rate| !rate.is_zero())
+                        .map(|rate| {
+                            (Decimal::ONE / rate)
+                                .round_dp(INVERTED_RATE_DECIMALS)
+                                .normalize()
+                        })
+                })
+            })
+            .ok_or(ExchangeRatesError::MissingRate {
+                target: to.to_owned(),
+                base: from.to_owned(),
+            })
+    }
+
+    fn source(&self) -> String {
+        let name = self
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.path.display().to_string());
+        format!("rates file {name}")
+    }
+
+    fn cache_file_name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange_rates::ExchangeRatesProviderKind;
+    use rust_decimal::dec;
+    use tempfile::tempdir;
+
+    fn d(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn reads_csv_file_with_latest_rate_on_or_before_date() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("rates.csv");
+        std::fs::write(
+            &path,
+            "date,from,to,rate\n2025-05-29,EUR,SEK,10.9\n2025-05-30, EUR, SEK, 10.8985\n2025-05-30,GBP,EUR,1.186\n",
+        )
+        .unwrap();
+        let sut = RatesFile::new(&path);
+
+        assert_eq!(sut.rate(d("2025-05-29"), "EUR", "SEK").unwrap(), dec!(10.9));
+        assert_eq!(
+            sut.rate(d("2025-06-01"), "EUR", "SEK").unwrap(),
+            dec!(10.8985)
+        );
+        assert_eq!(
+            sut.rate(d("2025-05-31"), "GBP", "EUR").unwrap(),
+            dec!(1.186)
+        );
+        assert_eq!(
+            sut.rate(d("2025-05-31"), "EUR", "GBP").unwrap(),
+            dec!(0.843170)
+        );
+        assert!(matches!(
+            sut.rate(d("2025-05-28"), "EUR", "SEK"),
+            Err(ExchangeRatesError::MissingRate { .. })
+        ));
+        assert_eq!(sut.source(), "rates file rates.csv");
+        assert_eq!(sut.cache_file_name(), None);
+    }
+
+    #[test]
+    fn reads_ron_file_in_format_of_cache() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("rates.ron"),
+            r#"{ "2025-05-30": { "EUR": { "SEK": 10.8985, "USD": 1.1357 } } }"#,
+        )
+        .unwrap();
+        let sut = ExchangeRatesProviderKind::File("rates.ron".into()).provider(dir.path());
+
+        assert_eq!(
+            sut.rate(d("2025-05-30"), "EUR", "USD").unwrap(),
+            dec!(1.1357)
+        );
+        assert_eq!(
+            sut.rate(d("2025-05-30"), "SEK", "SEK").unwrap(),
+            Decimal::ONE
+        );
+    }
+
+    #[test]
+    fn missing_or_invalid_file_is_parse_error() {
+        let dir = tempdir().unwrap();
+        let missing = RatesFile::new(dir.path().join("missing.csv"));
+        assert!(matches!(
+            missing.rate(d("2025-05-30"), "EUR", "SEK"),
+            Err(ExchangeRatesError::ParseError { .. })
+        ));
+
+        let path = dir.path().join("rates.csv");
+        std::fs::write(&path, "date,from,to,rate\n2025-05-30,EUR,SEK,ten\n").unwrap();
+        assert!(matches!(
+            RatesFile::new(&path).rate(d("2025-05-30"), "EUR", "SEK"),
+            Err(ExchangeRatesError::ParseError { .. })
+        ));
+    }
+}
@@ -22,7 +22,8 @@ pub use crate::bank_holidays::{
 };
 pub use crate::calendar_logic::{
     CalendarError, CalendarResult, calculate_period_number, normalize_period_end_date_for_cadence,
-    parse_period_label_for_cadence, period_end_from_relative_time, quantity_in_period,
+    parse_period_label_for_cadence, period_bounds, period_end_for_period_number,
+    period_end_from_relative_time, quantity_in_period,
 };
 pub use crate::document::{
     create_pdf_document, render_and_save_named_pdf, resolve_output_path_and_name,
@@ -34,17 +35,19 @@ pub use crate::encryption::{
 };
 #[cfg(feature = "exchange-rates")]
 pub use crate::exchange_rates::{
-    DeserializableResponse, ExchangeRateItem, ExchangeRatesError, ExchangeRatesFetcher,
-    ExchangeRatesMap, get_exchange_rate_with_fetcher, get_exchange_rate_with_reqwest,
+    DeserializableResponse, EcbRates, EuropeanCentralBank, ExchangeRateItem, ExchangeRateProvider,
+    ExchangeRatesError, ExchangeRatesFetcher, ExchangeRatesMap, ExchangeRatesProviderKind,
+    FRANKFURTER_RATES_SOURCE, Frankfurter, RatesFile, get_exchange_rate_with_fetcher,
+    get_exchange_rate_with_reqwest,
 };
 pub use crate::fs_utils::{create_folder_if_needed, create_folder_to_parent_of_path_if_needed};
 pub use crate::functional::{ResultExt, curry1, curry2};
 pub use crate::models::{
     AbstractNamedPdf, BankHolidays, Cadence, CompanyInformation, Cost, CountryCode, Currency, Date,
-    Day, Decimal, DueDays, DueInDays, FontIdentifier, FontWeight, Granularity, HexColor,
-    InvalidCountryCode, InvalidDueDays, ModelError, ModelResult, Month, MonthHalf, OutputPath,
-    PathAndName, Pdf, PostalAddress, Quantity, Rate, RelativeTime, StreetAddress, UnitPrice, Vat,
-    Year, save_pdf,
+    Day, DayOff, DaysOff, Decimal, DueDays, DueInDays, FontIdentifier, FontWeight, Granularity,
+    HexColor, InvalidCountryCode, InvalidDueDays, ModelError, ModelResult, Month, MonthHalf,
+    OutputPath, PathAndName, Pdf, PostalAddress, Quantity, Rate, RelativeTime, StreetAddress,
+    UnitPrice, Vat, WorkSchedule, Year, save_pdf,
 };
 pub use crate::ron::{
     RonError, deserialize_contents_of_ron, deserialize_ron_str, path_to_ron_file_with_base,
@@ -8,14 +8,21 @@ use strum::EnumIter;
 
 /// How often you invoice, e.g. once or twice per month
 #[derive(
-    Clone, Copy, Debug, Display, FromStr, Default, Serialize, Deserialize, PartialEq, EnumIter,
+    Clone, Copy, Debug, Display, FromStr, Default, Serialize, Deserialize, PartialEq, Eq, EnumIter,
 )]
 pub enum Cadence {
     /// Invoicing **once** per month.
     #[default]
     Monthly,
-    /// Invoicing **twice** per month.
+    /// Invoicing **twice** per month, for the 1st-15th and 16th-end of month.
     BiWeekly,
+    /// Invoicing **once** per ISO week, Monday to Sunday.
+    Weekly,
+    /// Invoicing **once** per two ISO weeks, Monday to Sunday the week after,
+    /// see [`Granularity::TwoWeeks`].
+    IsoBiWeekly,
+    /// Invoicing **once** per calendar quarter.
+    Quarterly,
 }
 
 impl Cadence {
@@ -23,17 +30,26 @@ impl Cadence {
         match self {
             Self::BiWeekly => Granularity::Fortnight,
             Self::Monthly => Granularity::Month,
+            Self::Weekly => Granularity::Week,
+            Self::IsoBiWeekly => Granularity::TwoWeeks,
+            Self::Quarterly => Granularity::Quarter,
         }
     }
 
+    /// Whether a whole number of periods of `granularity` fits in each period
+    /// of this cadence, e.g. months fit in quarters but not in weeks.
     pub fn validate(&self, granularity: impl Into<Granularity>) -> bool {
         use Cadence::*;
         use Granularity::*;
         let granularity = granularity.into();
         match (self, granularity) {
-            (BiWeekly, Month) => false,
-            (BiWeekly, Fortnight | Day | Hour) => true,
-            (Monthly, Fortnight | Day | Hour | Month) => true,
+            (_, Day | Hour) => true,
+            (Monthly, Fortnight | Month) => true,
+            (BiWeekly, Fortnight) => true,
+            (Weekly, Week) => true,
+            (IsoBiWeekly, Week | TwoWeeks) => true,
+            (Quarterly, Fortnight | Month | Quarter) => true,
+            (_, Week | TwoWeeks | Fortnight | Month | Quarter) => false,
         }
     }
 }
@@ -78,5 +94,29 @@ mod tests {
         assert!(Sut::BiWeekly.validate(Granularity::Fortnight));
         assert!(Sut::BiWeekly.validate(Granularity::Day));
         assert!(Sut::BiWeekly.validate(Granularity::Hour));
+
+        assert!(Sut::Weekly.validate(Granularity::Week));
+        assert!(Sut::Weekly.validate(Granularity::Day));
+        assert!(!Sut::Weekly.validate(Granularity::TwoWeeks));
+        assert!(!Sut::Weekly.validate(Granularity::Month));
+
+        assert!(Sut::IsoBiWeekly.validate(Granularity::TwoWeeks));
+        assert!(Sut::IsoBiWeekly.validate(Granularity::Week));
+        assert!(Sut::IsoBiWeekly.validate(Granularity::Hour));
+        assert!(!Sut::IsoBiWeekly.validate(Granularity::Fortnight));
+
+        assert!(Sut::Quarterly.validate(Granularity::Quarter));
+        assert!(Sut::Quarterly.validate(Granularity::Month));
+        assert!(Sut::Quarterly.validate(Granularity::Fortnight));
+        assert!(!Sut::Quarterly.validate(Granularity::Week));
+        assert!(!Sut::Monthly.validate(Granularity::Quarter));
+    }
+
+    #[test]
+    fn max_granularity_is_valid() {
+        use strum::IntoEnumIterator;
+        for cadence in Sut::iter() {
+            assert!(cadence.validate(cadence.max_granularity()));
+        }
     }
 }
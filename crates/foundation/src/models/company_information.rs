@@ -28,6 +28,13 @@ pub struct CompanyInformation {
     /// The VAT number of the company, e.g. `"GB123456789"`.
     #[getset(get = "pub", set_with = "pub")]
     vat_number: String,
+    /// Whether invoices to this company are reverse charged, i.e. no VAT is
+    /// charged and the company accounts for it, e.g. cross-border B2B
+    /// services within the EU. Only applies to clients.
+    #[getset(get = "pub", set_with = "pub")]
+    #[builder(default)]
+    #[serde(default)]
+    reverse_charge: bool,
 }
 
 impl HasSample for CompanyInformation {
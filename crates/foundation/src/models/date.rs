@@ -241,6 +241,24 @@ impl Date {
     pub fn advance(&self, terms: &impl DueInDays) -> Self {
         self.advance_days(&terms.due_in_days())
     }
+
+    /// Returns the number of days from this date until `other`, negative if
+    /// `other` is before this date.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_foundation;
+    /// use klirr_foundation::*;
+    ///
+    /// let due_date = "2025-05-30".parse::<Date>().unwrap();
+    /// let today = "2025-06-02".parse::<Date>().unwrap();
+    ///
+    /// assert_eq!(due_date.days_until(&today), 3);
+    /// assert_eq!(today.days_until(&due_date), -3);
+    /// ```
+    pub fn days_until(&self, other: &Self) -> i64 {
+        (other.to_datetime() - self.to_datetime()).num_days()
+    }
 }
 
 impl HasSample for Date {
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use derive_more::Deref;
+use derive_more::Display;
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{Date, HasSample, ModelError, ModelResult, Quantity};
+
+/// A single date off, the whole day or half of it.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Getters,
+)]
+#[display("{date}{}", if *half { ":half" } else { "" })]
+pub struct DayOff {
+    /// The date off, e.g. `2025-05-20`.
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// Whether only half of the day was off.
+    #[getset(get = "pub")]
+    #[serde(default)]
+    half: bool,
+}
+
+impl DayOff {
+    /// The whole day of `date` off.
+    pub fn whole_day(date: Date) -> Self {
+        Self { date, half: false }
+    }
+
+    /// Half of the day of `date` off.
+    pub fn half_day(date: Date) -> Self {
+        Self { date, half: true }
+    }
+
+    /// The share of a working day that is off, `1` or `0.5`.
+    pub fn quantity(&self) -> Quantity {
+        if self.half {
+            Quantity::from(rust_decimal::dec!(0.5))
+        } else {
+            Quantity::ONE
+        }
+    }
+}
+
+/// Specific dates off during a period, deducted from its working days
+/// instead of a bare quantity of days or hours off.
+///
+/// Sorted by date, with at most one [`DayOff`] per date, a whole day taking
+/// precedence over a half day of the same date.
+#[derive(Clone, Debug, Default, Display, Serialize, Deserialize, PartialEq, Eq, Hash, Deref)]
+#[display("{}", _0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+pub struct DaysOff(Vec<DayOff>);
+
+impl DaysOff {
+    /// Creates a new [`DaysOff`] from `days`, sorting them and merging days
+    /// off of the same date.
+    pub fn new(days: impl IntoIterator<Item = DayOff>) -> Self {
+        let mut merged = days.into_iter().fold(HashMap::new(), |mut acc, day| {
+            acc.entry(day.date)
+                .and_modify(|half: &mut bool| *half &= day.half)
+                .or_insert(day.half);
+            acc
+        });
+        let mut days = merged
+            .drain()
+            .map(|(date, half)| DayOff { date, half })
+            .collect::<Vec<_>>();
+        days.sort();
+        Self(days)
+    }
+
+    /// Whether there are no days off.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<DayOff> for DaysOff {
+    fn from_iter<T: IntoIterator<Item = DayOff>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl FromIterator<DaysOff> for DaysOff {
+    fn from_iter<T: IntoIterator<Item = DaysOff>>(iter: T) -> Self {
+        Self::new(iter.into_iter().flat_map(|days| days.0))
+    }
+}
+
+impl std::str::FromStr for DaysOff {
+    type Err = ModelError;
+
+    /// Parses a date, e.g. `2025-05-20`, an inclusive range of dates, e.g.
+    /// `2025-05-12..2025-05-16`, either optionally suffixed with `:half` for
+    /// half days off, e.g. `2025-05-20:half`.
+    ///
+    /// # Errors
+    /// Returns [`ModelError::InvalidDaysOff`] if a date is invalid, the suffix
+    /// is not `half` or the range ends before it starts.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_foundation;
+    /// use klirr_foundation::*;
+    ///
+    /// let days_off: DaysOff = "2025-05-12..2025-05-14".parse().unwrap();
+    /// assert_eq!(days_off.len(), 3);
+    ///
+    /// let half: DaysOff = "2025-05-20:half".parse().unwrap();
+    /// assert_eq!(half.to_string(), "2025-05-20:half");
+    /// ```
+    fn from_str(s: &str) -> ModelResult<Self, Self::Err> {
+        let invalid = |reason: &str| ModelError::InvalidDaysOff {
+            invalid_string: s.to_owned(),
+            reason: reason.to_owned(),
+        };
+        let (dates, half) = match s.trim().split_once(':') {
+            Some((dates, "half")) => (dates, true),
+            Some(_) => return Err(invalid("expected `:half` after the date")),
+            None => (s.trim(), false),
+        };
+        let parse_date = |date: &str| -> ModelResult<NaiveDate> {
+            NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+                .map_err(|_| invalid("expected a date formatted as YYYY-MM-DD"))
+        };
+        let (start, end) = match dates.split_once("..") {
+            Some((start, end)) => (parse_date(start)?, parse_date(end)?),
+            None => {
+                let date = parse_date(dates)?;
+                (date, date)
+            }
+        };
+        if start > end {
+            return Err(invalid("the range must not end before it starts"));
+        }
+        Ok(Self::new(
+            start
+                .iter_days()
+                .take_while(|date| *date <= end)
+                .map(|date| DayOff {
+                    date: Date::from(date),
+                    half,
+                }),
+        ))
+    }
+}
+
+impl HasSample for DaysOff {
+    fn sample() -> Self {
+        "2025-05-12..2025-05-16"
+            .parse()
+            .expect("valid sample days off")
+    }
+
+    fn sample_other() -> Self {
+        "2025-05-20:half".parse().expect("valid sample days off")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = DaysOff;
+
+    fn d(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn parse_range_is_inclusive() {
+        let sut = Sut::sample();
+        assert_eq!(sut.len(), 5);
+        assert_eq!(sut.first(), Some(&DayOff::whole_day(d("2025-05-12"))));
+        assert_eq!(sut.last(), Some(&DayOff::whole_day(d("2025-05-16"))));
+    }
+
+    #[test]
+    fn parse_half_range() {
+        let sut: Sut = "2025-05-12..2025-05-13:half".parse().unwrap();
+        assert_eq!(
+            *sut,
+            vec![
+                DayOff::half_day(d("2025-05-12")),
+                DayOff::half_day(d("2025-05-13"))
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for invalid in [
+            "",
+            "2025-05",
+            "2025-13-01",
+            "2025-05-20:quarter",
+            "2025-05-16..2025-05-12",
+            "2025-05-12..",
+        ] {
+            assert!(invalid.parse::<Sut>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn new_sorts_and_whole_day_wins_over_half_day() {
+        let sut = Sut::new([
+            DayOff::half_day(d("2025-05-20")),
+            DayOff::half_day(d("2025-05-13")),
+            DayOff::whole_day(d("2025-05-20")),
+        ]);
+        assert_eq!(
+            *sut,
+            vec![
+                DayOff::half_day(d("2025-05-13")),
+                DayOff::whole_day(d("2025-05-20"))
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_merges_days_off() {
+        let sut = [Sut::sample(), Sut::sample_other()]
+            .into_iter()
+            .collect::<Sut>();
+        assert_eq!(sut.len(), 6);
+        assert_eq!(
+            sut.to_string(),
+            "2025-05-12, 2025-05-13, 2025-05-14, 2025-05-15, 2025-05-16, 2025-05-20:half"
+        );
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample_other();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(crate::deserialize_ron_str::<Sut>(&ron).unwrap(), sut);
+    }
+}
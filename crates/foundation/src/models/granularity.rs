@@ -31,10 +31,18 @@ pub enum Granularity {
     #[default]
     /// Quantity is measured in billable work days.
     Day,
+    /// Quantity is measured in ISO weeks, Monday to Sunday.
+    Week,
+    /// Quantity is measured in periods of two ISO weeks. These periods are
+    /// aligned to ISO week 1 of 2024, i.e. they end on Sundays of even ISO
+    /// weeks in 2024, e.g. `2024-01-14`, and every 14 days from there.
+    TwoWeeks,
     /// Quantity is measured in half-month periods.
     Fortnight,
     /// Quantity is measured in full months.
     Month,
+    /// Quantity is measured in calendar quarters.
+    Quarter,
 }
 
 impl Granularity {
@@ -42,8 +50,11 @@ impl Granularity {
         match self {
             Self::Hour => "$150",
             Self::Day => "$1,000",
+            Self::Week => "$4,500",
+            Self::TwoWeeks => "$9,000",
             Self::Fortnight => "$9,000",
             Self::Month => "$15,000",
+            Self::Quarter => "$45,000",
         }
         .to_owned()
     }
@@ -82,6 +93,9 @@ mod tests {
         assert!(!Sut::Month.example_rate().is_empty());
         assert!(!Sut::Fortnight.example_rate().is_empty());
         assert!(!Sut::Hour.example_rate().is_empty());
+        assert!(!Sut::Week.example_rate().is_empty());
+        assert!(!Sut::TwoWeeks.example_rate().is_empty());
+        assert!(!Sut::Quarter.example_rate().is_empty());
     }
 
     #[test]
@@ -95,5 +109,9 @@ mod tests {
         assert!(Sut::Day > Sut::Hour);
         assert!(Sut::Hour < Sut::Day);
         assert!(Sut::Hour < Sut::Month);
+
+        assert!(Sut::Week > Sut::Day);
+        assert!(Sut::TwoWeeks > Sut::Week);
+        assert!(Sut::Quarter > Sut::Month);
     }
 }
@@ -6,6 +6,7 @@ mod country_code;
 mod currency;
 mod date;
 mod day;
+mod days_off;
 mod decimal;
 mod due_days;
 mod font_identifier;
@@ -26,6 +27,7 @@ mod relative_time;
 mod street_address;
 mod unit_price;
 mod vat;
+mod work_schedule;
 mod year;
 
 pub use bank_holidays::BankHolidays;
@@ -37,6 +39,7 @@ pub use currency::Currency;
 pub use date::Date;
 pub use date::DueInDays;
 pub use day::Day;
+pub use days_off::{DayOff, DaysOff};
 pub use decimal::Decimal;
 pub use due_days::{DueDays, InvalidDueDays};
 pub use font_identifier::FontIdentifier;
@@ -58,4 +61,5 @@ pub use relative_time::RelativeTime;
 pub use street_address::StreetAddress;
 pub use unit_price::UnitPrice;
 pub use vat::Vat;
+pub use work_schedule::WorkSchedule;
 pub use year::Year;
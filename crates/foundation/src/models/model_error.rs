@@ -58,6 +58,17 @@ pub enum ModelError {
         invalid_string: String,
         reason: String,
     },
+
+    /// The work schedule is invalid, e.g. has no working days.
+    #[error("Invalid work schedule: {reason}")]
+    InvalidWorkSchedule { reason: String },
+
+    /// Failed to parse dates off, e.g. `2025-05-12..2025-05-16`.
+    #[error("Invalid days off {invalid_string:?}: {reason}")]
+    InvalidDaysOff {
+        invalid_string: String,
+        reason: String,
+    },
 }
 
 #[cfg(test)]
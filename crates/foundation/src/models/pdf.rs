@@ -19,6 +19,16 @@ impl HasSample for Pdf {
     }
 }
 
+#[cfg(feature = "crypto")]
+impl Pdf {
+    /// Returns the hex encoded SHA-256 digest of the PDF bytes, e.g. used to
+    /// verify that a PDF on disk is the one that was generated.
+    pub fn sha256_hex(&self) -> String {
+        use sha2::Digest;
+        hex::encode(sha2::Sha256::digest(&self.0))
+    }
+}
+
 /// Formats an error using `Debug` for use in `save_pdf` string errors.
 fn format_debug_error(error: impl std::fmt::Debug) -> String {
     format!("{error:?}, ")
@@ -46,6 +56,16 @@ mod tests {
         assert_eq!(Pdf::sample_other(), Pdf::sample_other());
     }
 
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn sha256_hex() {
+        assert_eq!(
+            Pdf::sample().sha256_hex(),
+            "5f78c33274e43fa9de5659265c1d917e25c03722dcb0b8d27db8d5feaa813953"
+        );
+        assert_ne!(Pdf::sample().sha256_hex(), Pdf::sample_other().sha256_hex());
+    }
+
     #[test]
     fn inequality() {
         assert_ne!(Pdf(vec![1, 2, 3, 4]), Pdf(vec![4, 3, 2, 1]));
@@ -20,6 +20,12 @@ pub enum Rate {
     Daily(UnitPrice),
     /// A fixed rate per hour, invoice monthly or bi-weekly
     Hourly(UnitPrice),
+    /// A fixed rate per ISO week, invoiced weekly or every two ISO weeks
+    Weekly(UnitPrice),
+    /// A fixed rate per two ISO weeks, invoiced every two ISO weeks
+    TwoWeekly(UnitPrice),
+    /// A fixed rate per quarter, invoiced quarterly
+    Quarterly(UnitPrice),
 }
 
 impl From<(UnitPrice, Granularity)> for Rate {
@@ -29,6 +35,9 @@ impl From<(UnitPrice, Granularity)> for Rate {
             Granularity::Fortnight => Self::Fortnight(price),
             Granularity::Day => Self::Daily(price),
             Granularity::Hour => Self::Hourly(price),
+            Granularity::Week => Self::Weekly(price),
+            Granularity::TwoWeeks => Self::TwoWeekly(price),
+            Granularity::Quarter => Self::Quarterly(price),
         }
     }
 }
@@ -54,6 +63,21 @@ impl Rate {
         Self::Hourly(rate.into())
     }
 
+    /// A fixed rate per ISO week
+    pub fn weekly(rate: impl Into<UnitPrice>) -> Self {
+        Self::Weekly(rate.into())
+    }
+
+    /// A fixed rate per two ISO weeks
+    pub fn two_weekly(rate: impl Into<UnitPrice>) -> Self {
+        Self::TwoWeekly(rate.into())
+    }
+
+    /// A quarterly fixed rate
+    pub fn quarterly(rate: impl Into<UnitPrice>) -> Self {
+        Self::Quarterly(rate.into())
+    }
+
     /// Discriminator
     pub fn granularity(&self) -> Granularity {
         match self {
@@ -61,6 +85,9 @@ impl Rate {
             Self::Fortnight(_) => Granularity::Fortnight,
             Self::Daily(_) => Granularity::Day,
             Self::Hourly(_) => Granularity::Hour,
+            Self::Weekly(_) => Granularity::Week,
+            Self::TwoWeekly(_) => Granularity::TwoWeeks,
+            Self::Quarterly(_) => Granularity::Quarter,
         }
     }
 
@@ -79,6 +106,9 @@ impl std::ops::Deref for Rate {
             Self::Fortnight(price) => price,
             Self::Daily(price) => price,
             Self::Hourly(price) => price,
+            Self::Weekly(price) => price,
+            Self::TwoWeekly(price) => price,
+            Self::Quarterly(price) => price,
         }
     }
 }
@@ -126,6 +156,9 @@ mod tests {
         assert_eq!(Sut::daily(1_000).granularity(), Granularity::Day);
         assert_eq!(Sut::fortnight(9_000).granularity(), Granularity::Fortnight);
         assert_eq!(Sut::monthly(15_000).granularity(), Granularity::Month);
+        assert_eq!(Sut::weekly(4_500).granularity(), Granularity::Week);
+        assert_eq!(Sut::two_weekly(9_000).granularity(), Granularity::TwoWeeks);
+        assert_eq!(Sut::quarterly(45_000).granularity(), Granularity::Quarter);
     }
 
     #[test]
@@ -0,0 +1,192 @@
+use bon::bon;
+use chrono::Weekday;
+use getset::Getters;
+use indexmap::IndexSet;
+use rust_decimal::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::{Decimal, HasSample, ModelError, ModelResult};
+
+fn default_hours_per_day() -> Decimal {
+    Decimal::EIGHT
+}
+
+fn default_working_days() -> IndexSet<Weekday> {
+    IndexSet::from([
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+    ])
+}
+
+/// When you work, used to calculate billable days and hours of a period.
+///
+/// The default is a full-time schedule of eight hours per day, Monday to
+/// Friday, which is what klirr assumed before schedules were configurable.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Getters)]
+pub struct WorkSchedule {
+    /// Working hours per working day, e.g. `7.5`.
+    #[getset(get = "pub")]
+    #[serde(default = "default_hours_per_day")]
+    hours_per_day: Decimal,
+
+    /// The weekdays you work, e.g. `["Mon", "Tue", "Wed", "Thu"]` for a
+    /// four-day week.
+    #[getset(get = "pub")]
+    #[serde(default = "default_working_days")]
+    working_days: IndexSet<Weekday>,
+
+    /// Share of the schedule you work in percent, e.g. `80` for 80%, `None`
+    /// for full time. Billable days and hours are scaled by it.
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part_time_percent: Option<Decimal>,
+}
+
+#[bon]
+impl WorkSchedule {
+    /// Creates a new work schedule.
+    ///
+    /// # Errors
+    /// Returns [`ModelError::InvalidWorkSchedule`] if `hours_per_day` is not
+    /// within `(0, 24]`, if `working_days` is empty or if
+    /// `part_time_percent` is not within `(0, 100]`.
+    #[builder]
+    pub fn new(
+        #[builder(default = default_hours_per_day(), into)] hours_per_day: Decimal,
+        #[builder(default = default_working_days(), with = FromIterator::from_iter)]
+        working_days: IndexSet<Weekday>,
+        #[builder(into)] part_time_percent: Option<Decimal>,
+    ) -> ModelResult<Self> {
+        let invalid = |reason: &str| {
+            Err(ModelError::InvalidWorkSchedule {
+                reason: reason.to_owned(),
+            })
+        };
+        if *hours_per_day <= dec!(0) || *hours_per_day > dec!(24) {
+            return invalid("hours per day must be more than 0 and at most 24");
+        }
+        if working_days.is_empty() {
+            return invalid("at least one weekday must be a working day");
+        }
+        if part_time_percent.is_some_and(|percent| *percent <= dec!(0) || *percent > dec!(100)) {
+            return invalid("part time percentage must be more than 0 and at most 100");
+        }
+        Ok(Self {
+            hours_per_day,
+            working_days,
+            part_time_percent,
+        })
+    }
+}
+
+impl WorkSchedule {
+    /// Whether `weekday` is a working day.
+    pub fn is_working_day(&self, weekday: Weekday) -> bool {
+        self.working_days.contains(&weekday)
+    }
+
+    /// The factor billable days and hours are scaled by, e.g. `0.8` for 80%
+    /// part time and `1` for full time.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_foundation;
+    /// use klirr_foundation::*;
+    /// use rust_decimal::dec;
+    ///
+    /// let schedule = WorkSchedule::builder()
+    ///     .part_time_percent(dec!(80))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(*schedule.part_time_factor(), dec!(0.8));
+    /// assert_eq!(WorkSchedule::default().part_time_factor(), Decimal::ONE);
+    /// ```
+    pub fn part_time_factor(&self) -> Decimal {
+        self.part_time_percent
+            .map(|percent| Decimal::from(*percent / dec!(100)))
+            .unwrap_or(Decimal::ONE)
+    }
+}
+
+impl Default for WorkSchedule {
+    fn default() -> Self {
+        Self {
+            hours_per_day: default_hours_per_day(),
+            working_days: default_working_days(),
+            part_time_percent: None,
+        }
+    }
+}
+
+impl HasSample for WorkSchedule {
+    fn sample() -> Self {
+        Self::default()
+    }
+
+    fn sample_other() -> Self {
+        Self::builder()
+            .hours_per_day(dec!(7.5))
+            .working_days([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu])
+            .part_time_percent(dec!(80))
+            .build()
+            .expect("valid sample schedule")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    type Sut = WorkSchedule;
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sut::sample(), Sut::sample());
+        assert_eq!(Sut::sample_other(), Sut::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(Sut::sample(), Sut::sample_other());
+    }
+
+    #[test]
+    fn builder_defaults_to_full_time() {
+        assert_eq!(Sut::builder().build().unwrap(), Sut::default());
+    }
+
+    #[test]
+    fn builder_rejects_invalid_schedules() {
+        assert!(Sut::builder().hours_per_day(dec!(0)).build().is_err());
+        assert!(Sut::builder().hours_per_day(dec!(25)).build().is_err());
+        assert!(Sut::builder().working_days([]).build().is_err());
+        assert!(Sut::builder().part_time_percent(dec!(0)).build().is_err());
+        assert!(Sut::builder().part_time_percent(dec!(120)).build().is_err());
+    }
+
+    #[test]
+    fn is_working_day() {
+        let sut = Sut::sample_other();
+        assert!(sut.is_working_day(Weekday::Thu));
+        assert!(!sut.is_working_day(Weekday::Fri));
+    }
+
+    #[test]
+    fn deserialize_partial_schedule() {
+        let sut = crate::deserialize_ron_str::<Sut>(r#"(hours_per_day: 7.5)"#).unwrap();
+        assert_eq!(*sut.hours_per_day(), Decimal::from(dec!(7.5)));
+        assert_eq!(sut.working_days(), &default_working_days());
+        assert_eq!(sut.part_time_percent(), &None);
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let sut = Sut::sample_other();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(crate::deserialize_ron_str::<Sut>(&ron).unwrap(), sut);
+    }
+}
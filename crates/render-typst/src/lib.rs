@@ -12,5 +12,5 @@ mod render_test_helpers;
 
 pub use error::{Error, Result};
 pub use klirr_foundation::{FontIdentifier, FontRequiring, FontWeight, Pdf, ToTypst, ToTypstFn};
-pub use module::{DocumentPlan, InlineModule};
+pub use module::{DocumentPlan, InlineFile, InlineModule};
 pub use render::*;
@@ -25,12 +25,38 @@ impl InlineModule {
     }
 }
 
+/// Represents an inline file, e.g. an image, backed by a virtual path which
+/// Typst sources can read, e.g. using `image`.
+#[derive(Clone, Debug)]
+pub struct InlineFile {
+    virtual_path: String,
+    bytes: Vec<u8>,
+}
+
+impl InlineFile {
+    pub fn new(path: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        Self {
+            virtual_path: path.into(),
+            bytes: bytes.into(),
+        }
+    }
+
+    pub fn virtual_path(&self) -> &str {
+        &self.virtual_path
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
 /// Complete plan describing how to render a Typst document.
 #[derive(Clone, Debug)]
 pub struct DocumentPlan {
     fonts: IndexSet<FontIdentifier>,
     main: InlineModule,
     modules: Vec<InlineModule>,
+    files: Vec<InlineFile>,
 }
 
 impl DocumentPlan {
@@ -39,6 +65,7 @@ impl DocumentPlan {
             fonts: fonts.into_iter().collect(),
             main,
             modules: Vec::new(),
+            files: Vec::new(),
         }
     }
 
@@ -47,6 +74,11 @@ impl DocumentPlan {
         self
     }
 
+    pub fn with_files(mut self, files: impl IntoIterator<Item = InlineFile>) -> Self {
+        self.files.extend(files);
+        self
+    }
+
     pub fn fonts(&self) -> &IndexSet<FontIdentifier> {
         &self.fonts
     }
@@ -58,4 +90,8 @@ impl DocumentPlan {
     pub fn modules(&self) -> &[InlineModule] {
         &self.modules
     }
+
+    pub fn files(&self) -> &[InlineFile] {
+        &self.files
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{DocumentPlan, Error, InlineModule, Result, typst_context::TypstContext};
+use crate::{DocumentPlan, Error, InlineFile, InlineModule, Result, typst_context::TypstContext};
 use klirr_foundation::{FontRequiring, Pdf, TYPST_LAYOUT_FOUNDATION, ToTypstFn};
 use log::debug;
 use typst::layout::PagedDocument;
@@ -30,6 +30,18 @@ pub fn render<I: ToTypstFn, D: ToTypstFn, L: ToTypstFn + FontRequiring, E>(
     data: D,
     layout: L,
     map_render_error: impl Fn(Error) -> E,
+) -> Result<Pdf, E> {
+    render_with_files(i18n, data, layout, Vec::new(), map_render_error)
+}
+
+/// Renders a PDF document like [`render`], with `files` the layout can read
+/// by their virtual path, e.g. images of receipts.
+pub fn render_with_files<I: ToTypstFn, D: ToTypstFn, L: ToTypstFn + FontRequiring, E>(
+    i18n: I,
+    data: D,
+    layout: L,
+    files: impl IntoIterator<Item = InlineFile>,
+    map_render_error: impl Fn(Error) -> E,
 ) -> Result<Pdf, E> {
     let l10n_typst_str = i18n.to_typst_fn();
     let data_typst_str = data.to_typst_fn();
@@ -52,7 +64,8 @@ pub fn render<I: ToTypstFn, D: ToTypstFn, L: ToTypstFn + FontRequiring, E>(
         InlineModule::new(TYPST_VIRTUAL_NAME_LAYOUT, layout_typst_str),
         InlineModule::new(TYPST_VIRTUAL_NAME_L10N, l10n_typst_str),
         InlineModule::new(TYPST_VIRTUAL_NAME_DATA, data_typst_str),
-    ]);
+    ])
+    .with_files(files);
 
     render_document(&plan).map_err(map_render_error)
 }
@@ -187,6 +200,483 @@ mod tests {
         assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
     }
 
+    /// Several configured services render as one row each, sharing the
+    /// subtotal and VAT rows.
+    #[test]
+    fn multiple_services_with_vat_renders_without_error() {
+        use klirr_core_invoice::{Service, ServiceFees, Vat, prepare_invoice_input_data};
+        use rust_decimal::dec;
+
+        let base = Data::sample();
+        let payment_info = base
+            .payment_info()
+            .clone()
+            .with_vat(Vat::from_percent(dec!(25)).expect("25% is valid"));
+        let service_fees = ServiceFees::builder()
+            .name(base.service_fees().name())
+            .rate(*base.service_fees().rate())
+            .cadence(*base.service_fees().cadence())
+            .additional_services(vec![Service::sample(), Service::sample_other()])
+            .build()
+            .unwrap();
+        let data = Data::builder()
+            .information(base.information().clone())
+            .vendor(base.vendor().clone())
+            .client(base.client().clone())
+            .payment_info(payment_info)
+            .service_fees(service_fees)
+            .expensed_periods(base.expensed_periods().clone())
+            .build();
+
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .date(Date::sample())
+            .language(Language::EN)
+            .build();
+        let layout = *input.layout();
+        let prepared =
+            prepare_invoice_input_data(data, input, MockedExchangeRatesFetcher::default()).unwrap();
+        assert_eq!(prepared.line_items().items().len(), 3);
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::EN).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Services with different VAT rates render a VAT summary per rate.
+    #[test]
+    fn services_with_mixed_vat_rates_renders_without_error() {
+        use klirr_core_invoice::{Service, ServiceFees, Vat, prepare_invoice_input_data};
+        use rust_decimal::dec;
+
+        let base = Data::sample();
+        let payment_info = base
+            .payment_info()
+            .clone()
+            .with_vat(Vat::from_percent(dec!(25)).expect("25% is valid"));
+        let reduced = Service::builder()
+            .name("Training")
+            .rate(*Service::sample().rate())
+            .vat(Vat::from_percent(dec!(6)).expect("6% is valid"))
+            .build();
+        let service_fees = ServiceFees::builder()
+            .name(base.service_fees().name())
+            .rate(*base.service_fees().rate())
+            .cadence(*base.service_fees().cadence())
+            .additional_services(vec![reduced])
+            .build()
+            .unwrap();
+        let data = Data::builder()
+            .information(base.information().clone())
+            .vendor(base.vendor().clone())
+            .client(base.client().clone())
+            .payment_info(payment_info)
+            .service_fees(service_fees)
+            .expensed_periods(base.expensed_periods().clone())
+            .build();
+
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .date(Date::sample())
+            .language(Language::EN)
+            .build();
+        let layout = *input.layout();
+        let prepared =
+            prepare_invoice_input_data(data, input, MockedExchangeRatesFetcher::default()).unwrap();
+        assert_eq!(prepared.totals().vat_per_rate().len(), 2);
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::EN).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Reverse charged invoices carry no VAT and render the notice.
+    #[test]
+    fn reverse_charge_renders_without_error() {
+        use klirr_core_invoice::{Vat, prepare_invoice_input_data};
+        use rust_decimal::dec;
+
+        let base = Data::sample();
+        let payment_info = base
+            .payment_info()
+            .clone()
+            .with_vat(Vat::from_percent(dec!(25)).expect("25% is valid"));
+        let data = Data::builder()
+            .information(base.information().clone())
+            .vendor(base.vendor().clone())
+            .client(base.client().clone().with_reverse_charge(true))
+            .payment_info(payment_info)
+            .service_fees(base.service_fees().clone())
+            .expensed_periods(base.expensed_periods().clone())
+            .build();
+
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .date(Date::sample())
+            .language(Language::SV)
+            .build();
+        let layout = *input.layout();
+        let prepared =
+            prepare_invoice_input_data(data, input, MockedExchangeRatesFetcher::default()).unwrap();
+        assert!(prepared.totals().vat().is_zero());
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::SV).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Invoices with a reporting currency render the converted totals.
+    #[test]
+    fn reporting_totals_renders_without_error() {
+        use klirr_core_invoice::{Vat, prepare_invoice_input_data};
+        use rust_decimal::dec;
+
+        let base = Data::sample();
+        let payment_info = base
+            .payment_info()
+            .clone()
+            .with_vat(Vat::from_percent(dec!(25)).expect("25% is valid"))
+            .with_reporting_currency(Some(Currency::SEK));
+        let data = Data::builder()
+            .information(base.information().clone())
+            .vendor(base.vendor().clone())
+            .client(base.client().clone())
+            .payment_info(payment_info)
+            .service_fees(base.service_fees().clone())
+            .expensed_periods(base.expensed_periods().clone())
+            .build();
+
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .date(Date::sample())
+            .language(Language::SV)
+            .build();
+        let layout = *input.layout();
+        let prepared = prepare_invoice_input_data(
+            data,
+            input,
+            MockedExchangeRatesFetcher::from(ExchangeRatesMap::from_iter([(
+                Currency::EUR,
+                UnitPrice::from(dec!(11.05)),
+            )])),
+        )
+        .unwrap();
+        let reporting = prepared.reporting_totals().clone().unwrap();
+        assert_eq!(*reporting.currency(), Currency::SEK);
+        assert_eq!(*reporting.rate(), UnitPrice::from(dec!(11.05)));
+        assert_eq!(reporting.rate_date(), prepared.information().invoice_date());
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::SV).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Combined invoices render the expenses in a section of their own.
+    #[test]
+    fn combined_renders_without_error() {
+        use klirr_core_invoice::prepare_invoice_input_data;
+
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Combined { time_off: None })
+            .date("2025-05-31".parse::<Date>().unwrap())
+            .language(Language::EN)
+            .build();
+        let layout = *input.layout();
+        let prepared = prepare_invoice_input_data(
+            Data::sample(),
+            input,
+            MockedExchangeRatesFetcher::from(ExchangeRatesMap::from_iter([
+                (Currency::EUR, UnitPrice::from(10)),
+                (Currency::SEK, UnitPrice::from(10)),
+            ])),
+        )
+        .unwrap();
+        assert!(!prepared.line_items().expenses().is_empty());
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::EN).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Receipts of expenses render as appended pages or embedded files.
+    #[test]
+    fn expenses_with_receipts_renders_without_error() {
+        use crate::InlineFile;
+        use klirr_core_invoice::{Receipt, prepare_invoice_input_data};
+
+        let base = Data::sample();
+        let mut expensed_periods = base.expensed_periods().clone();
+        let receipts = [
+            Receipt::new("receipts/2025-05-31/coffee.svg").unwrap(),
+            Receipt::new("receipts/2025-05-31/coffee.pdf").unwrap(),
+        ];
+        expensed_periods
+            .attach_receipts(&"2025-05-31".parse().unwrap(), 1, receipts.clone())
+            .unwrap();
+        let data = Data::builder()
+            .information(base.information().clone())
+            .vendor(base.vendor().clone())
+            .client(base.client().clone())
+            .payment_info(base.payment_info().clone())
+            .service_fees(base.service_fees().clone())
+            .expensed_periods(expensed_periods)
+            .build();
+
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .date("2025-05-31".parse::<Date>().unwrap())
+            .language(Language::EN)
+            .build();
+        let layout = *input.layout();
+        let prepared = prepare_invoice_input_data(
+            data,
+            input,
+            MockedExchangeRatesFetcher::from(ExchangeRatesMap::from_iter([
+                (Currency::EUR, UnitPrice::from(10)),
+                (Currency::SEK, UnitPrice::from(10)),
+            ])),
+        )
+        .unwrap();
+        assert_eq!(prepared.information().receipts().len(), 2);
+
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="20"><rect width="10" height="20"/></svg>"#;
+        let files = [
+            InlineFile::new(receipts[0].as_str(), svg.as_bytes()),
+            InlineFile::new(receipts[1].as_str(), b"%PDF-1.7".to_vec()),
+        ];
+        let pdf = crate::render::render_with_files(
+            klirr_core_invoice::L10n::new(Language::EN).unwrap(),
+            prepared,
+            layout,
+            files,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Dates off listed on the invoice, including a half day.
+    #[test]
+    fn listed_days_off_renders_without_error() {
+        use klirr_core_invoice::{DaysOff, TimeOff, prepare_invoice_input_data};
+
+        let days_off = ["2025-05-12..2025-05-16", "2025-05-20:half"]
+            .into_iter()
+            .map(|days| days.parse::<DaysOff>().unwrap())
+            .collect::<DaysOff>();
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service {
+                time_off: Some(TimeOff::Dates {
+                    days_off,
+                    listed: true,
+                }),
+            })
+            .date("2025-05-31".parse::<Date>().unwrap())
+            .language(Language::SV)
+            .build();
+        let layout = *input.layout();
+        let prepared = prepare_invoice_input_data(
+            Data::sample(),
+            input,
+            MockedExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        assert_eq!(prepared.information().days_off().len(), 6);
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::SV).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Discount terms state the discount and its deadline below the totals.
+    #[test]
+    fn discount_terms_render_without_error() {
+        use klirr_core_invoice::{
+            CompanyInformation, ExpensedPeriods, PaymentInformation, ProtoInvoiceInfo, ServiceFees,
+            prepare_invoice_input_data,
+        };
+
+        let data = Data::builder()
+            .information(ProtoInvoiceInfo::sample())
+            .client(CompanyInformation::sample_client())
+            .vendor(CompanyInformation::sample_vendor())
+            .payment_info(PaymentInformation::sample().with_terms("2/10 Net 30".parse().unwrap()))
+            .service_fees(ServiceFees::sample())
+            .expensed_periods(ExpensedPeriods::sample())
+            .build();
+        for language in [Language::EN, Language::SV] {
+            let input = ValidInput::builder()
+                .items(InvoicedItems::Service { time_off: None })
+                .date("2025-05-31".parse::<Date>().unwrap())
+                .language(language)
+                .build();
+            let layout = *input.layout();
+            let prepared = prepare_invoice_input_data(
+                data.clone(),
+                input,
+                MockedExchangeRatesFetcher::default(),
+            )
+            .unwrap();
+            assert!(prepared.totals().early_payment_discount().is_some());
+
+            let pdf = crate::render::render(
+                klirr_core_invoice::L10n::new(language).unwrap(),
+                prepared,
+                layout,
+                |e| panic!("render failed: {e}"),
+            )
+            .unwrap();
+            assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+        }
+    }
+
+    /// The hours of a timesheet itemized on an appendix page.
+    #[test]
+    fn itemized_timesheet_renders_without_error() {
+        use klirr_core_invoice::{Timesheet, prepare_invoice_input_data};
+
+        let timesheet = Timesheet::from_csv(
+            "date,hours,description\n2025-05-12,7.5,Code review\n2025-05-13,8\n".as_bytes(),
+            true,
+        )
+        .unwrap();
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Service { time_off: None })
+            .date("2025-05-31".parse::<Date>().unwrap())
+            .timesheet(timesheet)
+            .build();
+        let layout = *input.layout();
+        let prepared = prepare_invoice_input_data(
+            Data::sample(),
+            input,
+            MockedExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        assert_eq!(prepared.information().timesheet().len(), 2);
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::EN).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Credit notes show their title and the credited invoice.
+    #[test]
+    fn credit_note_renders_without_error() {
+        use klirr_core_invoice::{
+            ValidCreditNoteInput, prepare_credit_note_input_data, prepare_invoice_input_data,
+        };
+
+        let invoice = prepare_invoice_input_data(
+            Data::sample(),
+            ValidInput::builder()
+                .date("2025-05-31".parse::<Date>().unwrap())
+                .build(),
+            MockedExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        let input = ValidCreditNoteInput::builder()
+            .invoice(invoice.information().number().clone())
+            .date("2025-06-15".parse::<Date>().unwrap())
+            .language(Language::SV)
+            .build();
+        let layout = *input.layout();
+        let prepared = prepare_credit_note_input_data(
+            Data::sample(),
+            input,
+            MockedExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        assert!(prepared.information().credited_invoice().is_some());
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::SV).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
+    /// Payment reminders show their title, the original due date and the
+    /// lines of the outstanding amount, interest and reminder fee.
+    #[test]
+    fn reminder_renders_without_error() {
+        use klirr_core_invoice::{
+            DueDays, HasSample, InvoiceStatus, LatePaymentCharges, LedgerEntry, ValidReminderInput,
+            prepare_reminder_input_data,
+        };
+
+        let base = Data::sample();
+        let data = base.clone().with_payment_info(
+            base.payment_info()
+                .clone()
+                .with_late_payment(LatePaymentCharges::sample()),
+        );
+        let entry = LedgerEntry::sample();
+        let date = entry
+            .due_date()
+            .advance_days(&DueDays::try_from(20u16).unwrap());
+        let status = InvoiceStatus::new(entry.clone(), &[], date);
+        for language in [Language::EN, Language::SV] {
+            let l10n = klirr_core_invoice::L10n::new(language).unwrap();
+            let input = ValidReminderInput::builder()
+                .invoice(entry.number().clone())
+                .date(date)
+                .language(language)
+                .build();
+            let layout = *input.layout();
+            let prepared = prepare_reminder_input_data(
+                data.clone(),
+                input,
+                &status,
+                l10n.content().line_items(),
+            )
+            .unwrap();
+            assert_eq!(prepared.line_items().items().len(), 3);
+
+            let pdf =
+                crate::render::render(l10n, prepared, layout, |e| panic!("render failed: {e}"))
+                    .unwrap();
+            assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+        }
+    }
+
     /// Multi-line invoices (typical for expenses) should still render
     /// successfully when VAT is configured, exercising the Subtotal-row
     /// branch of the layout that single-line service invoices skip.
@@ -234,6 +724,68 @@ mod tests {
         assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
     }
 
+    /// Mileage and per diem expenses render their quantities with units,
+    /// including the negative rows deducting the provided meals.
+    #[test]
+    fn mileage_and_per_diem_expenses_render_without_error() {
+        use klirr_core_invoice::{
+            Currency, Item, PerDiemRates, ProvidedMeals, Quantity, prepare_invoice_input_data,
+        };
+        use rust_decimal::dec;
+
+        let period_end_date = "2025-05-31".parse::<Date>().unwrap();
+        let mut expensed_periods = Data::sample().expensed_periods().clone();
+        expensed_periods.insert_expenses(
+            &period_end_date,
+            vec![
+                Item::mileage_from_str("Client visit, 120, km, 0.25, EUR, 2025-05-12").unwrap(),
+                Item::per_diem(
+                    "Berlin",
+                    "DE",
+                    Quantity::from(dec!(3)),
+                    "2025-05-14".parse().unwrap(),
+                    ProvidedMeals::builder().breakfasts(2).build(),
+                    &PerDiemRates::sample(),
+                )
+                .unwrap(),
+            ],
+        );
+        let base = Data::sample();
+        let data = Data::builder()
+            .information(base.information().clone())
+            .vendor(base.vendor().clone())
+            .client(base.client().clone())
+            .payment_info(base.payment_info().clone())
+            .service_fees(base.service_fees().clone())
+            .expensed_periods(expensed_periods)
+            .build();
+
+        let input = ValidInput::builder()
+            .items(InvoicedItems::Expenses)
+            .date(period_end_date)
+            .language(Language::SV)
+            .build();
+        let layout = *input.layout();
+        let prepared = prepare_invoice_input_data(
+            data,
+            input,
+            MockedExchangeRatesFetcher::from(ExchangeRatesMap::from_iter([
+                (Currency::EUR, UnitPrice::from(10)),
+                (Currency::SEK, UnitPrice::from(10)),
+            ])),
+        )
+        .unwrap();
+
+        let pdf = crate::render::render(
+            klirr_core_invoice::L10n::new(Language::SV).unwrap(),
+            prepared,
+            layout,
+            |e| panic!("render failed: {e}"),
+        )
+        .unwrap();
+        assert!(!pdf.as_ref().is_empty(), "rendered PDF should be non-empty");
+    }
+
     /// Single payment-method override replaces the BIC slot.
     #[test]
     fn services_with_one_payment_method_override_renders() {
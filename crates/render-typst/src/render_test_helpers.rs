@@ -1,8 +1,8 @@
 use crate::compare_images;
 use crate::render::render;
 use klirr_core_invoice::{
-    Currency, Data, ExchangeRates, ExchangeRatesMap, FetchExchangeRates, Item, L10n, Language,
-    ValidInput, prepare_invoice_input_data,
+    Currency, Data, Date, ExchangeRates, ExchangeRatesMap, FetchExchangeRates, Item, L10n,
+    Language, UnitPrice, ValidInput, prepare_invoice_input_data,
 };
 
 use std::path::{Path, PathBuf};
@@ -67,6 +67,20 @@ impl FetchExchangeRates for MockedExchangeRatesFetcher {
             .target_currency(target_currency)
             .build())
     }
+
+    fn fetch_rate(
+        &self,
+        from: Currency,
+        to: Currency,
+        _date: Date,
+    ) -> klirr_core_invoice::Result<UnitPrice> {
+        self.fetch_for_items(to, Vec::new())?
+            .convert(UnitPrice::from(rust_decimal::dec!(1)), from)
+    }
+
+    fn rates_source(&self) -> String {
+        "Mocked exchange rates".to_owned()
+    }
 }
 
 /// Generates a PNG image from a PDF rendered from the given layout path and input data.
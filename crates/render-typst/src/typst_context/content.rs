@@ -1,15 +1,23 @@
-use typst::syntax::{FileId, Source};
+use typst::{
+    foundations::Bytes,
+    syntax::{FileId, Source},
+};
 
-/// Holds all Typst sources required to build a document.
+/// Holds all Typst sources and files required to build a document.
 #[derive(Debug)]
 pub struct Content {
     main: Source,
     modules: Vec<Source>,
+    files: Vec<(FileId, Bytes)>,
 }
 
 impl Content {
-    pub fn new(main: Source, modules: Vec<Source>) -> Self {
-        Self { main, modules }
+    pub fn new(main: Source, modules: Vec<Source>, files: Vec<(FileId, Bytes)>) -> Self {
+        Self {
+            main,
+            modules,
+            files,
+        }
     }
 
     pub fn main(&self) -> &Source {
@@ -22,4 +30,11 @@ impl Content {
             .find(|module| module.id() == id)
             .cloned()
     }
+
+    pub fn find_file(&self, id: FileId) -> Option<Bytes> {
+        self.files
+            .iter()
+            .find(|(file_id, _)| *file_id == id)
+            .map(|(_, bytes)| bytes.clone())
+    }
 }
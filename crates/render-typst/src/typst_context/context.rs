@@ -10,7 +10,7 @@ use log::trace;
 use typst::{
     Library, World,
     foundations::{Bytes, Datetime},
-    syntax::{FileId, Source},
+    syntax::{FileId, Source, VirtualPath},
     text::{Font, FontBook},
     utils::LazyHash,
 };
@@ -33,7 +33,17 @@ impl TypstContext {
             .iter()
             .map(inline_module)
             .collect::<Result<Vec<_>>>()?;
-        let content = Content::new(main_source, module_sources);
+        let files = plan
+            .files()
+            .iter()
+            .map(|file| {
+                (
+                    FileId::new(None, VirtualPath::new(file.virtual_path())),
+                    Bytes::new(file.bytes().to_vec()),
+                )
+            })
+            .collect();
+        let content = Content::new(main_source, module_sources, files);
         let environment = Environment::new(plan.fonts().clone())?;
         trace!("Creating TypstContext END");
         Ok(Self {
@@ -71,7 +81,9 @@ impl World for TypstContext {
     }
 
     fn file(&self, id: FileId) -> typst::diag::FileResult<Bytes> {
-        panic!("Tried to access non-virtual file with ID: {:?}", id);
+        self.content().find_file(id).ok_or_else(|| {
+            typst::diag::FileError::NotFound(id.vpath().as_rootless_path().to_path_buf())
+        })
     }
 
     fn font(&self, index: usize) -> Option<Font> {
@@ -104,11 +116,11 @@ impl World for TypstContext {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::module::InlineFile;
     use indexmap::IndexSet;
     use klirr_foundation::{FontIdentifier, FontWeight};
     use std::path::Path;
     use test_log::test;
-    use typst::syntax::VirtualPath;
 
     fn plan() -> DocumentPlan {
         DocumentPlan::new(
@@ -156,9 +168,24 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn file_access_not_implemented() {
+    fn inline_file_access() {
+        let sut = TypstContext::from_plan(
+            &plan().with_files(vec![InlineFile::new("receipts/hotel.png", vec![1, 2, 3])]),
+        )
+        .unwrap();
+        let bytes = sut
+            .file(FileId::new(None, VirtualPath::new("/receipts/hotel.png")))
+            .unwrap();
+        assert_eq!(bytes.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn unknown_file_is_not_found() {
         let sut = sut();
-        let _ = sut.file(FileId::new_fake(VirtualPath::new(Path::new("unknown.typ"))));
+        let result = sut.file(FileId::new_fake(VirtualPath::new(Path::new("unknown.png"))));
+        assert!(matches!(
+            result,
+            Err(typst::diag::FileError::NotFound(path)) if path == Path::new("unknown.png")
+        ));
     }
 }